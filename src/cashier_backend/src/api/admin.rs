@@ -1,5 +1,10 @@
 use candid::Principal;
-use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::{
+    dto::fee_ledger::{FeeLedgerEntryDto, FeeLedgerInput, FeeRevenueInput, PeriodRevenueDto},
+    error::CanisterError,
+    init::{CkBtcMinterSettings, LinkFeeSettings},
+    service::link::PaginateResult,
+};
 use cashier_common::build_data::BuildData;
 use ic_cdk::{api::msg_caller, query, update};
 use log::debug;
@@ -91,6 +96,126 @@ pub fn is_inspect_message_enabled() -> bool {
     state.settings.is_inspect_message_enabled()
 }
 
+/// Sets the gate_service canister used to enforce link gates.
+///
+/// The cashier backend must hold the `GateCreate` permission on that canister.
+#[update]
+pub fn admin_gate_service_set(canister_id: Principal) -> Result<(), CanisterError> {
+    debug!("[admin_gate_service_set] canister_id={}", canister_id);
+    let mut state = get_state();
+    let caller = msg_caller();
+    state
+        .auth_service
+        .must_have_permission(&caller, Permission::Admin);
+
+    state.settings.set_gate_service_canister_id(canister_id);
+    Ok(())
+}
+
+/// Returns the gate_service canister used to enforce link gates.
+#[query]
+pub fn gate_service_get() -> Option<Principal> {
+    let state = get_state();
+    state.settings.get_gate_service_canister_id()
+}
+
+/// Sets the ckBTC minter used to pay out claims to Bitcoin addresses.
+#[update]
+pub fn admin_ckbtc_minter_set(ckbtc_minter: CkBtcMinterSettings) -> Result<(), CanisterError> {
+    debug!(
+        "[admin_ckbtc_minter_set] minter_id={}, ledger_id={}",
+        ckbtc_minter.minter_id, ckbtc_minter.ledger_id
+    );
+    let mut state = get_state();
+    let caller = msg_caller();
+    state
+        .auth_service
+        .must_have_permission(&caller, Permission::Admin);
+
+    state.settings.set_ckbtc_minter(ckbtc_minter);
+    Ok(())
+}
+
+/// Returns the ckBTC minter used to pay out claims to Bitcoin addresses.
+#[query]
+pub fn ckbtc_minter_get() -> Option<CkBtcMinterSettings> {
+    let state = get_state();
+    state.settings.get_ckbtc_minter()
+}
+
+/// Sets the link creation fee schedule: the fees per link type and fee token,
+/// the treasury account and the fee-free creators.
+#[update]
+pub fn admin_link_fee_set(link_fee: LinkFeeSettings) -> Result<(), CanisterError> {
+    debug!("[admin_link_fee_set] link_fee={:?}", link_fee);
+    let mut state = get_state();
+    let caller = msg_caller();
+    state
+        .auth_service
+        .must_have_permission(&caller, Permission::Admin);
+
+    let link_types = &link_fee.link_type_fees;
+    if link_types.iter().enumerate().any(|(i, type_fees)| {
+        link_types
+            .iter()
+            .skip(i + 1)
+            .any(|other| other.link_type == type_fees.link_type)
+    }) {
+        return Err(CanisterError::InvalidInput(
+            "Link type fees must be set once per link type".to_string(),
+        ));
+    }
+
+    state.settings.set_link_fee(link_fee);
+    Ok(())
+}
+
+/// Returns the link creation fee schedule.
+#[query]
+pub fn link_fee_get() -> LinkFeeSettings {
+    let state = get_state();
+    state.settings.get_link_fee()
+}
+
+/// Returns a page of the fees collected into the treasury in the given range, ordered by collection time.
+#[query]
+#[allow(clippy::needless_pass_by_value)]
+pub fn admin_fee_ledger_get(
+    input: FeeLedgerInput,
+) -> Result<PaginateResult<FeeLedgerEntryDto>, CanisterError> {
+    debug!("[admin_fee_ledger_get] input={:?}", input);
+    let state = get_state();
+    let caller = msg_caller();
+    state
+        .auth_service
+        .must_have_permission(&caller, Permission::Admin);
+
+    state.fee_ledger_service.get_entries(
+        input.start_at,
+        input.end_at,
+        &input.paginate.unwrap_or_default(),
+    )
+}
+
+/// Returns the fees collected into the treasury in the given range,
+/// aggregated per period and per token.
+#[query]
+#[allow(clippy::needless_pass_by_value)]
+pub fn admin_fee_revenue_get(
+    input: FeeRevenueInput,
+) -> Result<Vec<PeriodRevenueDto>, CanisterError> {
+    debug!("[admin_fee_revenue_get] input={:?}", input);
+    let state = get_state();
+    let caller = msg_caller();
+    state
+        .auth_service
+        .must_have_permission(&caller, Permission::Admin);
+
+    state
+        .fee_ledger_service
+        .get_revenue(input.start_at, input.end_at, input.period)
+}
+
 /// Clears all cached token fees from the service.
 ///
 /// This admin endpoint invalidates all cached token transfer fees, forcing
@@ -5,6 +5,7 @@ use cashier_backend_types::init::{CashierBackendInitData, CashierBackendUpgradeD
 use ic_cdk::{init, post_upgrade, pre_upgrade};
 use log::info;
 
+use crate::api::link_v2::{schedule_action_recovery, schedule_link_timers_rearm};
 use crate::api::state::get_state;
 use crate::apps::auth::Permission;
 use cashier_common::constant::DEFAULT_TOKEN_FEE_TTL_NS;
@@ -33,7 +34,26 @@ fn init(init_data: CashierBackendInitData) {
         .add_permissions(init_data.owner, vec![Permission::Admin])
         .expect("Should be able to set the admin");
 
+    if let Some(timeout_ns) = init_data.action_processing_timeout_ns {
+        info!("[init] Setting action processing timeout to {timeout_ns} ns");
+        state.settings.set_action_processing_timeout_ns(timeout_ns);
+    }
+
+    if let Some(ckbtc_minter) = init_data.ckbtc_minter {
+        info!(
+            "[init] Set CKBTC minter canister id to {}",
+            ckbtc_minter.minter_id
+        );
+        state.settings.set_ckbtc_minter(ckbtc_minter);
+    }
+
+    if let Some(link_fee) = init_data.link_fee {
+        info!("[init] Set link creation fee schedule to {link_fee:?}");
+        state.settings.set_link_fee(link_fee);
+    }
+
     init_ic_rand();
+    schedule_action_recovery();
 }
 
 #[pre_upgrade]
@@ -56,4 +76,23 @@ fn post_upgrade(upgrade_data: CashierBackendUpgradeData) {
             .token_fee_ttl_ns
             .unwrap_or(DEFAULT_TOKEN_FEE_TTL_NS),
     );
+
+    if let Some(timeout_ns) = upgrade_data.action_processing_timeout_ns {
+        get_state()
+            .settings
+            .set_action_processing_timeout_ns(timeout_ns);
+    }
+
+    if let Some(ckbtc_minter) = upgrade_data.ckbtc_minter {
+        get_state().settings.set_ckbtc_minter(ckbtc_minter);
+    }
+
+    if let Some(link_fee) = upgrade_data.link_fee {
+        get_state().settings.set_link_fee(link_fee);
+    }
+
+    // Timers do not survive upgrades, the pending link starts and expirations are
+    // re-armed in chunks so that no single message walks every link
+    schedule_link_timers_rearm(None);
+    schedule_action_recovery();
 }
@@ -8,13 +8,22 @@ use cashier_backend_types::{
         link::{CreateLinkInput, GetLinkOptions, GetLinkResp, LinkDto},
     },
     error::CanisterError,
-    link_v2::dto::{CreateLinkDto, ProcessActionDto, ProcessActionV2Input},
-    repository::keys::RequestLockKey,
+    link_v2::dto::{
+        ActionReceiptDto, CreateLinkBatchDto, CreateLinkBatchInput, CreateLinkDto,
+        CreateLinkEstimateDto, LinkActionHistoryDto, ProcessActionDto, ProcessActionV2Input,
+        SimulateActionDto, UpdateLinkV2Input,
+    },
+    repository::{action::v1::ActionType, keys::RequestLockKey, link::v1::LinkState},
     service::link::{PaginateInput, PaginateResult},
 };
-use cashier_common::{guard::is_not_anonymous, runtime::IcEnvironment};
+use cashier_common::{
+    constant::{LINK_TIMERS_REARM_CHUNK_SIZE, MAX_ACTION_RECOVERY_ATTEMPTS},
+    guard::is_not_anonymous,
+    runtime::IcEnvironment,
+};
 use ic_cdk::{api::msg_caller, query, update};
-use log::{debug, info};
+use log::{debug, info, warn};
+use std::time::Duration;
 
 /// Creates a new link V2
 /// # Arguments
@@ -47,6 +56,66 @@ async fn user_create_link_v2(input: CreateLinkInput) -> Result<CreateLinkDto, Ca
     res
 }
 
+/// Creates a batch of links V2 from the same template, funded by a single batch of
+/// ICRC-112 requests. Every link is activated by processing the single action of
+/// the batch with `user_process_action_v2` once the requests are executed.
+/// # Arguments
+/// * `input` - The link template and the number of links to create
+/// # Returns
+/// * `Ok(CreateLinkBatchDto)` - The created link ids, the action funding them and its ICRC-112 requests
+/// * `Err(CanisterError)` - If link creation fails or validation errors occur
+#[update(guard = "is_not_anonymous")]
+async fn user_create_link_batch_v2(
+    input: CreateLinkBatchInput,
+) -> Result<CreateLinkBatchDto, CanisterError> {
+    info!("[user_create_link_batch_v2]");
+    debug!("[user_create_link_batch_v2] input: {input:?}");
+
+    let mut request_lock_service = get_state().request_lock_service;
+    let mut link_v2_service = get_state().link_v2_service;
+    let created_at = get_state().env.time();
+    let canister_id = get_state().env.id();
+    let caller = msg_caller();
+    let key = RequestLockKey::CreateLink {
+        user_principal: caller,
+    };
+
+    let _ = request_lock_service.create(&key, created_at)?;
+    let res = link_v2_service
+        .create_link_batch(caller, canister_id, input, created_at)
+        .await;
+    let _ = request_lock_service.drop(&key);
+
+    res
+}
+
+/// Estimates the cost of creating a link, per token: the funding deposited into the link,
+/// the ledger fees and the link creation fee. Nothing is created.
+///
+/// Only cached ledger fees are used; tokens whose fee is not cached are flagged
+/// and their ledger fees are counted as 0.
+/// # Arguments
+/// * `input` - Link creation data
+/// # Returns
+/// * `Ok(CreateLinkEstimateDto)` - The cost breakdown of each token
+/// * `Err(CanisterError)` - If the input would not create a valid link
+#[query(guard = "is_not_anonymous")]
+fn estimate_create_link(input: CreateLinkInput) -> Result<CreateLinkEstimateDto, CanisterError> {
+    info!("[estimate_create_link]");
+    debug!("[estimate_create_link] input: {input:?}");
+
+    let state = get_state();
+    let link_fee_settings = state.settings.get_link_fee();
+    state.link_v2_service.estimate_create_link(
+        msg_caller(),
+        state.env.id(),
+        input,
+        state.env.time(),
+        &link_fee_settings,
+        |token_id| state.token_fee_service.get_cached_token_fee(token_id),
+    )
+}
+
 /// Disables an existing link V2
 /// # Arguments
 /// * `link_id` - The ID of the link to disable
@@ -62,6 +131,51 @@ fn user_disable_link_v2(link_id: &str) -> Result<LinkDto, CanisterError> {
     link_v2_service.disable_link(msg_caller(), link_id)
 }
 
+/// Re-enables a disabled link V2
+/// # Arguments
+/// * `link_id` - The ID of the link to enable
+/// # Returns
+/// * `Ok(LinkDto)` - The enabled link data
+/// * `Err(CanisterError)` - If enabling fails or unauthorized
+#[update(guard = "is_not_anonymous")]
+async fn user_enable_link_v2(link_id: String) -> Result<LinkDto, CanisterError> {
+    info!("[user_enable_link_v2]");
+    debug!("[user_enable_link_v2] link_id: {link_id}");
+
+    let mut link_v2_service = get_state().link_v2_service;
+    let canister_id = get_state().env.id();
+    link_v2_service
+        .enable_link(msg_caller(), canister_id, &link_id, get_state().env.time())
+        .await
+}
+
+/// Updates the metadata of an existing link V2
+/// # Arguments
+/// * `input` - The metadata changes to apply
+/// # Returns
+/// * `Ok(LinkDto)` - The updated link data
+/// * `Err(CanisterError)` - If the update is invalid or unauthorized
+#[update(guard = "is_not_anonymous")]
+fn user_update_link_v2(input: UpdateLinkV2Input) -> Result<LinkDto, CanisterError> {
+    info!("[user_update_link_v2]");
+    debug!("[user_update_link_v2] input: {input:?}");
+
+    let mut link_v2_service = get_state().link_v2_service;
+    let expiry_changed = input.expires_at.is_some();
+    let res = link_v2_service.update_link(msg_caller(), input, get_state().env.time());
+
+    // re-arm the expiry timer of funded links, the old timer will find the link not yet expired
+    if let Ok(link) = &res
+        && expiry_changed
+        && matches!(link.state, LinkState::Scheduled | LinkState::Active)
+        && let Some(expires_at) = link.expires_at
+    {
+        schedule_link_expiration(link.id.clone(), expires_at);
+    }
+
+    res
+}
+
 /// Creates a new action V2.
 /// # Arguments
 /// * `input` - Action creation data
@@ -83,9 +197,20 @@ async fn user_create_action_v2(input: CreateActionInput) -> Result<ActionDto, Ca
         action_type: input.action_type.clone().to_string(),
     };
 
-    let _ = request_lock_service.create(&key, get_state().env.time())?;
+    let now = get_state().env.time();
+    let _ = request_lock_service.create(&key, now)?;
     let res = link_v2_service
-        .create_action(msg_caller(), canister_id, &input.link_id, input.action_type)
+        .create_action(
+            msg_caller(),
+            canister_id,
+            &input.link_id,
+            input.action_type,
+            input.amount,
+            input.top_up_use_count,
+            input.btc_address,
+            input.destination,
+            now,
+        )
         .await;
     let _ = request_lock_service.drop(&key);
 
@@ -105,7 +230,6 @@ async fn user_process_action_v2(
     info!("[user_process_action_v2]");
     debug!("[user_process_action_v2] input: {input:?}");
 
-    let mut request_lock_service = get_state().request_lock_service;
     let mut link_v2_service = get_state().link_v2_service;
     let canister_id = get_state().env.id();
     let caller = msg_caller();
@@ -114,15 +238,192 @@ async fn user_process_action_v2(
         action_id: input.action_id.clone(),
     };
 
-    let _ = request_lock_service.create(&key, get_state().env.time())?;
+    let now = get_state().env.time();
+    // released even if the call traps, so the recovery worker only skips live calls
+    let lock = get_state().request_lock_service.lock(key, now)?;
     let res = link_v2_service
-        .process_action(msg_caller(), canister_id, &input.action_id)
+        .process_action(msg_caller(), canister_id, &input.action_id, now)
         .await;
-    let _ = request_lock_service.drop(&key);
+    drop(lock);
+
+    if let Ok(result) = &res {
+        schedule_link_timers(result);
+    }
 
     res
 }
 
+/// Simulates processing an action V2 without making any transfer.
+/// Checks the balances and allowances the action needs on the ledgers, so the caller
+/// can be warned before signing its ICRC-112 requests. No state is changed; it is an
+/// update call because the ledgers live on other subnets than this canister.
+/// # Arguments
+/// * `action_id` - The ID of the action to simulate
+/// # Returns
+/// * `Ok(SimulateActionDto)` - The predicted outcome and the first blocking error
+/// * `Err(CanisterError)` - If the action is not found or not the caller's
+#[update(guard = "is_not_anonymous")]
+async fn user_simulate_action_v2(action_id: String) -> Result<SimulateActionDto, CanisterError> {
+    info!("[user_simulate_action_v2]");
+    debug!("[user_simulate_action_v2] action_id: {action_id}");
+
+    get_state()
+        .link_v2_service
+        .simulate_action(msg_caller(), &action_id)
+        .await
+}
+
+/// Arms the start and expiry timers of the links a CreateLink action has funded.
+/// # Arguments
+/// * `result` - The result of processing an action
+fn schedule_link_timers(result: &ProcessActionDto) {
+    if !result.is_success || result.action.r#type != ActionType::CreateLink {
+        return;
+    }
+
+    // the links of a batch share the start and expiry of the link owning the action
+    let link_ids =
+        std::iter::once(&result.link.id).chain(result.link.batch_link_ids.iter().flatten());
+    for link_id in link_ids {
+        if result.link.state == LinkState::Scheduled
+            && let Some(starts_at) = result.link.starts_at
+        {
+            schedule_link_start(link_id.clone(), starts_at);
+        }
+        if let Some(expires_at) = result.link.expires_at {
+            schedule_link_expiration(link_id.clone(), expires_at);
+        }
+    }
+}
+
+/// Schedules a one-shot timer that opens a scheduled link once its start time is reached.
+/// # Arguments
+/// * `link_id` - The ID of the link to open
+/// * `starts_at` - The timestamp (in nanoseconds) at which the link opens
+pub(crate) fn schedule_link_start(link_id: String, starts_at: u64) {
+    let env = get_state().env;
+    let delay = Duration::from_nanos(starts_at.saturating_sub(env.time()));
+
+    env.set_timer(delay, move || {
+        let now = get_state().env.time();
+        match get_state().link_v2_service.start_link(&link_id, now) {
+            Ok(_) => info!("[schedule_link_start] link {link_id} started"),
+            Err(err) => debug!("[schedule_link_start] link {link_id} not started: {err}"),
+        }
+    });
+}
+
+/// Schedules a one-shot timer that ends the link once its expiry has passed.
+/// # Arguments
+/// * `link_id` - The ID of the link to expire
+/// * `expires_at` - The timestamp (in nanoseconds) at which the link expires
+pub(crate) fn schedule_link_expiration(link_id: String, expires_at: u64) {
+    let env = get_state().env;
+    let delay = Duration::from_nanos(expires_at.saturating_sub(env.time()));
+
+    env.set_timer(delay, move || {
+        let now = get_state().env.time();
+        match get_state().link_v2_service.expire_link(&link_id, now) {
+            Ok(_) => info!("[schedule_link_expiration] link {link_id} expired"),
+            Err(err) => debug!("[schedule_link_expiration] link {link_id} not expired: {err}"),
+        }
+    });
+}
+
+/// Re-arms the start and expiry timers of the links waiting for them, one chunk of
+/// links per timer, the next chunk being scheduled until every link was walked.
+/// # Arguments
+/// * `after` - The ID of the last link of the previous chunk, None to start from the first link
+pub(crate) fn schedule_link_timers_rearm(after: Option<String>) {
+    get_state().env.set_timer(Duration::ZERO, move || {
+        let (links, next) = get_state()
+            .link_v2_service
+            .get_links_waiting_for_timers(after.as_deref(), LINK_TIMERS_REARM_CHUNK_SIZE);
+
+        for link in links {
+            if link.state == LinkState::Scheduled
+                && let Some(starts_at) = link.starts_at
+            {
+                schedule_link_start(link.id.clone(), starts_at);
+            }
+            if let Some(expires_at) = link.expires_at {
+                schedule_link_expiration(link.id, expires_at);
+            }
+        }
+
+        if let Some(next) = next {
+            schedule_link_timers_rearm(Some(next));
+        }
+    });
+}
+
+/// Schedules the next run of the worker that recovers actions stuck in processing.
+/// The worker re-arms itself before recovering, so a trap while recovering an action
+/// does not stop it.
+pub(crate) fn schedule_action_recovery() {
+    let env = get_state().env;
+    let timeout_ns = get_state().settings.get_action_processing_timeout_ns();
+
+    env.set_timer(Duration::from_nanos(timeout_ns), move || {
+        schedule_action_recovery();
+        get_state().env.spawn(recover_stuck_actions(timeout_ns));
+    });
+}
+
+/// Recovers the actions left in processing by calls that trapped or timed out.
+/// Actions still being processed by a live call are skipped, and actions that cannot
+/// be recovered yet are left in processing for the next run, until they are failed
+/// after `MAX_ACTION_RECOVERY_ATTEMPTS` runs.
+/// # Arguments
+/// * `timeout_ns` - The time in nanoseconds after which a processing action is stuck
+async fn recover_stuck_actions(timeout_ns: u64) {
+    let env = get_state().env;
+    let stuck_actions = get_state()
+        .link_v2_service
+        .get_stuck_actions(env.time(), timeout_ns);
+
+    for action in stuck_actions {
+        let key = RequestLockKey::ProcessAction {
+            user_principal: action.creator,
+            action_id: action.id.clone(),
+        };
+        let Ok(lock) = get_state().request_lock_service.lock(key, env.time()) else {
+            debug!(
+                "[recover_stuck_actions] action {} is being processed",
+                action.id
+            );
+            continue;
+        };
+
+        let res = get_state()
+            .link_v2_service
+            .recover_action(
+                &action.id,
+                env.id(),
+                env.time(),
+                timeout_ns.saturating_mul(MAX_ACTION_RECOVERY_ATTEMPTS),
+            )
+            .await;
+        drop(lock);
+
+        match res {
+            Ok((state, processed)) => {
+                info!(
+                    "[recover_stuck_actions] action {} recovered as {:?}",
+                    action.id, state
+                );
+                if let Some(result) = processed {
+                    schedule_link_timers(&result);
+                }
+            }
+            Err(err) => warn!(
+                "[recover_stuck_actions] action {} left processing: {err}",
+                action.id
+            ),
+        }
+    }
+}
+
 /// Retrieves a paginated list of links created by the authenticated caller.
 ///
 /// This endpoint requires the caller to be authenticated (non-anonymous) and returns
@@ -171,3 +472,47 @@ async fn get_link_details_v2(
         .get_link_details(msg_caller(), link_id, options)
         .await
 }
+
+/// Retrieves the receipt of an action, listing the ledger, block index, amount
+/// and accounts of every transfer it made.
+///
+/// Only the creator of the action or of its link can read the receipt.
+///
+/// # Arguments
+/// * `action_id` - The ID of the action
+///
+/// # Returns
+/// * `Ok(ActionReceiptDto)` - The receipt of the action
+/// * `Err(CanisterError)` - If the action is not found or access is denied
+#[query(guard = "is_not_anonymous")]
+fn user_get_action_receipt_v2(action_id: &str) -> Result<ActionReceiptDto, CanisterError> {
+    info!("[user_get_action_receipt_v2]");
+    debug!("[user_get_action_receipt_v2] action_id: {action_id}");
+
+    let link_v2_service = get_state().link_v2_service;
+    link_v2_service.get_action_receipt(msg_caller(), action_id)
+}
+
+/// Lists the receive, send and withdraw actions made on a link, oldest first, with the user,
+/// state, timestamps and transfers of each action.
+///
+/// Only the creator of the link can list its actions.
+///
+/// # Arguments
+/// * `link_id` - The ID of the link
+/// * `input` - Optional pagination parameters
+///
+/// # Returns
+/// * `Ok(PaginateResult<LinkActionHistoryDto>)` - The page of link actions
+/// * `Err(CanisterError)` - If the link is not found or access is denied
+#[query(guard = "is_not_anonymous")]
+fn user_get_link_actions_v2(
+    link_id: &str,
+    input: Option<PaginateInput>,
+) -> Result<PaginateResult<LinkActionHistoryDto>, CanisterError> {
+    info!("[user_get_link_actions_v2]");
+    debug!("[user_get_link_actions_v2] link_id: {link_id}, input: {input:?}");
+
+    let link_v2_service = get_state().link_v2_service;
+    link_v2_service.get_link_actions(msg_caller(), link_id, input)
+}
@@ -13,9 +13,10 @@ pub mod state;
 use candid::Principal;
 use cashier_backend_types::auth::*;
 use cashier_backend_types::dto::action::*;
+use cashier_backend_types::dto::fee_ledger::*;
 use cashier_backend_types::dto::link::*;
 use cashier_backend_types::error::CanisterError;
-use cashier_backend_types::init::CashierBackendInitData;
+use cashier_backend_types::init::{CashierBackendInitData, CkBtcMinterSettings, LinkFeeSettings};
 use cashier_backend_types::link_v2::dto::*;
 use cashier_backend_types::service::link::*;
 use cashier_common::icrc::*;
@@ -1,6 +1,7 @@
 use crate::{
     apps::{
         auth::AuthService,
+        fee_ledger::FeeLedgerService,
         link_v2::service::LinkV2Service,
         request_lock::RequestLockService,
         settings::SettingsService,
@@ -18,6 +19,7 @@ use transaction_manager::ic_transaction_manager::IcTransactionManager;
 /// The state of the canister
 pub struct CanisterState<E: IcEnvironment + Clone + 'static> {
     pub auth_service: AuthService<&'static LocalKey<RefCell<AuthServiceStorage>>>,
+    pub fee_ledger_service: FeeLedgerService<ThreadlocalRepositories>,
     pub link_v2_service: LinkV2Service<ThreadlocalRepositories, IcTransactionManager<E>>,
     pub log_service: LoggerConfigService<&'static LocalKey<RefCell<LoggerServiceStorage>>>,
     pub request_lock_service: RequestLockService<ThreadlocalRepositories>,
@@ -38,6 +40,7 @@ impl<E: IcEnvironment + Clone + 'static> CanisterState<E> {
 
         CanisterState {
             auth_service: AuthService::new(&AUTH_SERVICE_STORE),
+            fee_ledger_service: FeeLedgerService::new(&repo),
             link_v2_service,
             log_service: LoggerConfigService::new(&LOGGER_SERVICE_STORE),
             request_lock_service: RequestLockService::new(&repo),
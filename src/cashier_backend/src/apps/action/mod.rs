@@ -2,7 +2,7 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::repositories::{self, Repositories};
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::dto::link::GetLinkOptions;
 use cashier_backend_types::error::CanisterError;
 use cashier_backend_types::link_v2::link_result::LinkProcessActionResult;
@@ -10,9 +10,15 @@ use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
 use cashier_backend_types::repository::link_action::v1::LinkUserState;
 use cashier_backend_types::{
     repository::{
-        action::v1::Action, action_intent::v1::ActionIntent, intent::v1::Intent,
-        intent_transaction::v1::IntentTransaction, link_action::v1::LinkAction,
-        transaction::v1::Transaction, user_action::v1::UserAction,
+        action::v1::Action,
+        action_intent::v1::ActionIntent,
+        common::{Asset, Wallet},
+        intent::v1::Intent,
+        intent::v1::{IntentState, IntentType},
+        intent_transaction::v1::IntentTransaction,
+        link_action::v1::LinkAction,
+        transaction::v1::{Transaction, TransactionState},
+        user_action::v1::UserAction,
     },
     service::action::ActionData,
 };
@@ -28,6 +34,8 @@ pub struct ActionService<R: Repositories> {
     intent_transaction_repository:
         repositories::intent_transaction::IntentTransactionRepository<R::IntentTransaction>,
     link_action_repository: repositories::link_action::LinkActionRepository<R::LinkAction>,
+    processing_action_repository:
+        repositories::processing_action::ProcessingActionRepository<R::ProcessingAction>,
     user_action_repository: repositories::user_action::UserActionRepository<R::UserAction>,
     user_link_action_repository:
         repositories::user_link_action::UserLinkActionRepository<R::UserLinkAction>,
@@ -43,6 +51,7 @@ impl<R: Repositories> ActionService<R> {
             transaction_repository: repo.transaction(),
             intent_transaction_repository: repo.intent_transaction(),
             link_action_repository: repo.link_action(),
+            processing_action_repository: repo.processing_action(),
             user_action_repository: repo.user_action(),
             user_link_action_repository: repo.user_link_action(),
         }
@@ -165,6 +174,9 @@ impl<R: Repositories> ActionService<R> {
         intents: Vec<Intent>,
         intent_tx_map: &HashMap<String, Vec<Transaction>>,
     ) -> Result<(), CanisterError> {
+        if action.state != ActionState::Processing {
+            self.processing_action_repository.remove(&action.id);
+        }
         self.action_repository.update(action);
         self.intent_repository.batch_update(intents);
 
@@ -179,6 +191,158 @@ impl<R: Repositories> ActionService<R> {
         Ok(())
     }
 
+    /// Marks an action and its unfinished transactions as processing, so that the action
+    /// can be recovered if the call processing it never completes.
+    /// # Arguments
+    /// * `action_data` - The action with its intents and transactions
+    /// * `now` - The current timestamp, stored as the start time of the transactions
+    pub fn mark_action_processing(&mut self, action_data: &ActionData, now: u64) {
+        let mut action = action_data.action.clone();
+        action.state = ActionState::Processing;
+        self.processing_action_repository.insert(&action.id, now);
+        self.action_repository.update(action);
+
+        let transactions = action_data
+            .intent_txs
+            .values()
+            .flatten()
+            .filter(|tx| tx.state != TransactionState::Success)
+            .map(|tx| Transaction {
+                state: TransactionState::Processing,
+                start_ts: Some(now),
+                ..tx.clone()
+            })
+            .collect();
+        self.transaction_repository.batch_create(transactions);
+    }
+
+    /// Returns the actions of the given types made on a link.
+    /// # Arguments
+    /// * `link_id` - The ID of the link
+    /// * `action_types` - The types of the actions to return
+    /// # Returns
+    /// * `Vec<LinkAction>` - The link actions
+    pub fn get_link_actions(&self, link_id: &str, action_types: &[ActionType]) -> Vec<LinkAction> {
+        self.link_action_repository
+            .get_link_actions_by_types(link_id, action_types)
+    }
+
+    /// Returns the actions that have been processing for longer than the given timeout.
+    /// Index entries of actions no longer processing are cleaned up.
+    /// # Arguments
+    /// * `now` - The current timestamp
+    /// * `timeout_ns` - The time in nanoseconds after which a processing action is stuck
+    /// # Returns
+    /// * `Vec<Action>` - The stuck actions
+    pub fn get_stuck_actions(&mut self, now: u64, timeout_ns: u64) -> Vec<Action> {
+        let Some(started_until) = now.checked_sub(timeout_ns) else {
+            return vec![];
+        };
+        let action_ids = self
+            .processing_action_repository
+            .get_started_until(started_until);
+
+        let mut stuck_actions = vec![];
+        for action_id in action_ids {
+            match self.action_repository.get(&action_id) {
+                Some(action) if action.state == ActionState::Processing => {
+                    stuck_actions.push(action)
+                }
+                _ => self.processing_action_repository.remove(&action_id),
+            }
+        }
+        stuck_actions
+    }
+
+    /// Returns the time the processing of an action started.
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `Option<u64>` - The start time, if the action is processing
+    pub fn get_processing_started_at(&self, action_id: &str) -> Option<u64> {
+        self.processing_action_repository.get(action_id)
+    }
+
+    /// Sets the time the processing of an action started, keeping the age of an action
+    /// whose processing was retried.
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// * `started_at` - The time the processing started
+    pub fn set_processing_started_at(&mut self, action_id: &str, started_at: u64) {
+        self.processing_action_repository
+            .insert(action_id, started_at);
+    }
+
+    /// Fails an action whose processing could not be recovered, along with its
+    /// unfinished intents and transactions.
+    /// # Arguments
+    /// * `action_id` - The ID of the action to fail
+    /// # Returns
+    /// * `Ok(())` - If the action was failed
+    /// * `Err(CanisterError)` - If the action is not found
+    pub fn fail_action(&mut self, action_id: &str) -> Result<(), CanisterError> {
+        let mut action_data = self
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
+
+        action_data.action.state = ActionState::Fail;
+        for intent in action_data.intents.iter_mut() {
+            if intent.state != IntentState::Success {
+                intent.state = IntentState::Fail;
+            }
+        }
+        for tx in action_data.intent_txs.values_mut().flatten() {
+            if tx.state == TransactionState::Processing {
+                tx.state = TransactionState::Fail;
+            }
+        }
+
+        self.update_action_data(
+            action_data.action,
+            action_data.intents,
+            &action_data.intent_txs,
+        )
+    }
+
+    /// Abandons an action that was never processed: it is failed and removed from the
+    /// actions of its creator on the link, so the creator can start a new one.
+    /// # Arguments
+    /// * `action_id` - The ID of the action to abandon
+    /// # Returns
+    /// * `Ok(())` - If the action was abandoned
+    /// * `Err(CanisterError)` - If the action is not found, is being processed or has made a transfer
+    pub fn abandon_action(&mut self, action_id: &str) -> Result<(), CanisterError> {
+        let action_data = self
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
+
+        let has_transferred = action_data
+            .intent_txs
+            .values()
+            .flatten()
+            .any(|tx| tx.state == TransactionState::Success);
+        if matches!(
+            action_data.action.state,
+            ActionState::Processing | ActionState::Success
+        ) || has_transferred
+        {
+            return Err(CanisterError::ValidationErrors(
+                "Only unprocessed actions can be abandoned".to_string(),
+            ));
+        }
+
+        self.fail_action(action_id)?;
+        self.user_link_action_repository.delete(&LinkAction {
+            link_id: action_data.action.link_id,
+            action_type: action_data.action.r#type,
+            action_id: action_data.action.id,
+            user_id: action_data.action.creator,
+            link_user_state: None,
+        });
+
+        Ok(())
+    }
+
     /// Update link user state based on the given link process action result.
     /// # Arguments
     /// * `result` - `LinkProcessActionResult` containing the processed action and link
@@ -187,7 +351,10 @@ impl<R: Repositories> ActionService<R> {
     pub fn update_link_user_state(&mut self, result: &LinkProcessActionResult) {
         let action = &result.process_action_result.action;
         if result.process_action_result.is_success
-            && matches!(action.r#type, ActionType::Receive | ActionType::Send)
+            && matches!(
+                action.r#type,
+                ActionType::Receive | ActionType::Send | ActionType::TopUp
+            )
             && action.state == ActionState::Success
         {
             let link_action = LinkAction {
@@ -202,7 +369,9 @@ impl<R: Repositories> ActionService<R> {
         }
     }
 
-    /// Returns the first action for a user and link, optionally filtered by options.
+    /// Returns the current action for a user and link, optionally filtered by options.
+    /// The action still in progress is preferred over the completed ones, otherwise
+    /// the latest completed action is returned.
     /// # Arguments
     /// * `caller` - the user principal
     /// * `link_id` - the link identifier
@@ -224,7 +393,15 @@ impl<R: Repositories> ActionService<R> {
                     .get_actions_by_user_link_and_type(*caller, link_id, &action_type);
 
                 if let Some(actions) = &user_link_actions {
-                    if let Some(link_action) = actions.first() {
+                    // actions are stored in creation order
+                    let current_action = actions
+                        .iter()
+                        .find(|link_action| {
+                            link_action.link_user_state != Some(LinkUserState::Completed)
+                        })
+                        .or_else(|| actions.last());
+
+                    if let Some(link_action) = current_action {
                         let action = self.get_action_by_id(&link_action.action_id.clone());
 
                         (action, link_action.link_user_state.clone())
@@ -244,40 +421,448 @@ impl<R: Repositories> ActionService<R> {
     /// * `caller` - The principal of the user attempting to create an action
     /// * `link_id` - The ID of the link
     /// * `action_type` - The type of action to be created
+    /// * `max_actions_per_user` - How many completed actions of this type the user may have
     /// # Returns
     /// * `Ok(())` - If the action can be created
     /// * `Err(CanisterError)` - If action creation should be blocked
     /// # Business Rules
-    /// * Only one action per user per link (can be change at the future)
+    /// * A user can't start a new action while a previous one is not completed
+    /// * A user can't have more than `max_actions_per_user` completed actions
     pub fn check_action_exists_for_user(
         &self,
         caller: Principal,
         link_id: &str,
         action_type: &ActionType,
+        max_actions_per_user: u64,
     ) -> Result<(), CanisterError> {
         let existing_actions = self
             .user_link_action_repository
-            .get_actions_by_user_link_and_type(caller, link_id, action_type);
-
-        match action_type {
-            ActionType::CreateLink
-            | ActionType::Withdraw
-            | ActionType::Receive
-            | ActionType::Send => {
-                // Block if ANY action exists for these types
-                if existing_actions
-                    .as_ref()
-                    .map(|actions| !actions.is_empty())
-                    .unwrap_or(false)
+            .get_actions_by_user_link_and_type(caller, link_id, action_type)
+            .unwrap_or_default();
+
+        if existing_actions.is_empty() {
+            return Ok(());
+        }
+
+        let completed_count = existing_actions
+            .iter()
+            .filter(|action| action.link_user_state == Some(LinkUserState::Completed))
+            .count() as u64;
+
+        if completed_count < existing_actions.len() as u64 {
+            return Err(CanisterError::ValidationErrors(format!(
+                "Action of type {} already exists for this link",
+                action_type
+            )));
+        }
+
+        if completed_count >= max_actions_per_user {
+            return Err(CanisterError::ValidationErrors(format!(
+                "Action of type {} has reached its limit of {} per user for this link",
+                action_type, max_actions_per_user
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Sums the allowance that the pending actions of a user still rely on to transfer an asset
+    /// from their wallet through a spender. An approve of the same spender replaces the allowance
+    /// on the ledger, so it must grant this amount on top of its own.
+    /// # Arguments
+    /// * `user_id` - The principal of the user
+    /// * `spender` - The spender of the allowance
+    /// * `asset` - The transferred asset
+    /// * `ledger_fee` - The ledger fee charged on the allowance by each transfer
+    /// # Returns
+    /// * `Nat` - The allowance reserved by the transfers not yet made
+    pub fn get_reserved_allowance(
+        &self,
+        user_id: Principal,
+        spender: &Wallet,
+        asset: &Asset,
+        ledger_fee: &Nat,
+    ) -> Nat {
+        let from = Wallet::new(user_id);
+        let mut reserved = Nat::from(0u64);
+
+        for action_id in self.user_action_repository.get_action_ids_by_user(user_id) {
+            let Some(action) = self.action_repository.get(&action_id) else {
+                continue;
+            };
+            if !matches!(action.state, ActionState::Created | ActionState::Processing) {
+                continue;
+            }
+
+            for action_intent in self.action_intent_repository.get_by_action_id(&action_id) {
+                let Some(intent) = self.intent_repository.get(&action_intent.intent_id) else {
+                    continue;
+                };
+                if matches!(intent.state, IntentState::Success | IntentState::Fail) {
+                    continue;
+                }
+                if let IntentType::TransferFrom(transfer_from) = intent.r#type
+                    && transfer_from.from == from
+                    && transfer_from.spender == *spender
+                    && transfer_from.asset == *asset
                 {
-                    return Err(CanisterError::ValidationErrors(format!(
-                        "Action of type {} already exists for this link",
-                        action_type
-                    )));
+                    reserved += transfer_from.amount + ledger_fee.clone();
                 }
             }
         }
 
-        Ok(())
+        reserved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::tests::TestRepositories;
+    use candid::Nat;
+    use cashier_backend_types::repository::{
+        common::{Asset, Wallet},
+        intent::v1::{IntentTask, IntentType, TransferData, TransferFromData},
+        transaction::v1::{FromCallType, IcTransaction, Icrc1Transfer, Protocol},
+    };
+    use cashier_common::chain::Chain;
+
+    fn link_action(user_id: Principal, action_id: &str, completed: bool) -> LinkAction {
+        LinkAction {
+            link_id: "link1".to_string(),
+            action_id: action_id.to_string(),
+            action_type: ActionType::Receive,
+            user_id,
+            link_user_state: completed.then_some(LinkUserState::Completed),
+        }
+    }
+
+    #[test]
+    fn it_should_block_new_action_while_previous_is_pending() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let service = ActionService::new(&repo);
+        let user = Principal::anonymous();
+        repo.user_link_action()
+            .create(link_action(user, "action1", false));
+
+        // Act
+        let result = service.check_action_exists_for_user(user, "link1", &ActionType::Receive, 3);
+
+        // Assert
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn it_should_allow_actions_up_to_the_per_user_limit() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let service = ActionService::new(&repo);
+        let user = Principal::anonymous();
+
+        // Act & Assert
+        assert!(
+            service
+                .check_action_exists_for_user(user, "link1", &ActionType::Receive, 2)
+                .is_ok()
+        );
+        repo.user_link_action()
+            .create(link_action(user, "action1", true));
+        assert!(
+            service
+                .check_action_exists_for_user(user, "link1", &ActionType::Receive, 2)
+                .is_ok()
+        );
+        repo.user_link_action()
+            .create(link_action(user, "action2", true));
+        assert!(
+            service
+                .check_action_exists_for_user(user, "link1", &ActionType::Receive, 2)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn it_should_return_the_action_in_progress_over_completed_ones() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let service = ActionService::new(&repo);
+        let user = Principal::anonymous();
+        for (action_id, completed) in [("action1", true), ("action2", false)] {
+            repo.action().create(Action {
+                id: action_id.to_string(),
+                r#type: ActionType::Receive,
+                state: ActionState::Created,
+                creator: user,
+                link_id: "link1".to_string(),
+                top_up_count: None,
+            });
+            repo.user_link_action()
+                .create(link_action(user, action_id, completed));
+        }
+
+        // Act
+        let (action, link_user_state) = service.get_first_action(
+            &user,
+            "link1",
+            Some(GetLinkOptions {
+                action_type: ActionType::Receive,
+            }),
+        );
+
+        // Assert
+        assert_eq!(action.unwrap().id, "action2");
+        assert_eq!(link_user_state, None);
+    }
+
+    fn store_action_with_transaction(service: &mut ActionService<TestRepositories>) -> ActionData {
+        let action = Action {
+            id: "action1".to_string(),
+            r#type: ActionType::Receive,
+            state: ActionState::Created,
+            creator: Principal::anonymous(),
+            link_id: "link1".to_string(),
+            top_up_count: None,
+        };
+        let intent = Intent {
+            id: "intent1".to_string(),
+            state: IntentState::Created,
+            created_at: 0,
+            dependency: vec![],
+            chain: Chain::IC,
+            task: IntentTask::TransferLinkToWallet,
+            r#type: IntentType::Transfer(TransferData {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                asset: Asset::default(),
+                amount: Nat::from(100u64),
+            }),
+            label: "RECEIVE".to_string(),
+            link_id: None,
+        };
+        let transaction = Transaction {
+            id: "tx1".to_string(),
+            created_at: 0,
+            state: TransactionState::Created,
+            dependency: None,
+            group: 0,
+            from_call_type: FromCallType::Canister,
+            protocol: Protocol::IC(IcTransaction::Icrc1Transfer(Icrc1Transfer {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                asset: Asset::default(),
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+            start_ts: None,
+            block_index: None,
+        };
+        let intent_txs = HashMap::from([(intent.id.clone(), vec![transaction])]);
+        service
+            .store_action_data(
+                link_action(action.creator, &action.id, false),
+                action.clone(),
+                vec![intent.clone()],
+                intent_txs.clone(),
+                action.creator,
+            )
+            .unwrap();
+
+        ActionData {
+            action,
+            intents: vec![intent],
+            intent_txs,
+        }
+    }
+
+    fn store_fee_action(
+        service: &mut ActionService<TestRepositories>,
+        action_id: &str,
+        state: ActionState,
+        transfer_from: &TransferFromData,
+    ) {
+        let action = Action {
+            id: action_id.to_string(),
+            r#type: ActionType::CreateLink,
+            state,
+            creator: Principal::anonymous(),
+            link_id: format!("link_{action_id}"),
+            top_up_count: None,
+        };
+        let intent = Intent {
+            id: format!("intent_{action_id}"),
+            state: IntentState::Created,
+            created_at: 0,
+            dependency: vec![],
+            chain: Chain::IC,
+            task: IntentTask::TransferWalletToTreasury,
+            r#type: IntentType::TransferFrom(transfer_from.clone()),
+            label: "LINK_CREATION_FEE".to_string(),
+            link_id: None,
+        };
+        service
+            .store_action_data(
+                link_action(action.creator, &action.id, false),
+                action.clone(),
+                vec![intent.clone()],
+                HashMap::from([(intent.id, vec![])]),
+                action.creator,
+            )
+            .unwrap();
+    }
+
+    #[test]
+    fn it_should_sum_the_allowance_reserved_by_pending_actions() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let spender = Wallet::new(Principal::management_canister());
+        let transfer_from = TransferFromData {
+            from: Wallet::new(Principal::anonymous()),
+            to: Wallet::default(),
+            spender: spender.clone(),
+            asset: Asset::default(),
+            amount: Nat::from(1_000u64),
+            actual_amount: Some(Nat::from(1_000u64)),
+            approve_amount: Some(Nat::from(1_010u64)),
+        };
+        store_fee_action(
+            &mut service,
+            "action1",
+            ActionState::Created,
+            &transfer_from,
+        );
+        store_fee_action(
+            &mut service,
+            "action2",
+            ActionState::Processing,
+            &transfer_from,
+        );
+        store_fee_action(
+            &mut service,
+            "action3",
+            ActionState::Success,
+            &transfer_from,
+        );
+        store_fee_action(
+            &mut service,
+            "action4",
+            ActionState::Created,
+            &TransferFromData {
+                spender: Wallet::default(),
+                ..transfer_from.clone()
+            },
+        );
+
+        // Act
+        let reserved = service.get_reserved_allowance(
+            Principal::anonymous(),
+            &spender,
+            &Asset::default(),
+            &Nat::from(10u64),
+        );
+
+        // Assert
+        assert_eq!(reserved, Nat::from(2_020u64));
+    }
+
+    #[test]
+    fn it_should_return_actions_stuck_in_processing() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let action_data = store_action_with_transaction(&mut service);
+
+        // Act
+        service.mark_action_processing(&action_data, 1_000);
+
+        // Assert
+        let stored = service.get_action_data("action1").unwrap();
+        assert_eq!(stored.action.state, ActionState::Processing);
+        assert_eq!(stored.intent_txs["intent1"][0].start_ts, Some(1_000));
+        assert!(service.get_stuck_actions(1_500, 1_000).is_empty());
+        assert_eq!(service.get_stuck_actions(2_000, 1_000).len(), 1);
+    }
+
+    #[test]
+    fn it_should_keep_the_processing_start_of_a_retried_action() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let action_data = store_action_with_transaction(&mut service);
+        service.mark_action_processing(&action_data, 1_000);
+        service.mark_action_processing(&action_data, 5_000);
+
+        // Act
+        service.set_processing_started_at("action1", 1_000);
+
+        // Assert
+        assert_eq!(service.get_processing_started_at("action1"), Some(1_000));
+        assert_eq!(service.get_stuck_actions(2_000, 1_000).len(), 1);
+    }
+
+    #[test]
+    fn it_should_fail_stuck_action_and_its_transactions() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let action_data = store_action_with_transaction(&mut service);
+        service.mark_action_processing(&action_data, 1_000);
+
+        // Act
+        let result = service.fail_action("action1");
+
+        // Assert
+        assert!(result.is_ok());
+        let stored = service.get_action_data("action1").unwrap();
+        assert_eq!(stored.action.state, ActionState::Fail);
+        assert_eq!(stored.intents[0].state, IntentState::Fail);
+        assert_eq!(
+            stored.intent_txs["intent1"][0].state,
+            TransactionState::Fail
+        );
+        assert!(service.get_stuck_actions(2_000, 1_000).is_empty());
+    }
+
+    #[test]
+    fn it_should_abandon_unprocessed_action() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let action_data = store_action_with_transaction(&mut service);
+        let user = action_data.action.creator;
+        repo.user_link_action()
+            .create(link_action(user, "action1", false));
+
+        // Act
+        let result = service.abandon_action("action1");
+
+        // Assert
+        assert!(result.is_ok());
+        let stored = service.get_action_data("action1").unwrap();
+        assert_eq!(stored.action.state, ActionState::Fail);
+        assert!(
+            service
+                .check_action_exists_for_user(user, "link1", &ActionType::Receive, 1)
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn it_should_not_abandon_processing_action() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let mut service = ActionService::new(&repo);
+        let action_data = store_action_with_transaction(&mut service);
+        service.mark_action_processing(&action_data, 1_000);
+
+        // Act
+        let result = service.abandon_action("action1");
+
+        // Assert
+        assert!(result.is_err());
+        let stored = service.get_action_data("action1").unwrap();
+        assert_eq!(stored.action.state, ActionState::Processing);
     }
 }
@@ -0,0 +1,473 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use std::collections::BTreeMap;
+
+use crate::repositories::{Repositories, fee_ledger::FeeLedgerRepository};
+use cashier_backend_types::{
+    dto::fee_ledger::{FeeLedgerEntryDto, PeriodRevenueDto, RevenuePeriod, TokenRevenueDto},
+    error::CanisterError,
+    repository::{
+        action::v1::Action,
+        fee_ledger::v1::FeeLedgerEntry,
+        intent::v1::{Intent, IntentState, IntentTask},
+        link::v1::Link,
+    },
+    service::link::{PaginateInput, PaginateResult},
+};
+
+const NANOS_PER_DAY: u64 = 24 * 3600 * 1_000_000_000;
+
+/// The fee ledger service, recording the fees collected into the treasury
+pub struct FeeLedgerService<R: Repositories> {
+    pub fee_ledger_repo: FeeLedgerRepository<R::FeeLedger>,
+}
+
+impl<R: Repositories> FeeLedgerService<R> {
+    /// Create a new FeeLedgerService
+    pub fn new(repositories: &R) -> Self {
+        Self {
+            fee_ledger_repo: repositories.fee_ledger(),
+        }
+    }
+
+    /// Records the treasury fees of an action whose fee transaction just succeeded.
+    /// # Arguments
+    /// * `link` - The link the action belongs to
+    /// * `action` - The processed action
+    /// * `previous_intents` - The intents of the action before it was processed
+    /// * `intents` - The intents of the action after it was processed
+    /// * `now` - The current timestamp
+    pub fn record_collected_fees(
+        &mut self,
+        link: &Link,
+        action: &Action,
+        previous_intents: &[Intent],
+        intents: &[Intent],
+        now: u64,
+    ) {
+        for intent in intents {
+            if intent.task != IntentTask::TransferWalletToTreasury
+                || intent.state != IntentState::Success
+            {
+                continue;
+            }
+            let already_collected = previous_intents
+                .iter()
+                .any(|previous| previous.id == intent.id && previous.state == IntentState::Success);
+            if already_collected {
+                continue;
+            }
+            let Some(asset) = intent.r#type.try_get_asset() else {
+                continue;
+            };
+            let amount = match intent.r#type.as_transfer_from() {
+                Some(transfer_from) => transfer_from.amount,
+                None => match intent.r#type.as_transfer() {
+                    Some(transfer) => transfer.amount,
+                    None => continue,
+                },
+            };
+
+            self.fee_ledger_repo.create(FeeLedgerEntry {
+                intent_id: intent.id.clone(),
+                action_id: action.id.clone(),
+                link_id: link.id.clone(),
+                link_type: link.link_type,
+                payer: action.creator,
+                token_id: asset.address(),
+                amount,
+                collected_at: now,
+            });
+        }
+    }
+
+    /// Returns a page of the fees collected in the given range.
+    /// # Arguments
+    /// * `start_at` - The start of the range, inclusive
+    /// * `end_at` - The end of the range, exclusive
+    /// * `paginate` - The pagination parameters
+    /// # Returns
+    /// * `Ok(PaginateResult<FeeLedgerEntryDto>)` - The page of fees collected, ordered by collection time
+    /// * `Err(CanisterError)` - If the range is empty
+    pub fn get_entries(
+        &self,
+        start_at: u64,
+        end_at: u64,
+        paginate: &PaginateInput,
+    ) -> Result<PaginateResult<FeeLedgerEntryDto>, CanisterError> {
+        validate_range(start_at, end_at)?;
+        Ok(self
+            .fee_ledger_repo
+            .get_entries_page_by_period(start_at, end_at, paginate)
+            .map(FeeLedgerEntryDto::from))
+    }
+
+    /// Returns the revenue collected in the given range, per period and per token.
+    /// The first and last periods are cut to the range. Periods without fees are omitted.
+    /// # Arguments
+    /// * `start_at` - The start of the range, inclusive
+    /// * `end_at` - The end of the range, exclusive
+    /// * `period` - The calendar period the fees are grouped by
+    /// # Returns
+    /// * `Ok(Vec<PeriodRevenueDto>)` - The revenue per period, ordered by period
+    /// * `Err(CanisterError)` - If the range is empty
+    pub fn get_revenue(
+        &self,
+        start_at: u64,
+        end_at: u64,
+        period: RevenuePeriod,
+    ) -> Result<Vec<PeriodRevenueDto>, CanisterError> {
+        validate_range(start_at, end_at)?;
+
+        let mut revenue: BTreeMap<u64, PeriodRevenueDto> = BTreeMap::new();
+        for entry in self.fee_ledger_repo.get_entries_by_period(start_at, end_at) {
+            let (period_start, period_end) = period_bounds(period, entry.collected_at);
+            let period_start = period_start.max(start_at);
+            let period_revenue = revenue
+                .entry(period_start)
+                .or_insert_with(|| PeriodRevenueDto {
+                    period_start,
+                    period_end: period_end.min(end_at),
+                    tokens: vec![],
+                });
+            match period_revenue
+                .tokens
+                .iter_mut()
+                .find(|token| token.token_id == entry.token_id)
+            {
+                Some(token_revenue) => {
+                    token_revenue.amount += entry.amount;
+                    token_revenue.fee_count += 1;
+                }
+                None => period_revenue.tokens.push(TokenRevenueDto {
+                    token_id: entry.token_id,
+                    amount: entry.amount,
+                    fee_count: 1,
+                }),
+            }
+        }
+
+        Ok(revenue.into_values().collect())
+    }
+}
+
+fn validate_range(start_at: u64, end_at: u64) -> Result<(), CanisterError> {
+    if start_at >= end_at {
+        return Err(CanisterError::InvalidInput(
+            "start_at must be before end_at".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+/// Returns the start, inclusive, and the end, exclusive, of the UTC period containing `ts`.
+fn period_bounds(period: RevenuePeriod, ts: u64) -> (u64, u64) {
+    let days = ts / NANOS_PER_DAY;
+    let (start_day, end_day) = match period {
+        RevenuePeriod::Day => (days, days + 1),
+        RevenuePeriod::Week => {
+            // 1970-01-01 is a Thursday, weeks start on Monday
+            let monday = days.saturating_sub((days + 3) % 7);
+            (monday, monday + 7)
+        }
+        RevenuePeriod::Month => {
+            let (year, month) = civil_from_days(days);
+            let next_month = if month == 12 {
+                days_from_civil(year + 1, 1)
+            } else {
+                days_from_civil(year, month + 1)
+            };
+            (days_from_civil(year, month), next_month)
+        }
+    };
+    (start_day * NANOS_PER_DAY, end_day * NANOS_PER_DAY)
+}
+
+/// Returns the year and month of a number of days since 1970-01-01.
+fn civil_from_days(days: u64) -> (u64, u64) {
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + u64::from(month <= 2);
+    (year, month)
+}
+
+/// Returns the number of days since 1970-01-01 of the first day of a month.
+fn days_from_civil(year: u64, month: u64) -> u64 {
+    let year = year - u64::from(month <= 2);
+    let era = year / 400;
+    let yoe = year - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::tests::TestRepositories;
+    use candid::{Nat, Principal};
+    use cashier_backend_types::repository::{
+        action::v1::{ActionState, ActionType},
+        common::{Asset, Wallet},
+        intent::v1::{IntentType, TransferFromData},
+        link::v1::{LinkState, LinkType},
+    };
+    use cashier_common::test_utils::{random_id_string, random_principal_id};
+
+    const SECOND: u64 = 1_000_000_000;
+
+    fn fee_entry(token_id: Principal, amount: u64, collected_at: u64) -> FeeLedgerEntry {
+        FeeLedgerEntry {
+            intent_id: random_id_string(),
+            action_id: random_id_string(),
+            link_id: random_id_string(),
+            link_type: LinkType::SendTip,
+            payer: random_principal_id(),
+            token_id,
+            amount: Nat::from(amount),
+            collected_at,
+        }
+    }
+
+    fn fee_intent(token_id: Principal, amount: u64, state: IntentState) -> Intent {
+        Intent {
+            id: random_id_string(),
+            state,
+            task: IntentTask::TransferWalletToTreasury,
+            r#type: IntentType::TransferFrom(TransferFromData {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                spender: Wallet::default(),
+                asset: Asset::IC { address: token_id },
+                amount: Nat::from(amount),
+                actual_amount: None,
+                approve_amount: None,
+            }),
+            ..Intent::default()
+        }
+    }
+
+    fn create_link_action(link: &Link) -> Action {
+        Action {
+            id: random_id_string(),
+            r#type: ActionType::CreateLink,
+            state: ActionState::Success,
+            creator: link.creator,
+            link_id: link.id.clone(),
+            top_up_count: None,
+        }
+    }
+
+    fn link() -> Link {
+        Link {
+            id: random_id_string(),
+            state: LinkState::Active,
+            title: "Test Link".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![],
+            creator: random_principal_id(),
+            create_at: 0,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 1,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+
+    #[test]
+    fn it_should_compute_period_bounds() {
+        // 2024-02-29 12:00:00 UTC
+        let ts = 1_709_208_000 * SECOND;
+
+        assert_eq!(
+            period_bounds(RevenuePeriod::Day, ts),
+            (1_709_164_800 * SECOND, 1_709_251_200 * SECOND)
+        );
+        assert_eq!(
+            period_bounds(RevenuePeriod::Week, ts),
+            (1_708_905_600 * SECOND, 1_709_510_400 * SECOND)
+        );
+        assert_eq!(
+            period_bounds(RevenuePeriod::Month, ts),
+            (1_706_745_600 * SECOND, 1_709_251_200 * SECOND)
+        );
+        // 2024-12-15 00:00:00 UTC rolls over to the next year
+        assert_eq!(
+            period_bounds(RevenuePeriod::Month, 1_734_220_800 * SECOND),
+            (1_733_011_200 * SECOND, 1_735_689_600 * SECOND)
+        );
+    }
+
+    #[test]
+    fn it_should_record_fee_once_when_fee_intent_succeeds() {
+        // Arrange
+        let mut service = FeeLedgerService::new(&TestRepositories::new());
+        let link = link();
+        let action = create_link_action(&link);
+        let token_id = random_principal_id();
+        let pending = fee_intent(token_id, 10_000, IntentState::Processing);
+        let succeeded = Intent {
+            state: IntentState::Success,
+            ..pending.clone()
+        };
+
+        // Act
+        service.record_collected_fees(
+            &link,
+            &action,
+            &[pending],
+            std::slice::from_ref(&succeeded),
+            42,
+        );
+        service.record_collected_fees(
+            &link,
+            &action,
+            std::slice::from_ref(&succeeded),
+            std::slice::from_ref(&succeeded),
+            43,
+        );
+
+        // Assert
+        let entries = service
+            .get_entries(0, 100, &PaginateInput::default())
+            .unwrap()
+            .data;
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.intent_id, succeeded.id);
+        assert_eq!(entry.action_id, action.id);
+        assert_eq!(entry.link_id, link.id);
+        assert_eq!(entry.link_type, LinkType::SendAirdrop);
+        assert_eq!(entry.payer, link.creator);
+        assert_eq!(entry.token_id, token_id);
+        assert_eq!(entry.amount, Nat::from(10_000u64));
+        assert_eq!(entry.collected_at, 42);
+    }
+
+    #[test]
+    fn it_should_not_record_failed_fee_intent() {
+        // Arrange
+        let mut service = FeeLedgerService::new(&TestRepositories::new());
+        let link = link();
+        let action = create_link_action(&link);
+        let failed = fee_intent(random_principal_id(), 10_000, IntentState::Fail);
+
+        // Act
+        service.record_collected_fees(&link, &action, &[], &[failed], 42);
+
+        // Assert
+        assert!(
+            service
+                .get_entries(0, 100, &PaginateInput::default())
+                .unwrap()
+                .data
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn it_should_aggregate_revenue_by_token_and_day() {
+        // Arrange
+        let mut service = FeeLedgerService::new(&TestRepositories::new());
+        let icp = random_principal_id();
+        let ckbtc = random_principal_id();
+        let day = NANOS_PER_DAY;
+        service
+            .fee_ledger_repo
+            .create(fee_entry(icp, 10_000, day + 1));
+        service
+            .fee_ledger_repo
+            .create(fee_entry(icp, 20_000, day + 2));
+        service
+            .fee_ledger_repo
+            .create(fee_entry(ckbtc, 1_000, day + 3));
+        service
+            .fee_ledger_repo
+            .create(fee_entry(icp, 30_000, 2 * day + 1));
+        service
+            .fee_ledger_repo
+            .create(fee_entry(icp, 40_000, 4 * day));
+
+        // Act
+        let revenue = service
+            .get_revenue(day, 3 * day, RevenuePeriod::Day)
+            .unwrap();
+
+        // Assert
+        assert_eq!(revenue.len(), 2);
+        assert_eq!(revenue[0].period_start, day);
+        assert_eq!(revenue[0].period_end, 2 * day);
+        assert_eq!(revenue[0].tokens.len(), 2);
+        let icp_revenue = revenue[0]
+            .tokens
+            .iter()
+            .find(|token| token.token_id == icp)
+            .unwrap();
+        assert_eq!(icp_revenue.amount, Nat::from(30_000u64));
+        assert_eq!(icp_revenue.fee_count, 2);
+        let ckbtc_revenue = revenue[0]
+            .tokens
+            .iter()
+            .find(|token| token.token_id == ckbtc)
+            .unwrap();
+        assert_eq!(ckbtc_revenue.amount, Nat::from(1_000u64));
+        assert_eq!(ckbtc_revenue.fee_count, 1);
+        assert_eq!(revenue[1].period_start, 2 * day);
+        assert_eq!(
+            revenue[1].tokens,
+            vec![TokenRevenueDto {
+                token_id: icp,
+                amount: Nat::from(30_000u64),
+                fee_count: 1,
+            }]
+        );
+    }
+
+    #[test]
+    fn it_should_cut_revenue_periods_to_range() {
+        // Arrange
+        let mut service = FeeLedgerService::new(&TestRepositories::new());
+        // 2024-02-29 12:00:00 UTC
+        let ts = 1_709_208_000 * SECOND;
+        service
+            .fee_ledger_repo
+            .create(fee_entry(random_principal_id(), 10_000, ts));
+
+        // Act
+        let revenue = service
+            .get_revenue(ts - SECOND, ts + SECOND, RevenuePeriod::Month)
+            .unwrap();
+
+        // Assert
+        assert_eq!(revenue.len(), 1);
+        assert_eq!(revenue[0].period_start, ts - SECOND);
+        assert_eq!(revenue[0].period_end, ts + SECOND);
+    }
+
+    #[test]
+    fn it_should_error_on_empty_range() {
+        // Arrange
+        let service = FeeLedgerService::new(&TestRepositories::new());
+
+        // Act
+        let result = service.get_revenue(100, 100, RevenuePeriod::Day);
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+    }
+}
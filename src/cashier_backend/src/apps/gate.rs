@@ -0,0 +1,58 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+//! Inter-canister calls to the gate_service canister.
+
+use candid::Principal;
+use cashier_backend_types::error::CanisterError;
+use gate_service_types::{Gate, GateForUser, NewGate, error::GateServiceError};
+use ic_cdk::call::{Call, CandidDecodeFailed};
+
+pub struct GateServiceClient(pub Principal);
+
+impl GateServiceClient {
+    pub fn new(principal: Principal) -> Self {
+        GateServiceClient(principal)
+    }
+
+    /// Calls the add_gate method on the gate_service canister.
+    /// The calling canister must have the `GateCreate` permission.
+    /// # Arguments
+    /// * `new_gate` - The gate to be created
+    /// # Returns
+    /// * `Result<Gate, CanisterError>` - The created gate or an error if the call fails
+    pub async fn add_gate(&self, new_gate: &NewGate) -> Result<Gate, CanisterError> {
+        let res = Call::bounded_wait(self.0, "add_gate")
+            .with_arg(new_gate)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Result<Gate, GateServiceError>, CandidDecodeFailed> = res.candid();
+
+        parsed_res
+            .map_err(CanisterError::from)?
+            .map_err(|e| CanisterError::CallCanisterFailed(format!("add_gate failed: {e}")))
+    }
+
+    /// Calls the get_gate_for_user method on the gate_service canister
+    /// # Arguments
+    /// * `gate_id` - The ID of the gate
+    /// * `user` - The user whose gate status is requested
+    /// # Returns
+    /// * `Result<GateForUser, CanisterError>` - The gate with its user status or an error if the call fails
+    pub async fn get_gate_for_user(
+        &self,
+        gate_id: &str,
+        user: Principal,
+    ) -> Result<GateForUser, CanisterError> {
+        let res = Call::bounded_wait(self.0, "get_gate_for_user")
+            .with_args(&(gate_id, user))
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Result<GateForUser, GateServiceError>, CandidDecodeFailed> =
+            res.candid();
+
+        parsed_res.map_err(CanisterError::from)?.map_err(|e| {
+            CanisterError::CallCanisterFailed(format!("get_gate_for_user failed: {e}"))
+        })
+    }
+}
@@ -2,19 +2,20 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::apps::link_v2::links::{
-    airdrop_link::AirdropLink, payment_link::PaymentLink, tip_link::TipLink,
-    token_basket_link::TokenBasketLink, traits::LinkV2,
+    airdrop_link::AirdropLink, payment_link::PaymentLink, random_airdrop_link::RandomAirdropLink,
+    split_payment_link::SplitPaymentLink, tip_link::TipLink, token_basket_link::TokenBasketLink,
+    traits::LinkV2,
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     dto::link::{CreateLinkInput, LinkDetailUpdateAssetInfoInput},
     error::CanisterError,
     repository::{
         asset_info::AssetInfo,
-        link::v1::{Link, LinkType},
+        link::v1::{Link, LinkType, SPLIT_TOTAL_WEIGHT_BPS},
     },
 };
-use std::rc::Rc;
+use std::{collections::BTreeSet, rc::Rc};
 use transaction_manager::traits::TransactionManager;
 
 pub struct LinkFactory<M: TransactionManager + 'static> {
@@ -48,48 +49,229 @@ impl<M: TransactionManager + 'static> LinkFactory<M> {
             .map(LinkDetailUpdateAssetInfoInput::to_model)
             .collect();
 
-        match input.link_type {
-            LinkType::SendTip => Ok(TipLink::create(
-                creator,
-                input.title,
-                asset_info,
-                input.link_use_action_max_count,
-                created_at_ts,
-                canister_id,
-                self.transaction_manager.clone(),
-            )
-            .link),
-            LinkType::SendAirdrop => Ok(AirdropLink::create(
-                creator,
-                input.title,
-                asset_info,
-                input.link_use_action_max_count,
-                created_at_ts,
-                canister_id,
-                self.transaction_manager.clone(),
-            )
-            .link),
-            LinkType::SendTokenBasket => Ok(TokenBasketLink::create(
-                creator,
-                input.title,
-                asset_info,
-                input.link_use_action_max_count,
-                created_at_ts,
-                canister_id,
-                self.transaction_manager.clone(),
-            )
-            .link),
-            LinkType::ReceivePayment => Ok(PaymentLink::create(
-                creator,
-                input.title,
-                asset_info,
-                input.link_use_action_max_count,
-                created_at_ts,
-                canister_id,
-                self.transaction_manager.clone(),
+        if let Some(expires_at) = input.expires_at
+            && expires_at <= created_at_ts
+        {
+            return Err(CanisterError::InvalidInput(
+                "expires_at must be later than the link creation time".to_string(),
+            ));
+        }
+
+        if let (Some(starts_at), Some(expires_at)) = (input.starts_at, input.expires_at)
+            && starts_at >= expires_at
+        {
+            return Err(CanisterError::InvalidInput(
+                "starts_at must be earlier than expires_at".to_string(),
+            ));
+        }
+
+        if (input.max_use_per_user.is_some() || input.allow_list.is_some())
+            && !matches!(
+                input.link_type,
+                LinkType::SendAirdrop | LinkType::SendRandomAirdrop
             )
-            .link),
+        {
+            return Err(CanisterError::InvalidInput(
+                "max_use_per_user and allow_list are only supported for airdrop links".to_string(),
+            ));
         }
+
+        if input.max_use_per_user == Some(0) {
+            return Err(CanisterError::InvalidInput(
+                "max_use_per_user must be greater than 0".to_string(),
+            ));
+        }
+
+        if input.allow_list.as_ref().is_some_and(Vec::is_empty) {
+            return Err(CanisterError::InvalidInput(
+                "allow_list must not be empty".to_string(),
+            ));
+        }
+
+        if input.link_type == LinkType::SendRandomAirdrop
+            && asset_info
+                .iter()
+                .any(|asset| asset.amount_per_link_use_action == 0u64)
+        {
+            return Err(CanisterError::InvalidInput(
+                "amount_per_link_use_action must be greater than 0 for random airdrop links"
+                    .to_string(),
+            ));
+        }
+
+        match (&input.split_recipients, input.link_type) {
+            (None, LinkType::ReceiveSplitPayment) => {
+                return Err(CanisterError::InvalidInput(
+                    "split_recipients are required for split payment links".to_string(),
+                ));
+            }
+            (Some(_), link_type) if link_type != LinkType::ReceiveSplitPayment => {
+                return Err(CanisterError::InvalidInput(
+                    "split_recipients are only supported for split payment links".to_string(),
+                ));
+            }
+            (Some(split_recipients), _) => {
+                if split_recipients.is_empty()
+                    || split_recipients
+                        .iter()
+                        .any(|recipient| recipient.weight_bps == 0)
+                {
+                    return Err(CanisterError::InvalidInput(
+                        "split_recipients must have at least one recipient with a weight greater than 0"
+                            .to_string(),
+                    ));
+                }
+
+                let total_weight_bps: u32 = split_recipients
+                    .iter()
+                    .map(|recipient| u32::from(recipient.weight_bps))
+                    .sum();
+                if total_weight_bps != u32::from(SPLIT_TOTAL_WEIGHT_BPS) {
+                    return Err(CanisterError::InvalidInput(format!(
+                        "split_recipients weights must add up to {SPLIT_TOTAL_WEIGHT_BPS} basis points"
+                    )));
+                }
+            }
+            (None, _) => {}
+        }
+
+        if let Some(amount_bounds) = &input.amount_bounds {
+            if input.link_type != LinkType::ReceivePayment || asset_info.len() != 1 {
+                return Err(CanisterError::InvalidInput(
+                    "amount_bounds are only supported for payment links with a single asset"
+                        .to_string(),
+                ));
+            }
+
+            if amount_bounds.min == 0u64 || amount_bounds.min > amount_bounds.max {
+                return Err(CanisterError::InvalidInput(
+                    "amount_bounds min must be greater than 0 and not greater than max".to_string(),
+                ));
+            }
+        }
+
+        if asset_info
+            .iter()
+            .any(|asset| asset.token_ids.is_some() && !asset.asset.is_nft())
+        {
+            return Err(CanisterError::InvalidInput(
+                "token_ids are only supported for NFT assets".to_string(),
+            ));
+        }
+
+        if asset_info.iter().any(|asset| asset.asset.is_nft()) {
+            if !matches!(input.link_type, LinkType::SendTip | LinkType::SendAirdrop) {
+                return Err(CanisterError::InvalidInput(
+                    "NFT assets are only supported for tip and airdrop links".to_string(),
+                ));
+            }
+
+            let [nft_asset] = asset_info.as_slice() else {
+                return Err(CanisterError::InvalidInput(
+                    "NFT links must have a single asset".to_string(),
+                ));
+            };
+
+            if nft_asset.amount_per_link_use_action != 1u64 {
+                return Err(CanisterError::InvalidInput(
+                    "amount_per_link_use_action must be 1 for NFT assets".to_string(),
+                ));
+            }
+
+            // each use sends one of the deposited tokens
+            let token_ids = nft_asset.token_ids.clone().unwrap_or_default();
+            let unique_token_ids: BTreeSet<&Nat> = token_ids.iter().collect();
+            if token_ids.len() as u64 != input.link_use_action_max_count
+                || unique_token_ids.len() != token_ids.len()
+            {
+                return Err(CanisterError::InvalidInput(
+                    "token_ids must list one distinct NFT per link use".to_string(),
+                ));
+            }
+        }
+
+        let mut link = match input.link_type {
+            LinkType::SendTip => {
+                TipLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+            LinkType::SendAirdrop => {
+                AirdropLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+            LinkType::SendTokenBasket => {
+                TokenBasketLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+            LinkType::SendRandomAirdrop => {
+                RandomAirdropLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+            LinkType::ReceiveSplitPayment => {
+                SplitPaymentLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    input.split_recipients.clone().unwrap_or_default(),
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+            LinkType::ReceivePayment => {
+                PaymentLink::create(
+                    creator,
+                    input.title,
+                    asset_info,
+                    input.link_use_action_max_count,
+                    created_at_ts,
+                    canister_id,
+                    self.transaction_manager.clone(),
+                )
+                .link
+            }
+        };
+        link.expires_at = input.expires_at;
+        link.starts_at = input.starts_at;
+        link.max_use_per_user = input.max_use_per_user;
+        link.allow_list = input.allow_list;
+        link.amount_bounds = input.amount_bounds;
+
+        Ok(link)
     }
 
     /// Converts a Link model to a corresponding LinkV2 instance.
@@ -118,6 +300,16 @@ impl<M: TransactionManager + 'static> LinkFactory<M> {
                 canister_id,
                 self.transaction_manager.clone(),
             ))),
+            LinkType::SendRandomAirdrop => Ok(Box::new(RandomAirdropLink::new(
+                link,
+                canister_id,
+                self.transaction_manager.clone(),
+            ))),
+            LinkType::ReceiveSplitPayment => Ok(Box::new(SplitPaymentLink::new(
+                link,
+                canister_id,
+                self.transaction_manager.clone(),
+            ))),
             LinkType::ReceivePayment => Ok(Box::new(PaymentLink::new(
                 link,
                 canister_id,
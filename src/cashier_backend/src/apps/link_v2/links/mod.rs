@@ -4,7 +4,9 @@
 pub mod airdrop_link;
 pub mod factory;
 pub mod payment_link;
+pub mod random_airdrop_link;
 pub mod shared;
+pub mod split_payment_link;
 pub mod tip_link;
 pub mod token_basket_link;
 pub mod traits;
@@ -5,15 +5,17 @@ use crate::apps::link_v2::links::{
     shared::receive_link::states::{
         active::ActiveState, created::CreatedState, inactive::InactiveState,
     },
+    shared::state_handler,
     traits::{LinkV2, LinkV2State},
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
         asset_info::AssetInfo,
+        common::Wallet,
         intent::v1::Intent,
         link::v1::{Link, LinkState, LinkType},
         transaction::v1::Transaction,
@@ -68,6 +70,17 @@ impl<M: TransactionManager + 'static> PaymentLink<M> {
             creator,
             state: LinkState::CreateLink,
             create_at: created_at_ts,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
 
         Self::new(new_link, canister_id, transaction_manager)
@@ -83,28 +96,32 @@ impl<M: TransactionManager + 'static> PaymentLink<M> {
     pub fn get_state_handler(
         link: &Link,
         canister_id: Principal,
-        transaction_manager: Rc<M>,
+        transaction_manager: &Rc<M>,
     ) -> Result<Box<dyn LinkV2State>, CanisterError> {
-        match link.state {
-            LinkState::CreateLink => Ok(Box::new(CreatedState::new(
-                link,
-                canister_id,
-                transaction_manager,
-            ))),
-            LinkState::Active => Ok(Box::new(ActiveState::new(
-                link,
-                canister_id,
-                transaction_manager,
-            ))),
-            LinkState::Inactive => Ok(Box::new(InactiveState::new(
-                link,
-                canister_id,
-                transaction_manager,
-            ))),
-            _ => Err(CanisterError::ValidationErrors(
-                "Unsupported link state".to_string(),
-            )),
-        }
+        state_handler::get_state_handler(
+            link,
+            || {
+                Box::new(CreatedState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+            || {
+                Box::new(ActiveState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+            || {
+                Box::new(InactiveState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+        )
     }
 }
 
@@ -113,20 +130,36 @@ impl<M: TransactionManager + 'static> LinkV2 for PaymentLink<M> {
     /// # Arguments
     /// * `caller` - The caller principal.
     /// * `action_type` - The type of action to be created.
+    /// * `amount` - The amount chosen by the caller, for links accepting one.
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC withdrawals
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
     /// # Returns
     /// * `Pin<Box<dyn Future<Output = Result<CreateActionResult, CanisterError>>>>` - A future that resolves to the resulting action or an error if the creation fails.
     fn create_action(
         &self,
         caller: Principal,
         action_type: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         let link = self.link.clone();
         let canister_id = self.canister_id;
         let transaction_manager = self.transaction_manager.clone();
 
         Box::pin(async move {
-            let state = PaymentLink::get_state_handler(&link, canister_id, transaction_manager)?;
-            let create_action_result = state.create_action(caller, action_type).await?;
+            let state = PaymentLink::get_state_handler(&link, canister_id, &transaction_manager)?;
+            let create_action_result = state
+                .create_action(
+                    caller,
+                    action_type,
+                    amount,
+                    top_up_use_count,
+                    btc_address,
+                    destination,
+                )
+                .await?;
             Ok(create_action_result)
         })
     }
@@ -151,7 +184,7 @@ impl<M: TransactionManager + 'static> LinkV2 for PaymentLink<M> {
         let transaction_manager = self.transaction_manager.clone();
 
         Box::pin(async move {
-            let state = PaymentLink::get_state_handler(&link, canister_id, transaction_manager)?;
+            let state = PaymentLink::get_state_handler(&link, canister_id, &transaction_manager)?;
             let process_action_result = state
                 .process_action(caller, action, intents, intent_txs_map)
                 .await?;
@@ -0,0 +1,201 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::api::state::get_state;
+use crate::apps::link_v2::links::{
+    shared::send_link::{actions::receive::ReceiveAction, states::active::ActiveState},
+    traits::LinkV2State,
+};
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
+    repository::{
+        action::v1::{Action, ActionType},
+        common::Wallet,
+        intent::v1::Intent,
+        link::v1::Link,
+        transaction::v1::Transaction,
+    },
+};
+use cashier_common::{constant::CLAIM_RESERVATION_TTL_NS, random, runtime::IcEnvironment};
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+use transaction_manager::{traits::TransactionManager, utils::calculator::calculate_random_share};
+
+/// Active state of a random-amount airdrop link.
+/// Each RECEIVE action reserves a random share of the remaining pot when it is created,
+/// so concurrent claims can never overdraw the link. A share left unprocessed past its
+/// reservation is given back to the pot and its claim can no longer be processed.
+pub struct RandomActiveState<M: TransactionManager + 'static> {
+    pub link: Link,
+    pub canister_id: Principal,
+    pub transaction_manager: Rc<M>,
+}
+
+impl<M: TransactionManager + 'static> RandomActiveState<M> {
+    pub fn new(link: &Link, canister_id: Principal, transaction_manager: Rc<M>) -> Self {
+        Self {
+            link: link.clone(),
+            canister_id,
+            transaction_manager,
+        }
+    }
+
+    /// Create RECEIVE action for the random airdrop link
+    /// # Arguments
+    /// * `caller` - The principal of the user creating the action
+    /// * `link` - The random airdrop link for which the action is being created
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC links
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
+    /// * `transaction_manager` - The transaction manager to handle action creation
+    /// # Returns
+    /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the RECEIVE action,
+    ///   with the link pot reduced by the reserved share
+    pub async fn create_receive_action(
+        caller: Principal,
+        link: Link,
+        canister_id: Principal,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+        transaction_manager: Rc<M>,
+    ) -> Result<LinkCreateActionResult, CanisterError> {
+        ActiveState::<M>::ensure_can_receive(&link, caller).await?;
+
+        // reload the link after the last await so concurrent claims see each other's reservations
+        let mut link = get_state()
+            .link_v2_service
+            .link_repository
+            .get(&link.id)
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+        let mut random_pot = link.random_pot.clone().ok_or_else(|| {
+            CanisterError::HandleLogicError("Random pot not found for this link".to_string())
+        })?;
+
+        if random_pot.remaining_claims == 0 {
+            return Err(CanisterError::ValidationErrors(
+                "All shares of this link have been claimed".to_string(),
+            ));
+        }
+
+        let shares = random_pot
+            .remaining_amounts
+            .iter()
+            .map(|remaining_amount| {
+                let random = random::next_u64().ok_or_else(|| {
+                    CanisterError::HandleLogicError(
+                        "Random number generator is not initialized".to_string(),
+                    )
+                })?;
+                Ok(calculate_random_share(
+                    remaining_amount,
+                    random_pot.remaining_claims,
+                    random,
+                ))
+            })
+            .collect::<Result<Vec<Nat>, CanisterError>>()?;
+
+        let receive_action = ReceiveAction::create_with_amounts(
+            &link,
+            caller,
+            canister_id,
+            &shares,
+            btc_address,
+            destination,
+        )?;
+        let create_action_result = transaction_manager
+            .create_action(receive_action.action, receive_action.intents, None)
+            .await?;
+
+        random_pot.reserve(
+            &create_action_result.action.id,
+            shares,
+            get_state()
+                .env
+                .time()
+                .saturating_add(CLAIM_RESERVATION_TTL_NS),
+        );
+        link.random_pot = Some(random_pot);
+
+        Ok(LinkCreateActionResult {
+            link,
+            create_action_result,
+        })
+    }
+}
+
+impl<M: TransactionManager + 'static> LinkV2State for RandomActiveState<M> {
+    fn create_action(
+        &self,
+        caller: Principal,
+        action_type: ActionType,
+        _amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let canister_id = self.canister_id;
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            match action_type {
+                ActionType::Receive => {
+                    let create_action_result = Self::create_receive_action(
+                        caller,
+                        link,
+                        canister_id,
+                        btc_address,
+                        destination,
+                        transaction_manager,
+                    )
+                    .await?;
+                    Ok(create_action_result)
+                }
+                _ => Err(CanisterError::ValidationErrors(
+                    "Unsupported action type for ActiveState".to_string(),
+                )),
+            }
+        })
+    }
+
+    fn process_action(
+        &self,
+        _caller: Principal,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            match action.r#type {
+                ActionType::Receive => {
+                    let is_reserved = link
+                        .random_pot
+                        .as_ref()
+                        .is_some_and(|random_pot| random_pot.is_reserved(&action.id));
+                    if !is_reserved {
+                        return Err(CanisterError::ValidationErrors(
+                            "The share of this claim has expired, please claim again".to_string(),
+                        ));
+                    }
+
+                    let receive_result = ActiveState::receive(
+                        &link,
+                        action,
+                        intents,
+                        intent_txs_map,
+                        transaction_manager,
+                    )
+                    .await?;
+                    Ok(receive_result)
+                }
+                _ => Err(CanisterError::ValidationErrors(
+                    "Unsupported action type for ActiveState".to_string(),
+                )),
+            }
+        })
+    }
+}
@@ -1,3 +1,4 @@
 pub mod receive_link;
 pub mod send_link;
+pub mod state_handler;
 pub mod utils;
@@ -3,23 +3,15 @@
 
 use candid::Principal;
 use cashier_backend_types::{
-    constant::INTENT_LABEL_LINK_CREATION_FEE,
     error::CanisterError,
     repository::{
         action::v1::{Action, ActionState, ActionType},
-        common::Asset,
         intent::v1::Intent,
         link::v1::Link,
     },
 };
-use cashier_common::{constant::ICP_CANISTER_PRINCIPAL, utils::convert_nat_to_u64};
-use icrc_ledger_types::icrc1::account::Account;
-use transaction_manager::{
-    intents::transfer_wallet_to_treasury::TransferWalletToTreasuryIntent,
-    utils::calculator::calculate_create_link_fee,
-};
 
-use crate::apps::link_v2::links::shared::utils::get_batch_tokens_fee_for_link;
+use crate::apps::link_v2::links::shared::utils::create_link_fee_intent;
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -46,34 +38,14 @@ impl CreateAction {
             link_id: link.id.clone(),
             creator: link.creator,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
-        // token_fee_map
-        let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
-
         // intents
-        let fee_asset = Asset::IC {
-            address: ICP_CANISTER_PRINCIPAL,
-        };
-        let (actual_amount, approval_amount) = calculate_create_link_fee(&token_fee_map);
-        let actual_amount = convert_nat_to_u64(&actual_amount)?;
-        let approval_amount = convert_nat_to_u64(&approval_amount)?;
-        let spender_account = Account {
-            owner: canister_id,
-            subaccount: None,
-        };
-
-        let fee_intent = TransferWalletToTreasuryIntent::create(
-            INTENT_LABEL_LINK_CREATION_FEE.to_string(),
-            fee_asset,
-            actual_amount,
-            approval_amount,
-            link.creator,
-            spender_account,
-            link.create_at,
-        )?;
-
-        let intents = vec![fee_intent.intent];
+        let intents = create_link_fee_intent(link, canister_id)
+            .await?
+            .into_iter()
+            .collect();
         Ok(Self::new(action, intents))
     }
 }
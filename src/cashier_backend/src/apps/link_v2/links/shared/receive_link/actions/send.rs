@@ -1,13 +1,13 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     constant::INTENT_LABEL_SEND_TIP_ASSET,
     error::CanisterError,
     repository::{
         action::v1::{Action, ActionState, ActionType},
-        common::Asset,
+        asset_info::AssetInfo,
         intent::v1::Intent,
         link::v1::Link,
     },
@@ -37,19 +37,48 @@ impl SendAction {
     /// * `link` - The Link for which the action is created.
     /// * `sender_id` - The Principal ID of the caller.
     /// * `canister_id` - The canister ID of the token contract.
+    /// * `amount` - The amount chosen by the sender, required by variable-amount links.
     /// # Returns
     /// * `Result<SendAction, CanisterError>` - The resulting action or an error if the creation fails.
     pub async fn create(
         link: &Link,
         sender_id: Principal,
         canister_id: Principal,
+        amount: Option<Nat>,
     ) -> Result<Self, CanisterError> {
+        // variable-amount links pay the amount chosen by the sender instead of the fixed one
+        let asset_info = match (&link.amount_bounds, amount) {
+            (Some(bounds), Some(amount)) => {
+                if !bounds.contains(&amount) {
+                    return Err(CanisterError::InvalidInput(format!(
+                        "amount must be between {} and {}",
+                        bounds.min, bounds.max
+                    )));
+                }
+                link.asset_info
+                    .iter()
+                    .cloned()
+                    .map(|mut asset_info| {
+                        asset_info.amount_per_link_use_action = amount.clone();
+                        asset_info
+                    })
+                    .collect::<Vec<AssetInfo>>()
+            }
+            (Some(_), None) => {
+                return Err(CanisterError::InvalidInput(
+                    "amount is required for this link".to_string(),
+                ));
+            }
+            (None, _) => link.asset_info.clone(),
+        };
+
         let action = Action {
             id: Uuid::new_v4().to_string(),
             r#type: ActionType::Send,
             link_id: link.id.clone(),
             creator: sender_id,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
         let link_account = get_link_account(&link.id, canister_id)?;
@@ -57,20 +86,14 @@ impl SendAction {
         // token_fee_map
         let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
 
-        let link_token_balance_map = calculate_link_balance_map(
-            &link.asset_info,
-            &token_fee_map,
-            link.link_use_action_max_count,
-        );
+        let link_token_balance_map =
+            calculate_link_balance_map(&asset_info, &token_fee_map, link.link_use_action_max_count);
 
         // intents
-        let deposit_intents = link
-            .asset_info
+        let deposit_intents = asset_info
             .iter()
             .map(|asset_info| {
-                let address = match asset_info.asset {
-                    Asset::IC { address } => address,
-                };
+                let address = asset_info.asset.address();
 
                 let sending_amount = link_token_balance_map.get(&address).ok_or_else(|| {
                     CanisterError::HandleLogicError(
@@ -2,7 +2,6 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{Nat, Principal};
-use cashier_backend_types::repository::common::Asset;
 use cashier_backend_types::{
     constant::INTENT_LABEL_SEND_TIP_ASSET,
     error::CanisterError,
@@ -44,6 +43,7 @@ impl WithdrawAction {
             link_id: link.id.clone(),
             creator: link.creator,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
         let link_account = get_link_account(&link.id, canister_id)?;
@@ -55,9 +55,7 @@ impl WithdrawAction {
             .asset_info
             .iter()
             .map(|asset_info| {
-                let address = match asset_info.asset {
-                    Asset::IC { address } => address,
-                };
+                let address = asset_info.asset.address();
                 let sending_amount = actual_token_balance_map
                     .get(&address)
                     .cloned()
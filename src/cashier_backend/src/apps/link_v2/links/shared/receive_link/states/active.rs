@@ -4,12 +4,13 @@
 use crate::apps::link_v2::links::{
     shared::receive_link::actions::send::SendAction, traits::LinkV2State,
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         link::v1::Link,
         transaction::v1::Transaction,
@@ -39,6 +40,7 @@ impl<M: TransactionManager + 'static> ActiveState<M> {
     /// * `link` - The tip link for which the action is being created
     /// * `canister_id` - The canister ID of the backend canister
     /// * `transaction_manager` - The transaction manager to handle action creation
+    /// * `amount` - The amount chosen by the caller, for variable-amount links
     /// # Returns
     /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the SEND action
     pub async fn create_send_action(
@@ -46,10 +48,12 @@ impl<M: TransactionManager + 'static> ActiveState<M> {
         link: Link,
         canister_id: Principal,
         transaction_manager: Rc<M>,
+        amount: Option<Nat>,
     ) -> Result<LinkCreateActionResult, CanisterError> {
-        let send_action = SendAction::create(&link, caller, canister_id).await?;
-        let create_action_result =
-            transaction_manager.create_action(send_action.action, send_action.intents, None)?;
+        let send_action = SendAction::create(&link, caller, canister_id, amount).await?;
+        let create_action_result = transaction_manager
+            .create_action(send_action.action, send_action.intents, None)
+            .await?;
 
         Ok(LinkCreateActionResult {
             link: link.clone(),
@@ -89,6 +93,10 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
         &self,
         caller: Principal,
         action_type: ActionType,
+        amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        _btc_address: Option<String>,
+        _destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         let link = self.link.clone();
         let canister_id = self.canister_id;
@@ -97,9 +105,14 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
         Box::pin(async move {
             match action_type {
                 ActionType::Send => {
-                    let create_action_result =
-                        Self::create_send_action(caller, link, canister_id, transaction_manager)
-                            .await?;
+                    let create_action_result = Self::create_send_action(
+                        caller,
+                        link,
+                        canister_id,
+                        transaction_manager,
+                        amount,
+                    )
+                    .await?;
                     Ok(create_action_result)
                 }
                 _ => Err(CanisterError::ValidationErrors(
@@ -1,20 +1,23 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use crate::api::state::get_state;
 use crate::apps::link_v2::links::{
     shared::receive_link::actions::create::CreateAction, traits::LinkV2State,
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         link::v1::{Link, LinkState},
         transaction::v1::Transaction,
     },
 };
+use cashier_common::runtime::IcEnvironment;
 use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
 use transaction_manager::traits::TransactionManager;
 
@@ -55,8 +58,9 @@ impl<M: TransactionManager + 'static> CreatedState<M> {
         }
 
         let create_action = CreateAction::create(&link, canister_id).await?;
-        let create_action_result =
-            transaction_manager.create_action(create_action.action, create_action.intents, None)?;
+        let create_action_result = transaction_manager
+            .create_action(create_action.action, create_action.intents, None)
+            .await?;
 
         Ok(LinkCreateActionResult {
             link: link.clone(),
@@ -94,9 +98,13 @@ impl<M: TransactionManager + 'static> CreatedState<M> {
             .process_action(action, intents, intent_txs_map)
             .await?;
 
-        // if process action succeeds, activate the link
+        // if process action succeeds, activate the link or wait for its start time
         if process_action_result.is_success {
-            link.state = LinkState::Active;
+            link.state = if link.is_started(get_state().env.time()) {
+                LinkState::Active
+            } else {
+                LinkState::Scheduled
+            };
         }
 
         Ok(LinkProcessActionResult {
@@ -111,6 +119,10 @@ impl<M: TransactionManager + 'static> LinkV2State for CreatedState<M> {
         &self,
         caller: Principal,
         action_type: ActionType,
+        _amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        _btc_address: Option<String>,
+        _destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         let link = self.link.clone();
         let canister_id = self.canister_id;
@@ -4,12 +4,13 @@
 use crate::apps::link_v2::links::{
     shared::receive_link::actions::withdraw::WithdrawAction, traits::LinkV2State,
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         link::v1::{Link, LinkState},
         transaction::v1::Transaction,
@@ -54,11 +55,9 @@ impl<M: TransactionManager + 'static> InactiveState<M> {
         }
 
         let withdraw_action = WithdrawAction::create(&link, canister_id).await?;
-        let create_action_result = transaction_manager.create_action(
-            withdraw_action.action,
-            withdraw_action.intents,
-            None,
-        )?;
+        let create_action_result = transaction_manager
+            .create_action(withdraw_action.action, withdraw_action.intents, None)
+            .await?;
 
         Ok(LinkCreateActionResult {
             link: link.clone(),
@@ -111,6 +110,10 @@ impl<M: TransactionManager + 'static> LinkV2State for InactiveState<M> {
         &self,
         caller: Principal,
         action_type: ActionType,
+        _amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        _btc_address: Option<String>,
+        _destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         let link = self.link.clone();
         let canister_id = self.canister_id;
@@ -1,31 +1,28 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
-    constant::{INTENT_LABEL_LINK_CREATION_FEE, INTENT_LABEL_SEND_TIP_ASSET},
+    constant::INTENT_LABEL_SEND_TIP_ASSET,
     error::CanisterError,
     repository::{
         action::v1::{Action, ActionState, ActionType},
-        common::Asset,
         intent::v1::Intent,
         link::v1::Link,
     },
 };
-use cashier_common::{
-    constant::ICP_CANISTER_PRINCIPAL,
-    utils::{convert_nat_to_u64, get_link_account},
-};
-use icrc_ledger_types::icrc1::account::Account;
+use cashier_common::utils::get_link_account;
 use transaction_manager::{
     intents::{
-        transfer_wallet_to_link::TransferWalletToLinkIntent,
-        transfer_wallet_to_treasury::TransferWalletToTreasuryIntent,
+        transfer_nft::TransferNftIntent, transfer_wallet_to_link::TransferWalletToLinkIntent,
     },
-    utils::calculator::{calculate_create_link_fee, calculate_link_balance_map},
+    utils::calculator::calculate_link_balance_map,
 };
 
-use crate::apps::link_v2::links::shared::utils::get_batch_tokens_fee_for_link;
+use crate::apps::link_v2::links::shared::utils::{
+    create_link_fee_intent, get_batch_tokens_fee_for_link,
+};
+use std::collections::HashMap;
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -46,78 +43,199 @@ impl CreateAction {
     /// # Returns
     /// * `Result<CreateAction, CanisterError>` - The resulting action or an error if the creation fails.
     pub async fn create(link: &Link, canister_id: Principal) -> Result<Self, CanisterError> {
+        // token_fee_map
+        let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
+        let fee_intent = create_link_fee_intent(link, canister_id).await?;
+
+        Self::build(link, canister_id, &token_fee_map, fee_intent)
+    }
+
+    /// Builds the CreateAction of a link from the token fees and its creation fee intent.
+    /// # Arguments
+    /// * `link` - The Link for which the action is created.
+    /// * `canister_id` - The canister ID of the token contract.
+    /// * `token_fee_map` - The ledger fee of each token of the link.
+    /// * `fee_intent` - The intent paying the creation fee, None if nothing is charged.
+    /// # Returns
+    /// * `Result<CreateAction, CanisterError>` - The resulting action or an error if the creation fails.
+    pub fn build(
+        link: &Link,
+        canister_id: Principal,
+        token_fee_map: &HashMap<Principal, Nat>,
+        fee_intent: Option<Intent>,
+    ) -> Result<Self, CanisterError> {
+        Self::build_batch(
+            std::slice::from_ref(link),
+            canister_id,
+            token_fee_map,
+            fee_intent,
+        )
+    }
+
+    /// Builds a single CreateAction funding several links created from the same template.
+    /// The action belongs to the first link and deposits the funds of every link, each
+    /// deposit being made for the link it funds.
+    /// # Arguments
+    /// * `links` - The Links funded by the action, the first one owns it.
+    /// * `canister_id` - The canister ID of the token contract.
+    /// * `token_fee_map` - The ledger fee of each token of the links.
+    /// * `fee_intent` - The intent paying the creation fee of all the links, None if nothing is charged.
+    /// # Returns
+    /// * `Result<CreateAction, CanisterError>` - The resulting action or an error if the creation fails.
+    pub fn build_batch(
+        links: &[Link],
+        canister_id: Principal,
+        token_fee_map: &HashMap<Principal, Nat>,
+        fee_intent: Option<Intent>,
+    ) -> Result<Self, CanisterError> {
+        let link = links
+            .first()
+            .ok_or_else(|| CanisterError::HandleLogicError("No link to fund".to_string()))?;
         let action = Action {
             id: Uuid::new_v4().to_string(),
             r#type: ActionType::CreateLink,
             link_id: link.id.clone(),
             creator: link.creator,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
-        let link_account = get_link_account(&link.id, canister_id)?;
+        // intents
+        let mut intents = Vec::<Intent>::new();
+        for link in links {
+            intents.extend(
+                Self::deposit_intents(link, canister_id, token_fee_map)?
+                    .into_iter()
+                    .map(|intent| Intent {
+                        link_id: Some(link.id.clone()),
+                        ..intent
+                    }),
+            );
+        }
+        intents.extend(fee_intent);
 
-        // token_fee_map
-        let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
+        Ok(Self::new(action, intents))
+    }
+
+    /// Builds the intents depositing the funds of a link into its account.
+    fn deposit_intents(
+        link: &Link,
+        canister_id: Principal,
+        token_fee_map: &HashMap<Principal, Nat>,
+    ) -> Result<Vec<Intent>, CanisterError> {
+        let link_account = get_link_account(&link.id, canister_id)?;
 
         let link_token_balance_map = calculate_link_balance_map(
             &link.asset_info,
-            &token_fee_map,
+            token_fee_map,
             link.link_use_action_max_count,
         );
 
-        // intents
-        let deposit_intents = link
-            .asset_info
-            .iter()
-            .map(|asset_info| {
-                let address = match asset_info.asset {
-                    Asset::IC { address } => address,
-                };
+        let mut deposit_intents = Vec::<Intent>::new();
+        for asset_info in link.asset_info.iter() {
+            // every NFT of the link is deposited with its own transfer
+            if asset_info.asset.is_nft() {
+                for token_id in asset_info.token_ids.iter().flatten() {
+                    let deposit_intent = TransferNftIntent::create_wallet_to_link(
+                        INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                        asset_info.asset.clone(),
+                        token_id.clone(),
+                        link.creator,
+                        link_account,
+                        link.create_at,
+                    )?;
+                    deposit_intents.push(deposit_intent.intent);
+                }
+                continue;
+            }
 
-                let sending_amount = link_token_balance_map.get(&address).ok_or_else(|| {
+            let sending_amount = link_token_balance_map
+                .get(&asset_info.asset.address())
+                .ok_or_else(|| {
                     CanisterError::HandleLogicError(
                         "Failed to get sending amount from balance map".to_string(),
                     )
                 })?;
 
-                TransferWalletToLinkIntent::create(
-                    INTENT_LABEL_SEND_TIP_ASSET.to_string(),
-                    asset_info.asset.clone(),
-                    sending_amount.clone(),
-                    link.creator,
-                    link_account,
-                    link.create_at,
-                )
-            })
-            .collect::<Result<Vec<TransferWalletToLinkIntent>, CanisterError>>()?;
-
-        let fee_asset = Asset::IC {
-            address: ICP_CANISTER_PRINCIPAL,
-        };
-        let (actual_amount, approval_amount) = calculate_create_link_fee(&token_fee_map);
-        let actual_amount = convert_nat_to_u64(&actual_amount)?;
-        let approval_amount = convert_nat_to_u64(&approval_amount)?;
-        let spender_account = Account {
-            owner: canister_id,
-            subaccount: None,
-        };
+            let deposit_intent = TransferWalletToLinkIntent::create(
+                INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                asset_info.asset.clone(),
+                sending_amount.clone(),
+                link.creator,
+                link_account,
+                link.create_at,
+            )?;
+            deposit_intents.push(deposit_intent.intent);
+        }
+
+        Ok(deposit_intents)
+    }
+}
 
-        let fee_intent = TransferWalletToTreasuryIntent::create(
-            INTENT_LABEL_LINK_CREATION_FEE.to_string(),
-            fee_asset,
-            actual_amount,
-            approval_amount,
-            link.creator,
-            spender_account,
-            link.create_at,
-        )?;
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::{
+        asset_info::AssetInfo,
+        common::Asset,
+        link::v1::{LinkState, LinkType},
+    };
+    use cashier_common::test_utils::random_principal_id;
 
-        let mut intents = Vec::<Intent>::new();
-        deposit_intents.iter().for_each(|dintent| {
-            intents.push(dintent.intent.clone());
-        });
-        intents.push(fee_intent.intent);
+    fn batch_link(creator: Principal, asset: &Asset) -> Link {
+        Link {
+            id: Uuid::new_v4().to_string(),
+            state: LinkState::CreateLink,
+            title: "Tip".to_string(),
+            link_type: LinkType::SendTip,
+            asset_info: vec![AssetInfo {
+                asset: asset.clone(),
+                amount_per_link_use_action: Nat::from(1_000u64),
+                label: "SEND_TIP_ASSET".to_string(),
+                token_ids: None,
+                reserved_token_ids: None,
+            }],
+            creator,
+            create_at: 0,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 1,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
 
-        Ok(Self::new(action, intents))
+    #[test]
+    fn it_should_make_each_batch_deposit_for_the_link_it_funds() {
+        // Arrange
+        let creator = random_principal_id();
+        let asset = Asset::IC {
+            address: random_principal_id(),
+        };
+        let links: Vec<Link> = (0..3).map(|_| batch_link(creator, &asset)).collect();
+        let fee_map = HashMap::from([(asset.address(), Nat::from(10u64))]);
+
+        // Act
+        let result =
+            CreateAction::build_batch(&links, random_principal_id(), &fee_map, None).unwrap();
+
+        // Assert
+        assert_eq!(result.action.link_id, links[0].id);
+        let intent_link_ids: Vec<Option<String>> = result
+            .intents
+            .iter()
+            .map(|intent| intent.link_id.clone())
+            .collect();
+        let link_ids: Vec<Option<String>> =
+            links.iter().map(|link| Some(link.id.clone())).collect();
+        assert_eq!(intent_link_ids, link_ids);
     }
 }
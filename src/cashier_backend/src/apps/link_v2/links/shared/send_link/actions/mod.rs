@@ -3,4 +3,5 @@
 
 pub mod create;
 pub mod receive;
+pub mod top_up;
 pub mod withdraw;
@@ -1,18 +1,23 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use candid::Principal;
+use crate::apps::link_v2::links::shared::utils::get_ckbtc_minter;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     constant::INTENT_LABEL_SEND_TIP_ASSET,
     error::CanisterError,
     repository::{
         action::v1::{Action, ActionState, ActionType},
+        common::{Asset, Wallet},
         intent::v1::Intent,
         link::v1::Link,
     },
 };
-use cashier_common::utils::get_link_account;
-use transaction_manager::intents::transfer_link_to_wallet::TransferLinkToWalletIntent;
+use cashier_common::{constant::ICP_CANISTER_PRINCIPAL, utils::get_link_account};
+use transaction_manager::intents::{
+    transfer_link_to_bitcoin::TransferLinkToBitcoinIntent,
+    transfer_link_to_wallet::TransferLinkToWalletIntent, transfer_nft::TransferNftIntent,
+};
 use uuid::Uuid;
 
 #[derive(Debug)]
@@ -31,48 +36,394 @@ impl ReceiveAction {
     /// * `link` - The Link for which the action is created.
     /// * `receiver_id` - The Principal ID of the receiver.
     /// * `canister_id` - The canister ID of the token contract.
+    /// * `btc_address` - The Bitcoin address paid instead of the receiver's wallet, for ckBTC links.
+    /// * `destination` - The wallet paid instead of the receiver's principal, an ICP account identifier for ICP links only.
     /// # Returns
     /// * `Result<ReceiveAction, CanisterError>` - The resulting action or an error if the creation fails.
     pub async fn create(
         link: &Link,
         receiver_id: Principal,
         canister_id: Principal,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+    ) -> Result<Self, CanisterError> {
+        let amounts = link
+            .asset_info
+            .iter()
+            .map(|asset_info| asset_info.amount_per_link_use_action.clone())
+            .collect::<Vec<Nat>>();
+
+        Self::create_with_amounts(
+            link,
+            receiver_id,
+            canister_id,
+            &amounts,
+            btc_address,
+            destination,
+        )
+    }
+
+    /// Creates a new ReceiveAction for a given Link, paying the given amounts.
+    /// NFT assets send the first token id not yet reserved by another claim instead of an amount.
+    /// # Arguments
+    /// * `link` - The Link for which the action is created.
+    /// * `receiver_id` - The Principal ID of the receiver.
+    /// * `canister_id` - The canister ID of the token contract.
+    /// * `amounts` - The amount sent for each asset, in the order of the link `asset_info`.
+    /// * `btc_address` - The Bitcoin address paid instead of the receiver's wallet, for ckBTC links.
+    /// * `destination` - The wallet paid instead of the receiver's principal, an ICP account identifier for ICP links only.
+    /// # Returns
+    /// * `Result<ReceiveAction, CanisterError>` - The resulting action or an error if the creation fails.
+    pub fn create_with_amounts(
+        link: &Link,
+        receiver_id: Principal,
+        canister_id: Principal,
+        amounts: &[Nat],
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
     ) -> Result<Self, CanisterError> {
+        if amounts.len() != link.asset_info.len() {
+            return Err(CanisterError::HandleLogicError(
+                "Receive amounts do not match the link assets".to_string(),
+            ));
+        }
+
         let action = Action {
             id: Uuid::new_v4().to_string(),
             r#type: ActionType::Receive,
             link_id: link.id.clone(),
             creator: receiver_id,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
         let link_account = get_link_account(&link.id, canister_id)?;
 
+        if let Some(btc_address) = btc_address {
+            let ckbtc_minter = get_ckbtc_minter()?;
+            let ckbtc_asset = Asset::IC {
+                address: ckbtc_minter.ledger_id,
+            };
+            let (asset_info, sending_amount) = match (link.asset_info.as_slice(), amounts) {
+                ([asset_info], [sending_amount]) if asset_info.asset == ckbtc_asset => {
+                    (asset_info, sending_amount)
+                }
+                _ => {
+                    return Err(CanisterError::InvalidInput(
+                        "Only ckBTC links can pay out to a Bitcoin address".to_string(),
+                    ));
+                }
+            };
+
+            let link_to_bitcoin_intent = TransferLinkToBitcoinIntent::create(
+                INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                asset_info.asset.clone(),
+                sending_amount.clone(),
+                btc_address,
+                ckbtc_minter.minter_id,
+                link_account,
+                link.create_at,
+            )?;
+
+            return Ok(Self::new(action, vec![link_to_bitcoin_intent.intent]));
+        }
+
+        let to_wallet = match destination {
+            Some(Wallet::IcpAccountIdentifier { account_identifier }) => {
+                let icp_asset = Asset::IC {
+                    address: ICP_CANISTER_PRINCIPAL,
+                };
+                if link
+                    .asset_info
+                    .iter()
+                    .any(|asset_info| asset_info.asset != icp_asset)
+                {
+                    return Err(CanisterError::InvalidInput(
+                        "Only ICP links can pay out to an ICP account identifier".to_string(),
+                    ));
+                }
+                Wallet::from_icp_account_identifier(&account_identifier)
+                    .map_err(CanisterError::InvalidInput)?
+            }
+            Some(wallet) => wallet,
+            None => Wallet::new(receiver_id),
+        };
+
         // intents
-        let link_to_wallet_intents = link
-            .asset_info
-            .iter()
-            .map(|asset_info| {
-                let sending_amount = asset_info.amount_per_link_use_action.clone();
+        let mut intents = Vec::<Intent>::new();
+        for (asset_info, sending_amount) in link.asset_info.iter().zip(amounts) {
+            let intent = if asset_info.asset.is_nft() {
+                let token_id = asset_info
+                    .token_ids
+                    .as_ref()
+                    .and_then(|token_ids| token_ids.first())
+                    .ok_or_else(|| {
+                        CanisterError::ValidationErrors(
+                            "All NFTs of this link have been claimed".to_string(),
+                        )
+                    })?;
 
-                TransferLinkToWalletIntent::create(
+                TransferNftIntent::create_link_to_wallet(
                     INTENT_LABEL_SEND_TIP_ASSET.to_string(),
                     asset_info.asset.clone(),
-                    sending_amount,
-                    receiver_id,
+                    token_id.clone(),
+                    to_wallet.clone(),
                     link_account,
                     link.create_at,
-                )
-            })
-            .collect::<Result<Vec<TransferLinkToWalletIntent>, CanisterError>>()?;
+                )?
+                .intent
+            } else {
+                TransferLinkToWalletIntent::create_to_wallet(
+                    INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                    asset_info.asset.clone(),
+                    sending_amount.clone(),
+                    to_wallet.clone(),
+                    link_account,
+                    link.create_at,
+                )?
+                .intent
+            };
+            intents.push(intent);
+        }
 
-        let mut intents = Vec::<Intent>::new();
-        link_to_wallet_intents
+        Ok(Self::new(action, intents))
+    }
+
+    /// Reserves the NFTs sent by a receive action, so the next claims send other token ids.
+    /// # Arguments
+    /// * `link` - The Link whose available token ids are updated.
+    /// * `action_id` - The ID of the receive action.
+    /// * `intents` - The intents of the receive action.
+    /// * `expires_at` - The time after which the token ids can be made available again.
+    /// # Returns
+    /// * `bool` - Whether any token id was reserved.
+    pub fn reserve_nft_token_ids(
+        link: &mut Link,
+        action_id: &str,
+        intents: &[Intent],
+        expires_at: u64,
+    ) -> bool {
+        let mut reserved = false;
+        for nft_transfer in intents
             .iter()
-            .for_each(|link_to_wallet_intent| {
-                intents.push(link_to_wallet_intent.intent.clone());
-            });
+            .filter_map(|intent| intent.r#type.as_nft_transfer())
+        {
+            for asset_info in link
+                .asset_info
+                .iter_mut()
+                .filter(|asset_info| asset_info.asset == nft_transfer.asset)
+            {
+                reserved |=
+                    asset_info.reserve_token_id(action_id, &nft_transfer.token_id, expires_at);
+            }
+        }
 
-        Ok(Self::new(action, intents))
+        reserved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::{
+        asset_info::AssetInfo,
+        link::v1::{LinkState, LinkType},
+    };
+    use cashier_common::test_utils::random_principal_id;
+
+    fn nft_link(token_ids: Vec<u64>) -> Link {
+        link_with_asset(AssetInfo {
+            asset: Asset::IcNft {
+                address: random_principal_id(),
+            },
+            amount_per_link_use_action: Nat::from(1u64),
+            label: "SEND_AIRDROP_ASSET".to_string(),
+            token_ids: Some(token_ids.into_iter().map(Nat::from).collect()),
+            reserved_token_ids: None,
+        })
+    }
+
+    fn tip_link(address: Principal) -> Link {
+        link_with_asset(AssetInfo {
+            asset: Asset::IC { address },
+            amount_per_link_use_action: Nat::from(10_000u64),
+            label: INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+            token_ids: None,
+            reserved_token_ids: None,
+        })
+    }
+
+    fn link_with_asset(asset_info: AssetInfo) -> Link {
+        Link {
+            id: Uuid::new_v4().to_string(),
+            state: LinkState::Active,
+            title: "Tickets".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![asset_info],
+            creator: random_principal_id(),
+            create_at: 0,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 2,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+
+    #[test]
+    fn it_should_send_and_reserve_the_next_nft() {
+        // Arrange
+        let mut link = nft_link(vec![4, 9]);
+        let receiver_id = random_principal_id();
+
+        // Act
+        let receive_action = ReceiveAction::create_with_amounts(
+            &link,
+            receiver_id,
+            random_principal_id(),
+            &[Nat::from(1u64)],
+            None,
+            None,
+        )
+        .unwrap();
+        let reserved = ReceiveAction::reserve_nft_token_ids(
+            &mut link,
+            &receive_action.action.id,
+            &receive_action.intents,
+            100,
+        );
+
+        // Assert
+        assert_eq!(receive_action.intents.len(), 1);
+        let nft_transfer = receive_action.intents[0].r#type.as_nft_transfer().unwrap();
+        assert_eq!(nft_transfer.token_id, Nat::from(4u64));
+        assert_eq!(nft_transfer.to, Wallet::new(receiver_id));
+        assert!(reserved);
+        assert_eq!(link.asset_info[0].token_ids, Some(vec![Nat::from(9u64)]));
+        assert!(link.asset_info[0].is_token_id_reserved(&receive_action.action.id));
+    }
+
+    #[test]
+    fn it_should_fail_when_all_nfts_are_claimed() {
+        // Arrange
+        let link = nft_link(vec![]);
+
+        // Act
+        let result = ReceiveAction::create_with_amounts(
+            &link,
+            random_principal_id(),
+            random_principal_id(),
+            &[Nat::from(1u64)],
+            None,
+            None,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::ValidationErrors(_))));
+    }
+
+    #[test]
+    fn it_should_pay_an_icp_account_identifier() {
+        // Arrange
+        let link = tip_link(ICP_CANISTER_PRINCIPAL);
+        let account_identifier = Wallet::new(random_principal_id())
+            .get_icp_account_identifier()
+            .unwrap()
+            .to_hex();
+
+        // Act
+        let receive_action = ReceiveAction::create_with_amounts(
+            &link,
+            random_principal_id(),
+            random_principal_id(),
+            &[Nat::from(10_000u64)],
+            None,
+            Some(Wallet::IcpAccountIdentifier {
+                account_identifier: account_identifier.to_uppercase(),
+            }),
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(receive_action.intents.len(), 1);
+        let transfer = receive_action.intents[0].r#type.as_transfer().unwrap();
+        assert_eq!(
+            transfer.to,
+            Wallet::IcpAccountIdentifier { account_identifier }
+        );
+    }
+
+    #[test]
+    fn it_should_reject_an_icp_account_identifier_for_other_tokens() {
+        // Arrange
+        let link = tip_link(random_principal_id());
+        let account_identifier = Wallet::new(random_principal_id())
+            .get_icp_account_identifier()
+            .unwrap()
+            .to_hex();
+
+        // Act
+        let result = ReceiveAction::create_with_amounts(
+            &link,
+            random_principal_id(),
+            random_principal_id(),
+            &[Nat::from(10_000u64)],
+            None,
+            Some(Wallet::IcpAccountIdentifier { account_identifier }),
+        );
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn it_should_reject_an_invalid_icp_account_identifier() {
+        // Arrange
+        let link = tip_link(ICP_CANISTER_PRINCIPAL);
+
+        // Act
+        let result = ReceiveAction::create_with_amounts(
+            &link,
+            random_principal_id(),
+            random_principal_id(),
+            &[Nat::from(10_000u64)],
+            None,
+            Some(Wallet::IcpAccountIdentifier {
+                account_identifier: "00".repeat(32),
+            }),
+        );
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+    }
+
+    #[test]
+    fn it_should_pay_an_account_with_subaccount() {
+        // Arrange
+        let link = tip_link(random_principal_id());
+        let destination = Wallet::new_with_subaccount(random_principal_id(), Some([3u8; 32]));
+
+        // Act
+        let receive_action = ReceiveAction::create_with_amounts(
+            &link,
+            random_principal_id(),
+            random_principal_id(),
+            &[Nat::from(10_000u64)],
+            None,
+            Some(destination.clone()),
+        )
+        .unwrap();
+
+        // Assert
+        let transfer = receive_action.intents[0].r#type.as_transfer().unwrap();
+        assert_eq!(transfer.to, destination);
     }
 }
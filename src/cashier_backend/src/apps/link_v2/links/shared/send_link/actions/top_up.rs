@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    constant::INTENT_LABEL_TOP_UP_LINK_ASSET,
+    error::CanisterError,
+    repository::{
+        action::v1::{Action, ActionState, ActionType},
+        intent::v1::{Intent, IntentType},
+        link::v1::Link,
+    },
+};
+use cashier_common::utils::get_link_account;
+use std::collections::HashMap;
+use transaction_manager::{
+    intents::transfer_wallet_to_link::TransferWalletToLinkIntent,
+    utils::calculator::calculate_link_balance_map,
+};
+
+use crate::apps::link_v2::links::shared::utils::get_batch_tokens_fee_for_link;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct TopUpAction {
+    pub action: Action,
+    pub intents: Vec<Intent>,
+}
+
+impl TopUpAction {
+    pub fn new(action: Action, intents: Vec<Intent>) -> Self {
+        Self { action, intents }
+    }
+
+    /// Creates a new TopUpAction for a given Link.
+    /// # Arguments
+    /// * `link` - The Link for which the action is created.
+    /// * `canister_id` - The canister ID of the token contract.
+    /// * `top_up_count` - The number of additional uses to fund.
+    /// * `ts` - The timestamp of the top-up.
+    /// # Returns
+    /// * `Result<TopUpAction, CanisterError>` - The resulting action or an error if the creation fails.
+    pub async fn create(
+        link: &Link,
+        canister_id: Principal,
+        top_up_count: u64,
+        ts: u64,
+    ) -> Result<Self, CanisterError> {
+        // NFT links hold a fixed set of tokens, one per use
+        if link
+            .asset_info
+            .iter()
+            .any(|asset_info| asset_info.asset.is_nft())
+        {
+            return Err(CanisterError::ValidationErrors(
+                "NFT links cannot be topped up".to_string(),
+            ));
+        }
+
+        let action = Action {
+            id: Uuid::new_v4().to_string(),
+            r#type: ActionType::TopUp,
+            link_id: link.id.clone(),
+            creator: link.creator,
+            state: ActionState::Created,
+            top_up_count: Some(top_up_count),
+        };
+
+        let link_account = get_link_account(&link.id, canister_id)?;
+        let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
+        let top_up_balance_map =
+            calculate_link_balance_map(&link.asset_info, &token_fee_map, top_up_count);
+
+        // intents
+        let deposit_intents = link
+            .asset_info
+            .iter()
+            .map(|asset_info| {
+                let sending_amount = top_up_balance_map
+                    .get(&asset_info.asset.address())
+                    .ok_or_else(|| {
+                        CanisterError::HandleLogicError(
+                            "Failed to get sending amount from balance map".to_string(),
+                        )
+                    })?;
+
+                TransferWalletToLinkIntent::create(
+                    INTENT_LABEL_TOP_UP_LINK_ASSET.to_string(),
+                    asset_info.asset.clone(),
+                    sending_amount.clone(),
+                    link.creator,
+                    link_account,
+                    ts,
+                )
+            })
+            .collect::<Result<Vec<TransferWalletToLinkIntent>, CanisterError>>()?;
+
+        let intents = deposit_intents
+            .into_iter()
+            .map(|deposit_intent| deposit_intent.intent)
+            .collect();
+
+        Ok(Self::new(action, intents))
+    }
+
+    /// Returns the number of uses requested by a top-up, checking that the deposit of every asset funds them.
+    /// # Arguments
+    /// * `link` - The Link being topped up.
+    /// * `action` - The top-up action.
+    /// * `intents` - The intents of the top-up action.
+    /// * `fee_map` - A map of token principal to its corresponding fee
+    /// # Returns
+    /// * `Result<u64, CanisterError>` - The number of uses funded by the top-up
+    pub fn top_up_count(
+        link: &Link,
+        action: &Action,
+        intents: &[Intent],
+        fee_map: &HashMap<Principal, Nat>,
+    ) -> Result<u64, CanisterError> {
+        let top_up_count = action.top_up_count.ok_or_else(|| {
+            CanisterError::HandleLogicError("Top-up action has no use count".to_string())
+        })?;
+
+        for asset_info in link.asset_info.iter() {
+            let deposited_amount = intents
+                .iter()
+                .find_map(|intent| match &intent.r#type {
+                    IntentType::Transfer(data) if data.asset == asset_info.asset => {
+                        Some(data.amount.clone())
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| {
+                    CanisterError::HandleLogicError("Top-up deposit intent not found".to_string())
+                })?;
+
+            let fee = fee_map
+                .get(&asset_info.asset.address())
+                .cloned()
+                .unwrap_or(Nat::from(0u64));
+            let amount_per_use = asset_info.amount_per_link_use_action.clone() + fee;
+            if deposited_amount < amount_per_use * top_up_count {
+                return Err(CanisterError::ValidationErrors(format!(
+                    "The top-up deposit of {} does not fund {} uses",
+                    asset_info.asset.address(),
+                    top_up_count
+                )));
+            }
+        }
+
+        Ok(top_up_count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::{
+        asset_info::AssetInfo,
+        common::Asset,
+        link::v1::{LinkState, LinkType},
+    };
+    use cashier_common::test_utils::random_principal_id;
+
+    fn top_up_link(amount_per_use: u64) -> Link {
+        Link {
+            id: Uuid::new_v4().to_string(),
+            state: LinkState::Active,
+            title: "Airdrop".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![AssetInfo {
+                asset: Asset::IC {
+                    address: random_principal_id(),
+                },
+                amount_per_link_use_action: Nat::from(amount_per_use),
+                label: "SEND_AIRDROP_ASSET".to_string(),
+                token_ids: None,
+                reserved_token_ids: None,
+            }],
+            creator: random_principal_id(),
+            create_at: 0,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 5,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+
+    fn top_up_action(link: &Link, top_up_count: u64) -> Action {
+        Action {
+            id: Uuid::new_v4().to_string(),
+            r#type: ActionType::TopUp,
+            state: ActionState::Created,
+            creator: link.creator,
+            link_id: link.id.clone(),
+            top_up_count: Some(top_up_count),
+        }
+    }
+
+    fn deposit_intent(link: &Link, asset: Asset, amount: u64) -> Intent {
+        let link_account = get_link_account(&link.id, random_principal_id()).unwrap();
+        TransferWalletToLinkIntent::create(
+            INTENT_LABEL_TOP_UP_LINK_ASSET.to_string(),
+            asset,
+            Nat::from(amount),
+            link.creator,
+            link_account,
+            0,
+        )
+        .unwrap()
+        .intent
+    }
+
+    #[test]
+    fn it_should_return_the_requested_top_up_count() {
+        // Arrange
+        let link = top_up_link(1_000);
+        let asset = link.asset_info[0].asset.clone();
+        let fee_map = HashMap::from([(asset.address(), Nat::from(10u64))]);
+        let intents = vec![deposit_intent(&link, asset, 3_030)];
+
+        // Act
+        let result = TopUpAction::top_up_count(&link, &top_up_action(&link, 3), &intents, &fee_map);
+
+        // Assert
+        assert_eq!(result.unwrap(), 3);
+    }
+
+    #[test]
+    fn it_should_reject_a_top_up_when_an_asset_deposit_falls_short() {
+        // Arrange
+        let mut link = top_up_link(1_000);
+        let mut second_asset = link.asset_info[0].clone();
+        second_asset.asset = Asset::IC {
+            address: random_principal_id(),
+        };
+        link.asset_info.push(second_asset.clone());
+        let first_asset = link.asset_info[0].asset.clone();
+        let intents = vec![
+            deposit_intent(&link, first_asset, 3_000),
+            deposit_intent(&link, second_asset.asset, 2_000),
+        ];
+
+        // Act
+        let result =
+            TopUpAction::top_up_count(&link, &top_up_action(&link, 3), &intents, &HashMap::new());
+
+        // Assert
+        assert!(result.is_err());
+    }
+}
@@ -2,21 +2,24 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{Nat, Principal};
-use cashier_backend_types::repository::common::Asset;
 use cashier_backend_types::{
     constant::INTENT_LABEL_SEND_TIP_ASSET,
     error::CanisterError,
     repository::{
         action::v1::{Action, ActionState, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         link::v1::Link,
     },
 };
 use cashier_common::utils::get_link_account;
-use transaction_manager::intents::transfer_link_to_wallet::TransferLinkToWalletIntent;
+use transaction_manager::intents::{
+    transfer_link_to_wallet::TransferLinkToWalletIntent, transfer_nft::TransferNftIntent,
+};
 
 use crate::apps::link_v2::links::shared::utils::{
     get_batch_tokens_balance_for_link, get_batch_tokens_fee_for_link,
+    get_nft_token_ids_held_by_link,
 };
 use uuid::Uuid;
 
@@ -44,51 +47,61 @@ impl WithdrawAction {
             link_id: link.id.clone(),
             creator: link.creator,
             state: ActionState::Created,
+            top_up_count: None,
         };
 
         let link_account = get_link_account(&link.id, canister_id)?;
         let actual_token_balance_map = get_batch_tokens_balance_for_link(link, canister_id).await?;
         let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
 
+        let held_token_ids_map = get_nft_token_ids_held_by_link(link, canister_id).await?;
+
         // intents
-        let link_to_wallet_intents = link
-            .asset_info
-            .iter()
-            .map(|asset_info| {
-                let address = match asset_info.asset {
-                    Asset::IC { address } => address,
-                };
-                let sending_amount = actual_token_balance_map
-                    .get(&address)
-                    .cloned()
-                    .unwrap_or(Nat::from(0u64));
-                let fee_amount = token_fee_map
-                    .get(&address)
-                    .cloned()
-                    .unwrap_or(Nat::from(0u64));
-                let sending_amount = if sending_amount <= fee_amount {
-                    Nat::from(0u64)
-                } else {
-                    sending_amount - fee_amount
-                };
+        let mut intents = Vec::<Intent>::new();
+        for asset_info in link.asset_info.iter() {
+            let address = asset_info.asset.address();
 
-                TransferLinkToWalletIntent::create(
-                    INTENT_LABEL_SEND_TIP_ASSET.to_string(),
-                    asset_info.asset.clone(),
-                    sending_amount,
-                    link.creator,
-                    link_account,
-                    link.create_at,
-                )
-            })
-            .collect::<Result<Vec<TransferLinkToWalletIntent>, CanisterError>>()?;
+            // the NFTs still held by the link go back to the creator one by one, including
+            // those reserved by claims that can no longer be processed on an inactive link
+            if asset_info.asset.is_nft() {
+                for token_id in held_token_ids_map.get(&address).into_iter().flatten() {
+                    let link_to_wallet_intent = TransferNftIntent::create_link_to_wallet(
+                        INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                        asset_info.asset.clone(),
+                        token_id.clone(),
+                        Wallet::new(link.creator),
+                        link_account,
+                        link.create_at,
+                    )?;
+                    intents.push(link_to_wallet_intent.intent);
+                }
+                continue;
+            }
 
-        let mut intents = Vec::<Intent>::new();
-        link_to_wallet_intents
-            .iter()
-            .for_each(|link_to_wallet_intent| {
-                intents.push(link_to_wallet_intent.intent.clone());
-            });
+            let sending_amount = actual_token_balance_map
+                .get(&address)
+                .cloned()
+                .unwrap_or(Nat::from(0u64));
+            let fee_amount = token_fee_map
+                .get(&address)
+                .cloned()
+                .unwrap_or(Nat::from(0u64));
+            let sending_amount = if sending_amount <= fee_amount {
+                Nat::from(0u64)
+            } else {
+                sending_amount - fee_amount
+            };
+
+            let link_to_wallet_intent = TransferLinkToWalletIntent::create(
+                INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+                asset_info.asset.clone(),
+                sending_amount,
+                link.creator,
+                link_account,
+                link.create_at,
+            )?;
+            intents.push(link_to_wallet_intent.intent);
+        }
 
         Ok(Self::new(action, intents))
     }
@@ -1,20 +1,27 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use crate::api::state::get_state;
 use crate::apps::link_v2::links::{
-    shared::send_link::actions::receive::ReceiveAction, traits::LinkV2State,
+    shared::{
+        send_link::actions::{receive::ReceiveAction, top_up::TopUpAction},
+        utils::{ensure_gate_opened_by_user, get_batch_tokens_fee_for_link},
+    },
+    traits::LinkV2State,
 };
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         link::v1::{Link, LinkState},
         transaction::v1::Transaction,
     },
 };
+use cashier_common::{constant::CLAIM_RESERVATION_TTL_NS, runtime::IcEnvironment};
 use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
 use transaction_manager::traits::TransactionManager;
 
@@ -33,26 +40,119 @@ impl<M: TransactionManager + 'static> ActiveState<M> {
         }
     }
 
+    /// Checks that the caller is allowed to receive from the active link
+    /// # Arguments
+    /// * `link` - The link to receive from
+    /// * `caller` - The principal of the user receiving
+    /// # Returns
+    /// * `Result<(), CanisterError>` - Ok if the caller can receive, otherwise an error
+    pub async fn ensure_can_receive(link: &Link, caller: Principal) -> Result<(), CanisterError> {
+        if link.is_expired(get_state().env.time()) {
+            return Err(CanisterError::ValidationErrors(
+                "Link has expired".to_string(),
+            ));
+        }
+
+        if !link.is_allowed(&caller) {
+            return Err(CanisterError::Unauthorized(
+                "Caller is not on the allow-list of this link".to_string(),
+            ));
+        }
+
+        ensure_gate_opened_by_user(link, caller).await
+    }
+
     /// Create RECEIVE action for the tip link
     /// # Arguments
     /// * `caller` - The principal of the user creating the action
     /// * `link` - The tip link for which the action is being created
     /// * `canister_id` - The canister ID of the backend canister
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC links
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
     /// * `transaction_manager` - The transaction manager to handle action creation
     /// # Returns
-    /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the RECEIVE action
+    /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the RECEIVE action,
+    ///   with the NFT token ids it sends reserved on the link
     pub async fn create_receive_action(
         caller: Principal,
         link: Link,
         canister_id: Principal,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+        transaction_manager: Rc<M>,
+    ) -> Result<LinkCreateActionResult, CanisterError> {
+        Self::ensure_can_receive(&link, caller).await?;
+
+        // NFT links hand out the next available token id, reload the link after the last await
+        // so concurrent claims see each other's reservations
+        let mut link = if link.asset_info.iter().any(|info| info.asset.is_nft()) {
+            get_state()
+                .link_v2_service
+                .link_repository
+                .get(&link.id)
+                .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?
+        } else {
+            link
+        };
+
+        let receive_action =
+            ReceiveAction::create(&link, caller, canister_id, btc_address, destination).await?;
+        ReceiveAction::reserve_nft_token_ids(
+            &mut link,
+            &receive_action.action.id,
+            &receive_action.intents,
+            get_state()
+                .env
+                .time()
+                .saturating_add(CLAIM_RESERVATION_TTL_NS),
+        );
+        let create_action_result = transaction_manager
+            .create_action(receive_action.action, receive_action.intents, None)
+            .await?;
+
+        Ok(LinkCreateActionResult {
+            link,
+            create_action_result,
+        })
+    }
+
+    /// Create TOP_UP action to fund more uses of the active link
+    /// # Arguments
+    /// * `caller` - The principal of the user creating the action
+    /// * `link` - The link being topped up
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `top_up_use_count` - The number of additional uses to fund
+    /// * `transaction_manager` - The transaction manager to handle action creation
+    /// # Returns
+    /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the TOP_UP action
+    pub async fn create_top_up_action(
+        caller: Principal,
+        link: Link,
+        canister_id: Principal,
+        top_up_use_count: Option<u64>,
         transaction_manager: Rc<M>,
     ) -> Result<LinkCreateActionResult, CanisterError> {
-        let receive_action = ReceiveAction::create(&link, caller, canister_id).await?;
-        let create_action_result = transaction_manager.create_action(
-            receive_action.action,
-            receive_action.intents,
-            None,
-        )?;
+        // validate caller is the link creator
+        if caller != link.creator {
+            return Err(CanisterError::Unauthorized(
+                "Only the creator can top up this link".to_string(),
+            ));
+        }
+
+        let top_up_count = top_up_use_count.ok_or_else(|| {
+            CanisterError::InvalidInput("Number of uses to top up is required".to_string())
+        })?;
+        if top_up_count == 0 {
+            return Err(CanisterError::InvalidInput(
+                "Number of uses to top up must be greater than 0".to_string(),
+            ));
+        }
+
+        let top_up_action =
+            TopUpAction::create(&link, canister_id, top_up_count, get_state().env.time()).await?;
+        let create_action_result = transaction_manager
+            .create_action(top_up_action.action, top_up_action.intents, None)
+            .await?;
 
         Ok(LinkCreateActionResult {
             link: link.clone(),
@@ -60,6 +160,53 @@ impl<M: TransactionManager + 'static> ActiveState<M> {
         })
     }
 
+    /// Process TOP_UP action to raise the maximum number of uses of an active or ended link
+    /// # Arguments
+    /// * `caller` - The principal of the user topping up the link
+    /// * `link` - The link being topped up
+    /// * `action` - The top-up action to be processed
+    /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - A mapping of intent IDs to their associated transactions
+    /// * `transaction_manager` - The transaction manager to handle the action processing
+    /// # Returns
+    /// * `Result<LinkProcessActionResult, CanisterError>` - The result of processing the top-up action
+    pub async fn top_up(
+        caller: Principal,
+        link: &Link,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+        transaction_manager: Rc<M>,
+    ) -> Result<LinkProcessActionResult, CanisterError> {
+        // validate caller is the link creator
+        if caller != link.creator {
+            return Err(CanisterError::Unauthorized(
+                "Only the creator can top up this link".to_string(),
+            ));
+        }
+
+        let mut link = link.clone();
+        let token_fee_map = get_batch_tokens_fee_for_link(&link).await?;
+        let top_up_count = TopUpAction::top_up_count(&link, &action, &intents, &token_fee_map)?;
+
+        let process_action_result = transaction_manager
+            .process_action(action, intents, intent_txs_map)
+            .await?;
+
+        if process_action_result.is_success {
+            link.link_use_action_max_count += top_up_count;
+            // an ended link is open again for the uses it has been funded for
+            if link.state == LinkState::InactiveEnded {
+                link.state = LinkState::Active;
+            }
+        }
+
+        Ok(LinkProcessActionResult {
+            link,
+            process_action_result,
+        })
+    }
+
     /// Process a RECEIVE action on the active tip link
     /// # Arguments
     /// * `link` - The tip link being received
@@ -76,6 +223,19 @@ impl<M: TransactionManager + 'static> ActiveState<M> {
         intent_txs_map: HashMap<String, Vec<Transaction>>,
         transaction_manager: Rc<M>,
     ) -> Result<LinkProcessActionResult, CanisterError> {
+        // the token ids of a claim left unprocessed are made available again when it expires
+        let has_nft = link.asset_info.iter().any(|info| info.asset.is_nft());
+        if has_nft
+            && !link
+                .asset_info
+                .iter()
+                .any(|info| info.is_token_id_reserved(&action.id))
+        {
+            return Err(CanisterError::ValidationErrors(
+                "The NFT of this claim has expired, please claim again".to_string(),
+            ));
+        }
+
         let mut link = link.clone();
 
         let process_action_result = transaction_manager
@@ -101,6 +261,10 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
         &self,
         caller: Principal,
         action_type: ActionType,
+        _amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         let link = self.link.clone();
         let canister_id = self.canister_id;
@@ -109,9 +273,26 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
         Box::pin(async move {
             match action_type {
                 ActionType::Receive => {
-                    let create_action_result =
-                        Self::create_receive_action(caller, link, canister_id, transaction_manager)
-                            .await?;
+                    let create_action_result = Self::create_receive_action(
+                        caller,
+                        link,
+                        canister_id,
+                        btc_address,
+                        destination,
+                        transaction_manager,
+                    )
+                    .await?;
+                    Ok(create_action_result)
+                }
+                ActionType::TopUp => {
+                    let create_action_result = Self::create_top_up_action(
+                        caller,
+                        link,
+                        canister_id,
+                        top_up_use_count,
+                        transaction_manager,
+                    )
+                    .await?;
                     Ok(create_action_result)
                 }
                 _ => Err(CanisterError::ValidationErrors(
@@ -123,7 +304,7 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
 
     fn process_action(
         &self,
-        _caller: Principal,
+        caller: Principal,
         action: Action,
         intents: Vec<Intent>,
         intent_txs_map: HashMap<String, Vec<Transaction>>,
@@ -139,6 +320,18 @@ impl<M: TransactionManager + 'static> LinkV2State for ActiveState<M> {
                             .await?;
                     Ok(receive_result)
                 }
+                ActionType::TopUp => {
+                    let top_up_result = Self::top_up(
+                        caller,
+                        &link,
+                        action,
+                        intents,
+                        intent_txs_map,
+                        transaction_manager,
+                    )
+                    .await?;
+                    Ok(top_up_result)
+                }
                 _ => Err(CanisterError::ValidationErrors(
                     "Unsupported action type for ActiveState".to_string(),
                 )),
@@ -0,0 +1,228 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::api::state::get_state;
+use crate::apps::link_v2::links::traits::LinkV2State;
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
+    repository::{
+        action::v1::{Action, ActionType},
+        common::Wallet,
+        intent::v1::Intent,
+        link::v1::{Link, LinkState},
+        transaction::v1::Transaction,
+    },
+};
+use cashier_common::runtime::IcEnvironment;
+use std::{collections::HashMap, future::Future, pin::Pin};
+
+/// Get the state handler of a link for its current state
+/// # Arguments
+/// * `link` - The Link model
+/// * `created` - Builds the handler of a link waiting for its `CreateLink` action
+/// * `active` - Builds the handler of an active link, also used to top up ended links
+/// * `inactive` - Builds the handler of an inactive link, also used to withdraw scheduled links
+/// # Returns
+/// * `Result<Box<dyn LinkV2State>, CanisterError>` - The resulting state handler or an error if the state is unsupported
+pub fn get_state_handler(
+    link: &Link,
+    created: impl FnOnce() -> Box<dyn LinkV2State>,
+    active: impl FnOnce() -> Box<dyn LinkV2State>,
+    inactive: impl FnOnce() -> Box<dyn LinkV2State>,
+) -> Result<Box<dyn LinkV2State>, CanisterError> {
+    match link.state {
+        LinkState::CreateLink => Ok(created()),
+        LinkState::Scheduled => Ok(Box::new(ScheduledState {
+            inactive: inactive(),
+        })),
+        LinkState::Active => Ok(active()),
+        LinkState::Inactive => Ok(inactive()),
+        LinkState::InactiveEnded => Ok(Box::new(EndedState {
+            link: link.clone(),
+            active: active(),
+        })),
+    }
+}
+
+/// A funded link waiting for its start time. Only the creator's WITHDRAW is
+/// accepted, handled like on an inactive link, so a pre-funded link can be
+/// cancelled before it opens.
+pub struct ScheduledState {
+    inactive: Box<dyn LinkV2State>,
+}
+
+impl ScheduledState {
+    fn not_open_yet() -> CanisterError {
+        CanisterError::ValidationErrors("Link is not open yet".to_string())
+    }
+}
+
+impl LinkV2State for ScheduledState {
+    fn create_action(
+        &self,
+        caller: Principal,
+        action_type: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
+        match action_type {
+            ActionType::Withdraw => self.inactive.create_action(
+                caller,
+                action_type,
+                amount,
+                top_up_use_count,
+                btc_address,
+                destination,
+            ),
+            _ => Box::pin(async move { Err(Self::not_open_yet()) }),
+        }
+    }
+
+    fn process_action(
+        &self,
+        caller: Principal,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>> {
+        match action.r#type {
+            ActionType::Withdraw => {
+                self.inactive
+                    .process_action(caller, action, intents, intent_txs_map)
+            }
+            _ => Box::pin(async move { Err(Self::not_open_yet()) }),
+        }
+    }
+}
+
+/// A link that ended. Only the creator's TOP_UP is accepted, handled like on an active link,
+/// so a link whose uses have all been consumed can be funded for more uses.
+pub struct EndedState {
+    link: Link,
+    active: Box<dyn LinkV2State>,
+}
+
+impl EndedState {
+    fn unsupported() -> CanisterError {
+        CanisterError::ValidationErrors("Unsupported link state".to_string())
+    }
+
+    /// Checks that the link ended by consuming all its uses. A withdrawn link has uses left
+    /// that its balance no longer backs, and an expired link cannot be reopened.
+    /// # Arguments
+    /// * `link` - The ended link
+    /// * `now` - The current timestamp
+    /// # Returns
+    /// * `Result<(), CanisterError>` - Ok if the link can be topped up, otherwise an error
+    fn ensure_can_top_up(link: &Link, now: u64) -> Result<(), CanisterError> {
+        if link.link_use_action_counter < link.link_use_action_max_count {
+            return Err(CanisterError::ValidationErrors(
+                "Only links that consumed all their uses can be topped up".to_string(),
+            ));
+        }
+        if link.is_expired(now) {
+            return Err(CanisterError::ValidationErrors(
+                "Link has expired".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl LinkV2State for EndedState {
+    fn create_action(
+        &self,
+        caller: Principal,
+        action_type: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
+        match action_type {
+            ActionType::TopUp => {
+                if let Err(e) = Self::ensure_can_top_up(&self.link, get_state().env.time()) {
+                    return Box::pin(async move { Err(e) });
+                }
+                self.active.create_action(
+                    caller,
+                    action_type,
+                    amount,
+                    top_up_use_count,
+                    btc_address,
+                    destination,
+                )
+            }
+            _ => Box::pin(async move { Err(Self::unsupported()) }),
+        }
+    }
+
+    fn process_action(
+        &self,
+        caller: Principal,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>> {
+        match action.r#type {
+            ActionType::TopUp => {
+                if let Err(e) = Self::ensure_can_top_up(&self.link, get_state().env.time()) {
+                    return Box::pin(async move { Err(e) });
+                }
+                self.active
+                    .process_action(caller, action, intents, intent_txs_map)
+            }
+            _ => Box::pin(async move { Err(Self::unsupported()) }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::link::v1::LinkType;
+    use cashier_common::test_utils::random_principal_id;
+
+    fn ended_link(link_use_action_counter: u64, expires_at: Option<u64>) -> Link {
+        Link {
+            id: "link".to_string(),
+            state: LinkState::InactiveEnded,
+            title: "Airdrop".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![],
+            creator: random_principal_id(),
+            create_at: 0,
+            link_use_action_counter,
+            link_use_action_max_count: 5,
+            expires_at,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+
+    #[test]
+    fn it_should_top_up_only_links_that_consumed_all_their_uses() {
+        // Arrange
+        let consumed = ended_link(5, None);
+        let withdrawn = ended_link(3, None);
+        let expiring = ended_link(5, Some(100));
+
+        // Act & Assert
+        assert!(EndedState::ensure_can_top_up(&consumed, 200).is_ok());
+        assert!(EndedState::ensure_can_top_up(&withdrawn, 200).is_err());
+        assert!(EndedState::ensure_can_top_up(&expiring, 50).is_ok());
+        assert!(EndedState::ensure_can_top_up(&expiring, 200).is_err());
+    }
+}
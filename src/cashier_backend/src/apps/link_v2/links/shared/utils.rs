@@ -1,17 +1,34 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use crate::api::state::get_state;
+use crate::{api::state::get_state, apps::gate::GateServiceClient};
 use candid::{Nat, Principal};
 use cashier_backend_types::{
+    constant::INTENT_LABEL_LINK_CREATION_FEE,
     error::CanisterError,
-    repository::{common::Asset, link::v1::Link},
+    init::CkBtcMinterSettings,
+    repository::{
+        common::Asset,
+        intent::v1::Intent,
+        link::v1::{Link, LinkType},
+    },
+};
+use cashier_common::{
+    constant::ICP_CANISTER_PRINCIPAL,
+    utils::{convert_nat_to_u64, get_link_account, to_subaccount},
 };
-use cashier_common::{constant::ICP_CANISTER_PRINCIPAL, utils::to_subaccount};
 use futures::future;
+use gate_service_types::{Gate, GateKey, GateStatus, NewGate};
+use icrc_ledger_types::icrc1::account::Account as IcrcAccount;
 use serde_bytes::ByteBuf;
 use std::collections::HashMap;
-use transaction_manager::icrc_token::{service::IcrcService, types::Account};
+use transaction_manager::{
+    icrc_token::{service::IcrcService, types::Account},
+    icrc7_token::service::Icrc7Service,
+    intents::transfer_wallet_to_treasury::TransferWalletToTreasuryIntent,
+    transaction::ic_transaction_validator::is_nft_owned_by,
+    utils::calculator::{calculate_create_link_fee, calculate_link_balance_map},
+};
 
 /// Retrieves token fees for a link's assets, ensuring ICP is included.
 /// # Arguments
@@ -38,9 +55,10 @@ pub async fn get_batch_tokens_fee_for_link(
         .collect();
 
     // if ICP is missing in assets, add it
-    if !assets.iter().any(|asset| match asset {
-        Asset::IC { address, .. } => *address == ICP_CANISTER_PRINCIPAL,
-    }) {
+    if !assets
+        .iter()
+        .any(|asset| matches!(asset, Asset::IC { address } if *address == ICP_CANISTER_PRINCIPAL))
+    {
         assets.push(Asset::IC {
             address: ICP_CANISTER_PRINCIPAL,
         });
@@ -80,9 +98,7 @@ async fn get_batch_tokens_balance(
     let get_balance_tasks = assets
         .iter()
         .map(|asset| {
-            let address = match asset {
-                Asset::IC { address, .. } => *address,
-            };
+            let address = asset.address();
             let account = account.clone();
             async move {
                 let service = IcrcService::new(address);
@@ -120,6 +136,7 @@ async fn get_batch_tokens_balance(
 ///
 /// This function extracts all assets from the link's `asset_info`, derives the link's
 /// subaccount from its ID, and queries the balance for each asset at that account.
+/// The balance of an NFT asset is the number of its available and reserved token ids the link still holds.
 ///
 /// # Arguments
 ///
@@ -144,6 +161,7 @@ pub async fn get_batch_tokens_balance_for_link(
         .asset_info
         .iter()
         .map(|info| info.asset.clone())
+        .filter(|asset| !asset.is_nft())
         .collect();
 
     let subaccount = to_subaccount(&link.id)?;
@@ -153,5 +171,375 @@ pub async fn get_batch_tokens_balance_for_link(
         subaccount: Some(ByteBuf::from(subaccount.to_vec())),
     };
 
-    get_batch_tokens_balance(&assets, &link_account).await
+    let mut balance_map = get_batch_tokens_balance(&assets, &link_account).await?;
+    let held_token_ids_map = get_nft_token_ids_held_by_link(link, canister_id).await?;
+    balance_map.extend(
+        held_token_ids_map
+            .into_iter()
+            .map(|(address, token_ids)| (address, Nat::from(token_ids.len()))),
+    );
+
+    Ok(balance_map)
+}
+
+/// Retrieves the available and reserved token ids of a link's NFT assets that the link account still holds.
+///
+/// # Arguments
+///
+/// * `link` - A reference to the link whose NFTs should be looked up
+/// * `canister_id` - The principal of the canister that owns the link account
+///
+/// # Returns
+///
+/// Returns a `HashMap` mapping each NFT collection's `Principal` to the token ids held by the link.
+///
+/// # Errors
+///
+/// Returns a `CanisterError` if:
+/// * Subaccount derivation from the link ID fails
+/// * Any collection canister fails to respond
+pub async fn get_nft_token_ids_held_by_link(
+    link: &Link,
+    canister_id: Principal,
+) -> Result<HashMap<Principal, Vec<Nat>>, CanisterError> {
+    let link_account = get_link_account(&link.id, canister_id)?;
+
+    let get_owner_tasks = link
+        .asset_info
+        .iter()
+        .filter(|info| info.asset.is_nft())
+        .map(|info| {
+            let address = info.asset.address();
+            let token_ids = info.held_token_ids();
+            async move {
+                let owners_res = Icrc7Service::new(address).icrc7_owner_of(&token_ids).await;
+                (address, token_ids, owners_res)
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let results = future::join_all(get_owner_tasks).await;
+
+    let mut token_ids_map = HashMap::new();
+    for (address, token_ids, result) in results {
+        let owners = result.map_err(|err| {
+            CanisterError::CallCanisterFailed(format!(
+                "Failed to get owners for NFT collection {}: {:?}",
+                address.to_text(),
+                err,
+            ))
+        })?;
+
+        let held_token_ids = token_ids
+            .into_iter()
+            .zip(owners)
+            .filter(|(_, owner)| is_nft_owned_by(owner.as_ref(), link_account))
+            .map(|(token_id, _)| token_id)
+            .collect();
+        token_ids_map.insert(address, held_token_ids);
+    }
+
+    Ok(token_ids_map)
+}
+
+/// Calculates the balance a send link needs to serve all of its remaining uses.
+/// # Arguments
+/// * `link` - The link to check
+/// * `fee_map` - A map of token principal to its corresponding fee
+/// # Returns
+/// * `HashMap<Principal, Nat>` - A map of token principal to the balance required by the link
+pub fn calculate_remaining_link_balance_map(
+    link: &Link,
+    fee_map: &HashMap<Principal, Nat>,
+) -> HashMap<Principal, Nat> {
+    match &link.random_pot {
+        Some(random_pot) => link
+            .asset_info
+            .iter()
+            .zip(&random_pot.remaining_amounts)
+            .map(|(info, remaining_amount)| {
+                let address = info.asset.address();
+                let fee = fee_map.get(&address).cloned().unwrap_or(Nat::from(0u64));
+                (
+                    address,
+                    remaining_amount.clone() + fee * Nat::from(random_pot.remaining_claims),
+                )
+            })
+            .collect(),
+        None => calculate_link_balance_map(
+            &link.asset_info,
+            fee_map,
+            link.link_use_action_max_count
+                .saturating_sub(link.link_use_action_counter),
+        ),
+    }
+}
+
+/// Checks that the link account holds enough tokens to serve all remaining uses of a send link.
+/// Receive links hold no funds for their users, so they always pass.
+/// # Arguments
+/// * `link` - The link to check
+/// * `canister_id` - The canister ID of the backend canister
+/// # Returns
+/// * `Result<(), CanisterError>` - Ok if the link is funded, otherwise an error
+pub async fn ensure_link_funded_for_remaining_uses(
+    link: &Link,
+    canister_id: Principal,
+) -> Result<(), CanisterError> {
+    if matches!(
+        link.link_type,
+        LinkType::ReceivePayment | LinkType::ReceiveSplitPayment
+    ) {
+        return Ok(());
+    }
+
+    let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
+    let token_balance_map = get_batch_tokens_balance_for_link(link, canister_id).await?;
+    let required_balance_map = calculate_remaining_link_balance_map(link, &token_fee_map);
+
+    let is_funded = required_balance_map.iter().all(|(address, required)| {
+        token_balance_map
+            .get(address)
+            .is_some_and(|balance| balance >= required)
+    });
+    if !is_funded {
+        return Err(CanisterError::ValidationErrors(
+            "Link balance is not enough to cover its remaining uses".to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Creates the intent paying the link creation fee to the treasury,
+/// following the fee schedule configured in the settings.
+/// # Arguments
+/// * `link` - The link being created
+/// * `canister_id` - The canister ID of the backend canister, approved to spend the fee
+/// # Returns
+/// * `Result<Option<Intent>, CanisterError>` - The fee intent, None if the link is created for free
+pub async fn create_link_fee_intent(
+    link: &Link,
+    canister_id: Principal,
+) -> Result<Option<Intent>, CanisterError> {
+    create_links_fee_intent(link, 1, canister_id).await
+}
+
+/// Creates the intent paying at once the creation fee of several links built from the same template.
+/// Its approve also covers the allowance reserved by the other pending actions of the creator.
+/// # Arguments
+/// * `link` - One of the links being created
+/// * `link_count` - The number of links being created
+/// * `canister_id` - The canister ID of the backend canister, approved to spend the fee
+/// # Returns
+/// * `Result<Option<Intent>, CanisterError>` - The fee intent, None if the links are created for free
+pub async fn create_links_fee_intent(
+    link: &Link,
+    link_count: u64,
+    canister_id: Principal,
+) -> Result<Option<Intent>, CanisterError> {
+    let link_fee_settings = get_state().settings.get_link_fee();
+    let link_tokens: Vec<Principal> = link
+        .asset_info
+        .iter()
+        .filter(|info| !info.asset.is_nft())
+        .map(|info| info.asset.address())
+        .collect();
+
+    let Some(mut link_fee) =
+        link_fee_settings.get_link_creation_fee(&link.creator, link.link_type, &link_tokens)
+    else {
+        return Ok(None);
+    };
+    link_fee.amount = link_fee.amount.checked_mul(link_count).ok_or_else(|| {
+        CanisterError::InvalidInput("The creation fee of the links is too large".to_string())
+    })?;
+
+    let fee_asset = Asset::IC {
+        address: link_fee.token_id,
+    };
+    let token_fee_map = get_state()
+        .token_fee_service
+        .get_batch_tokens_fee(std::slice::from_ref(&fee_asset))
+        .await?;
+    let (actual_amount, approval_amount) = calculate_create_link_fee(&link_fee, &token_fee_map);
+    let spender_account = IcrcAccount {
+        owner: canister_id,
+        subaccount: None,
+    };
+
+    // the approve replaces the allowance on the ledger, so it also grants the allowance
+    // still reserved by the other pending actions of the creator
+    let ledger_fee = approval_amount.clone() - actual_amount.clone();
+    let reserved_allowance = get_state()
+        .link_v2_service
+        .action_service
+        .get_reserved_allowance(
+            link.creator,
+            &spender_account.into(),
+            &fee_asset,
+            &ledger_fee,
+        );
+    let approval_amount = approval_amount + reserved_allowance;
+
+    let fee_intent = TransferWalletToTreasuryIntent::create(
+        INTENT_LABEL_LINK_CREATION_FEE.to_string(),
+        fee_asset,
+        convert_nat_to_u64(&actual_amount)?,
+        convert_nat_to_u64(&approval_amount)?,
+        link.creator,
+        spender_account,
+        link_fee_settings.treasury,
+        link.create_at,
+    )?;
+
+    Ok(Some(fee_intent.intent))
+}
+
+/// Returns the ckBTC minter configured in the settings.
+pub fn get_ckbtc_minter() -> Result<CkBtcMinterSettings, CanisterError> {
+    get_state().settings.get_ckbtc_minter().ok_or_else(|| {
+        CanisterError::ValidationErrors("Bitcoin withdrawals are not enabled".to_string())
+    })
+}
+
+/// Returns the gate_service canister configured in the settings.
+fn get_gate_service_client() -> Result<GateServiceClient, CanisterError> {
+    get_state()
+        .settings
+        .get_gate_service_canister_id()
+        .map(GateServiceClient::new)
+        .ok_or_else(|| {
+            CanisterError::HandleLogicError("Gate service canister is not configured".to_string())
+        })
+}
+
+/// Creates a gate on the gate_service canister with the link as its subject.
+///
+/// # Arguments
+///
+/// * `link_id` - The ID of the link being gated
+/// * `key` - The key users must provide to open the gate
+///
+/// # Returns
+///
+/// Returns the created `Gate`.
+///
+/// # Errors
+///
+/// Returns a `CanisterError` if:
+/// * The gate service canister is not configured
+/// * The gate service rejects the gate
+pub async fn create_gate_for_link(link_id: &str, key: GateKey) -> Result<Gate, CanisterError> {
+    let new_gate = NewGate {
+        subject_id: link_id.to_string(),
+        key,
+    };
+
+    get_gate_service_client()?.add_gate(&new_gate).await
+}
+
+/// Ensures the user has opened the gate attached to the link, if any.
+///
+/// # Arguments
+///
+/// * `link` - The link to check
+/// * `user` - The principal of the user acting on the link
+///
+/// # Errors
+///
+/// Returns a `CanisterError` if:
+/// * The gate has not been opened by the user
+/// * The gate service canister is not configured or the call fails
+pub async fn ensure_gate_opened_by_user(link: &Link, user: Principal) -> Result<(), CanisterError> {
+    let Some(gate_id) = &link.gate_id else {
+        return Ok(());
+    };
+
+    let gate_for_user = get_gate_service_client()?
+        .get_gate_for_user(gate_id, user)
+        .await?;
+
+    match gate_for_user.gate_user_status {
+        Some(status) if status.status == GateStatus::Open => Ok(()),
+        _ => Err(CanisterError::Unauthorized(
+            "Gate is not opened for this user".to_string(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::{
+        asset_info::AssetInfo,
+        link::v1::{LinkState, RandomPot},
+    };
+    use cashier_common::test_utils::random_principal_id;
+
+    fn send_link(asset: Principal, random_pot: Option<RandomPot>) -> Link {
+        Link {
+            id: "link".to_string(),
+            state: LinkState::Inactive,
+            title: "Airdrop".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![AssetInfo {
+                asset: Asset::IC { address: asset },
+                amount_per_link_use_action: Nat::from(1_000u64),
+                label: "SEND_AIRDROP_ASSET".to_string(),
+                token_ids: None,
+                reserved_token_ids: None,
+            }],
+            creator: random_principal_id(),
+            create_at: 0,
+            link_use_action_counter: 2,
+            link_use_action_max_count: 5,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+
+    #[test]
+    fn it_should_calculate_remaining_balance_for_unused_claims() {
+        // Arrange
+        let asset = random_principal_id();
+        let link = send_link(asset, None);
+        let fee_map = HashMap::from([(asset, Nat::from(10u64))]);
+
+        // Act
+        let result = calculate_remaining_link_balance_map(&link, &fee_map);
+
+        // Assert
+        assert_eq!(result.get(&asset), Some(&Nat::from(3_030u64)));
+    }
+
+    #[test]
+    fn it_should_calculate_remaining_balance_from_random_pot() {
+        // Arrange
+        let asset = random_principal_id();
+        let link = send_link(
+            asset,
+            Some(RandomPot {
+                remaining_amounts: vec![Nat::from(2_500u64)],
+                remaining_claims: 3,
+                reservations: vec![],
+            }),
+        );
+        let fee_map = HashMap::from([(asset, Nat::from(10u64))]);
+
+        // Act
+        let result = calculate_remaining_link_balance_map(&link, &fee_map);
+
+        // Assert
+        assert_eq!(result.get(&asset), Some(&Nat::from(2_530u64)));
+    }
 }
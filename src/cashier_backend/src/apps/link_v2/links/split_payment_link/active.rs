@@ -0,0 +1,124 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::apps::link_v2::links::{
+    shared::receive_link::states::active::ActiveState, split_payment_link::send::SplitSendAction,
+    traits::LinkV2State,
+};
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
+    repository::{
+        action::v1::{Action, ActionType},
+        common::Wallet,
+        intent::v1::Intent,
+        link::v1::Link,
+        transaction::v1::Transaction,
+    },
+};
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+use transaction_manager::traits::TransactionManager;
+
+/// Active state of a split payment link.
+/// Payments are paid out to the recipients in the same action, so nothing stays on the link.
+pub struct SplitActiveState<M: TransactionManager + 'static> {
+    pub link: Link,
+    pub canister_id: Principal,
+    pub transaction_manager: Rc<M>,
+}
+
+impl<M: TransactionManager + 'static> SplitActiveState<M> {
+    pub fn new(link: &Link, canister_id: Principal, transaction_manager: Rc<M>) -> Self {
+        Self {
+            link: link.clone(),
+            canister_id,
+            transaction_manager,
+        }
+    }
+
+    /// Create SEND action for the split payment link
+    /// # Arguments
+    /// * `caller` - The principal of the user creating the action
+    /// * `link` - The split payment link for which the action is being created
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `transaction_manager` - The transaction manager to handle action creation
+    /// # Returns
+    /// * `Result<LinkCreateActionResult, CanisterError>` - The result of creating the SEND action
+    pub async fn create_send_action(
+        caller: Principal,
+        link: Link,
+        canister_id: Principal,
+        transaction_manager: Rc<M>,
+    ) -> Result<LinkCreateActionResult, CanisterError> {
+        let send_action = SplitSendAction::create(&link, caller, canister_id).await?;
+        let create_action_result = transaction_manager
+            .create_action(send_action.action, send_action.intents, None)
+            .await?;
+
+        Ok(LinkCreateActionResult {
+            link: link.clone(),
+            create_action_result,
+        })
+    }
+}
+
+impl<M: TransactionManager + 'static> LinkV2State for SplitActiveState<M> {
+    fn create_action(
+        &self,
+        caller: Principal,
+        action_type: ActionType,
+        _amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        _btc_address: Option<String>,
+        _destination: Option<Wallet>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let canister_id = self.canister_id;
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            match action_type {
+                ActionType::Send => {
+                    let create_action_result =
+                        Self::create_send_action(caller, link, canister_id, transaction_manager)
+                            .await?;
+                    Ok(create_action_result)
+                }
+                _ => Err(CanisterError::ValidationErrors(
+                    "Unsupported action type for ActiveState".to_string(),
+                )),
+            }
+        })
+    }
+
+    fn process_action(
+        &self,
+        _caller: Principal,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            match action.r#type {
+                ActionType::Send => {
+                    let send_result = ActiveState::send(
+                        &link,
+                        action,
+                        intents,
+                        intent_txs_map,
+                        transaction_manager,
+                    )
+                    .await?;
+                    Ok(send_result)
+                }
+                _ => Err(CanisterError::ValidationErrors(
+                    "Unsupported action type for ActiveState".to_string(),
+                )),
+            }
+        })
+    }
+}
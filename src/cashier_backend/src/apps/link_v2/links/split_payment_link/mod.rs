@@ -0,0 +1,201 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+pub mod active;
+pub mod send;
+
+use crate::apps::link_v2::links::{
+    shared::receive_link::states::{created::CreatedState, inactive::InactiveState},
+    shared::state_handler,
+    split_payment_link::active::SplitActiveState,
+    traits::{LinkV2, LinkV2State},
+};
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
+    repository::{
+        action::v1::{Action, ActionType},
+        asset_info::AssetInfo,
+        common::Wallet,
+        intent::v1::Intent,
+        link::v1::{Link, LinkState, LinkType, SplitRecipient},
+        transaction::v1::Transaction,
+    },
+};
+use std::{collections::HashMap, future::Future, pin::Pin, rc::Rc};
+use transaction_manager::traits::TransactionManager;
+use uuid::Uuid;
+
+pub struct SplitPaymentLink<M: TransactionManager + 'static> {
+    pub link: Link,
+    pub canister_id: Principal,
+    pub transaction_manager: Rc<M>,
+}
+
+impl<M: TransactionManager + 'static> SplitPaymentLink<M> {
+    pub fn new(link: Link, canister_id: Principal, transaction_manager: Rc<M>) -> Self {
+        Self {
+            link,
+            canister_id,
+            transaction_manager,
+        }
+    }
+
+    /// Create a new SplitPaymentLink instance
+    /// # Arguments
+    /// * `creator` - The principal of the user creating the link
+    /// * `title` - The title of the link
+    /// * `asset_info` - The asset information associated with the link
+    /// * `max_use` - The maximum number of times the link can be used
+    /// * `split_recipients` - The wallets every payment is split between
+    /// * `created_at_ts` - The timestamp when the link is created
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `transaction_manager` - The transaction manager to handle link actions
+    /// # Returns
+    /// * `SplitPaymentLink` - The newly created SplitPaymentLink instance
+    #[allow(clippy::too_many_arguments)]
+    pub fn create(
+        creator: Principal,
+        title: String,
+        asset_info: Vec<AssetInfo>,
+        max_use: u64,
+        split_recipients: Vec<SplitRecipient>,
+        created_at_ts: u64,
+        canister_id: Principal,
+        transaction_manager: Rc<M>,
+    ) -> Self {
+        let new_link = Link {
+            id: Uuid::new_v4().to_string(),
+            link_type: LinkType::ReceiveSplitPayment,
+            title,
+            asset_info,
+            link_use_action_counter: 0,
+            link_use_action_max_count: max_use,
+            creator,
+            state: LinkState::CreateLink,
+            create_at: created_at_ts,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: Some(split_recipients),
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        };
+
+        Self::new(new_link, canister_id, transaction_manager)
+    }
+
+    /// Get the appropriate state handler for the current link state
+    /// # Arguments
+    /// * `link` - The Link model
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `fee_map` - A map of canister principals to their corresponding fees
+    /// # Returns
+    /// * `Result<Box<dyn LinkV2State>, CanisterError>` - The resulting state handler or an error if the state is unsupported
+    pub fn get_state_handler(
+        link: &Link,
+        canister_id: Principal,
+        transaction_manager: &Rc<M>,
+    ) -> Result<Box<dyn LinkV2State>, CanisterError> {
+        state_handler::get_state_handler(
+            link,
+            || {
+                Box::new(CreatedState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+            || {
+                Box::new(SplitActiveState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+            || {
+                Box::new(InactiveState::new(
+                    link,
+                    canister_id,
+                    transaction_manager.clone(),
+                ))
+            },
+        )
+    }
+}
+
+impl<M: TransactionManager + 'static> LinkV2 for SplitPaymentLink<M> {
+    /// Creates an action for the SplitPaymentLink.
+    /// # Arguments
+    /// * `caller` - The caller principal.
+    /// * `action_type` - The type of action to be created.
+    /// * `amount` - The amount chosen by the caller, for links accepting one.
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC withdrawals
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
+    /// # Returns
+    /// * `Pin<Box<dyn Future<Output = Result<CreateActionResult, CanisterError>>>>` - A future that resolves to the resulting action or an error if the creation fails.
+    fn create_action(
+        &self,
+        caller: Principal,
+        action_type: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let canister_id = self.canister_id;
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            let state =
+                SplitPaymentLink::get_state_handler(&link, canister_id, &transaction_manager)?;
+            let create_action_result = state
+                .create_action(
+                    caller,
+                    action_type,
+                    amount,
+                    top_up_use_count,
+                    btc_address,
+                    destination,
+                )
+                .await?;
+            Ok(create_action_result)
+        })
+    }
+
+    /// Processes an action for the SplitPaymentLink.
+    /// # Arguments
+    /// * `caller` - The caller principal.
+    /// * `action` - The action to be processed.
+    /// * `intents` - The intents associated with the action.
+    /// * `intent_txs_map` - A map of intent IDs to their corresponding transactions.
+    /// # Returns
+    /// * `Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>>` - A future that resolves to the resulting action or an error if the processing fails.
+    fn process_action(
+        &self,
+        caller: Principal,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<LinkProcessActionResult, CanisterError>>>> {
+        let link = self.link.clone();
+        let canister_id = self.canister_id;
+        let transaction_manager = self.transaction_manager.clone();
+
+        Box::pin(async move {
+            let state =
+                SplitPaymentLink::get_state_handler(&link, canister_id, &transaction_manager)?;
+            let process_action_result = state
+                .process_action(caller, action, intents, intent_txs_map)
+                .await?;
+            Ok(process_action_result)
+        })
+    }
+}
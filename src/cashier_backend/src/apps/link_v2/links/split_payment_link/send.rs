@@ -0,0 +1,116 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    constant::{INTENT_LABEL_RECEIVE_PAYMENT_ASSET, INTENT_LABEL_SPLIT_PAYMENT_PAYOUT},
+    error::CanisterError,
+    repository::{
+        action::v1::{Action, ActionState, ActionType},
+        intent::v1::Intent,
+        link::v1::Link,
+    },
+};
+use cashier_common::utils::get_link_account;
+use transaction_manager::{
+    intents::{
+        transfer_link_to_wallet::TransferLinkToWalletIntent,
+        transfer_wallet_to_link::TransferWalletToLinkIntent,
+    },
+    utils::calculator::calculate_split_amounts,
+};
+
+use crate::apps::link_v2::links::shared::utils::get_batch_tokens_fee_for_link;
+use uuid::Uuid;
+
+#[derive(Debug)]
+pub struct SplitSendAction {
+    pub action: Action,
+    pub intents: Vec<Intent>,
+}
+
+impl SplitSendAction {
+    pub fn new(action: Action, intents: Vec<Intent>) -> Self {
+        Self { action, intents }
+    }
+
+    /// Creates a new SEND action for a split payment link.
+    /// For each asset, the sender deposits the payment plus one ledger fee per recipient,
+    /// then the link pays every recipient its share once the deposit succeeded.
+    /// # Arguments
+    /// * `link` - The Link for which the action is created.
+    /// * `sender_id` - The Principal ID of the caller.
+    /// * `canister_id` - The canister ID of the token contract.
+    /// # Returns
+    /// * `Result<SplitSendAction, CanisterError>` - The resulting action or an error if the creation fails.
+    pub async fn create(
+        link: &Link,
+        sender_id: Principal,
+        canister_id: Principal,
+    ) -> Result<Self, CanisterError> {
+        let action = Action {
+            id: Uuid::new_v4().to_string(),
+            r#type: ActionType::Send,
+            link_id: link.id.clone(),
+            creator: sender_id,
+            state: ActionState::Created,
+            top_up_count: None,
+        };
+
+        let split_recipients = link.split_recipients.as_ref().ok_or_else(|| {
+            CanisterError::HandleLogicError("Split recipients not found for this link".to_string())
+        })?;
+        let weights_bps = split_recipients
+            .iter()
+            .map(|recipient| recipient.weight_bps)
+            .collect::<Vec<u16>>();
+
+        let link_account = get_link_account(&link.id, canister_id)?;
+
+        // token_fee_map
+        let token_fee_map = get_batch_tokens_fee_for_link(link).await?;
+
+        // intents
+        let mut intents = Vec::<Intent>::new();
+        for asset_info in link.asset_info.iter() {
+            let address = asset_info.asset.address();
+            let fee_amount = token_fee_map
+                .get(&address)
+                .cloned()
+                .unwrap_or(Nat::from(0u64));
+            let payment_amount = asset_info.amount_per_link_use_action.clone();
+            let deposit_amount =
+                payment_amount.clone() + fee_amount * split_recipients.len() as u64;
+
+            let deposit_intent = TransferWalletToLinkIntent::create(
+                INTENT_LABEL_RECEIVE_PAYMENT_ASSET.to_string(),
+                asset_info.asset.clone(),
+                deposit_amount,
+                sender_id,
+                link_account,
+                link.create_at,
+            )?
+            .intent;
+            let deposit_intent_id = deposit_intent.id.clone();
+            intents.push(deposit_intent);
+
+            let shares = calculate_split_amounts(&payment_amount, &weights_bps);
+            for (recipient, share) in split_recipients.iter().zip(shares) {
+                let mut payout_intent = TransferLinkToWalletIntent::create_to_wallet(
+                    INTENT_LABEL_SPLIT_PAYMENT_PAYOUT.to_string(),
+                    asset_info.asset.clone(),
+                    share,
+                    recipient.wallet.clone(),
+                    link_account,
+                    link.create_at,
+                )?
+                .intent;
+                // payouts are only executed once the deposit has been validated
+                payout_intent.dependency = vec![deposit_intent_id.clone()];
+                intents.push(payout_intent);
+            }
+        }
+
+        Ok(Self::new(action, intents))
+    }
+}
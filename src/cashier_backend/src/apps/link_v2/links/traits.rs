@@ -1,12 +1,13 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     error::CanisterError,
     link_v2::link_result::{LinkCreateActionResult, LinkProcessActionResult},
     repository::{
         action::v1::{Action, ActionType},
+        common::Wallet,
         intent::v1::Intent,
         transaction::v1::Transaction,
     },
@@ -18,6 +19,10 @@ pub trait LinkV2 {
     /// # Arguments
     /// * `caller` - The principal of the user creating the action
     /// * `action` - The type of action to be created
+    /// * `amount` - The amount chosen by the caller, for links accepting one
+    /// * `top_up_use_count` - The number of uses funded by a TOP_UP action
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC withdrawals
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
     /// # Returns
     /// * `LinkCreateActionResult` - The result containing the updated link and action creation result
     /// # Errors
@@ -26,6 +31,10 @@ pub trait LinkV2 {
         &self,
         caller: Principal,
         action: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>>;
 
     /// Process an action associated with the link
@@ -54,6 +63,10 @@ pub trait LinkV2State {
     /// # Arguments
     /// * `caller` - The principal of the user creating the action
     /// * `action` - The type of action to be created
+    /// * `amount` - The amount chosen by the caller, for links accepting one
+    /// * `top_up_use_count` - The number of uses funded by a TOP_UP action
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, for ckBTC withdrawals
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
     /// # Returns
     /// * `LinkCreateActionResult` - The result containing the updated link and action creation result
     /// # Errors
@@ -62,6 +75,10 @@ pub trait LinkV2State {
         &self,
         _caller: Principal,
         _action: ActionType,
+        _amount: Option<Nat>,
+        _top_up_use_count: Option<u64>,
+        _btc_address: Option<String>,
+        _destination: Option<Wallet>,
     ) -> Pin<Box<dyn Future<Output = Result<LinkCreateActionResult, CanisterError>>>> {
         Box::pin(async move { Err(CanisterError::from("create_action not implemented")) })
     }
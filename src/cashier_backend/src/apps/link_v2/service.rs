@@ -2,13 +2,24 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::apps::action::ActionService;
+use crate::apps::fee_ledger::FeeLedgerService;
 use crate::apps::link_v2::links::factory::LinkFactory;
+use crate::apps::link_v2::links::shared::send_link::actions::create::CreateAction;
+use crate::apps::link_v2::links::shared::utils::{
+    create_gate_for_link, create_links_fee_intent, ensure_link_funded_for_remaining_uses,
+    get_batch_tokens_fee_for_link,
+};
 use crate::repositories;
 use crate::repositories::Repositories;
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::dto::link::{GetLinkOptions, GetLinkResp, LinkUserStateDto};
-use cashier_backend_types::link_v2::dto::{CreateLinkDto, ProcessActionDto};
-use cashier_backend_types::repository::link::v1::LinkState;
+use cashier_backend_types::link_v2::action_result::CreateActionResult;
+use cashier_backend_types::link_v2::dto::{
+    ActionReceiptDto, CreateLinkBatchDto, CreateLinkBatchInput, CreateLinkDto,
+    CreateLinkEstimateDto, LinkActionHistoryDto, ProcessActionDto, SimulateActionDto,
+    TokenCostEstimateDto, UpdateLinkV2Input,
+};
+use cashier_backend_types::repository::link::v1::{Link, LinkState};
 use cashier_backend_types::service::link::{PaginateInput, PaginateResult};
 use cashier_backend_types::{
     dto::{
@@ -16,11 +27,23 @@ use cashier_backend_types::{
         link::{CreateLinkInput, LinkDto},
     },
     error::CanisterError,
-    repository::{action::v1::ActionType, link_action::v1::LinkAction, user_link::v1::UserLink},
+    repository::{
+        action::v1::{Action, ActionState, ActionType},
+        common::Wallet,
+        link_action::v1::LinkAction,
+        user_link::v1::UserLink,
+    },
     service::action::ActionData,
 };
-use std::rc::Rc;
-use transaction_manager::traits::TransactionManager;
+use cashier_backend_types::{init::LinkFeeSettings, repository::link::v1::LinkType};
+use cashier_common::constant::MAX_LINK_BATCH_SIZE;
+use std::{
+    collections::{BTreeSet, HashMap},
+    rc::Rc,
+};
+use transaction_manager::{
+    traits::TransactionManager, utils::calculator::calculate_create_link_cost,
+};
 
 pub struct LinkV2Service<R: Repositories, M: TransactionManager + 'static> {
     pub link_repository: repositories::link::LinkRepository<R::Link>,
@@ -28,6 +51,7 @@ pub struct LinkV2Service<R: Repositories, M: TransactionManager + 'static> {
     pub user_link_action_repository:
         repositories::user_link_action::UserLinkActionRepository<R::UserLinkAction>,
     pub action_service: ActionService<R>,
+    pub fee_ledger_service: FeeLedgerService<R>,
     pub transaction_manager: Rc<M>,
 }
 
@@ -39,6 +63,7 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
             user_link_repository: repo.user_link(),
             user_link_action_repository: repo.user_link_action(),
             action_service: ActionService::new(repo),
+            fee_ledger_service: FeeLedgerService::new(repo),
             transaction_manager,
         }
     }
@@ -47,7 +72,7 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
     ///
     /// # Arguments
     /// * `creator` - The principal of the user creating the link
-    /// * `input` - The input data for creating the link
+    /// * `input` - The input data for creating the link, including an optional gate
     /// * `created_at_ts` - The timestamp when the link is created
     /// # Returns
     /// * `GetLinkResp` - The response containing the created link and action details
@@ -60,9 +85,23 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         input: CreateLinkInput,
         created_at_ts: u64,
     ) -> Result<CreateLinkDto, CanisterError> {
+        let gate_key = input.gate.clone();
         let factory = LinkFactory::new(self.transaction_manager.clone());
         let link_model = factory.create_link(creator_id, input, created_at_ts, canister_id)?;
 
+        // create action firstly, nothing is persisted until it succeeds
+        let link = factory.create_from_link(link_model, canister_id)?;
+        let result = link
+            .create_action(creator_id, ActionType::CreateLink, None, None, None, None)
+            .await?;
+        let mut link_model = result.link;
+
+        // the gate is attached last, so a failed creation leaves no orphan gate
+        if let Some(gate_key) = gate_key {
+            let gate = create_gate_for_link(&link_model.id, gate_key).await?;
+            link_model.gate_id = Some(gate.id);
+        }
+
         // save link & user_link to db
         self.link_repository.create(link_model.clone());
 
@@ -72,15 +111,8 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         };
         self.user_link_repository.create(new_user_link);
 
-        // create action firstly
-        let action_dto = self
-            .create_action(
-                creator_id,
-                canister_id,
-                &link_model.id,
-                ActionType::CreateLink,
-            )
-            .await?;
+        self.store_create_action_result(&result.create_action_result)?;
+        let action_dto: ActionDto = result.create_action_result.into();
 
         let link_dto = LinkDto::from(link_model);
 
@@ -90,6 +122,162 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         })
     }
 
+    /// Creates several links from the same template, funded by a single createAction.
+    /// The action belongs to the first link: it deposits the funds of every link and
+    /// charges the creation fee of all of them, and processing it activates every link.
+    /// Nothing is persisted until the action and the gates of all the links are created.
+    ///
+    /// # Arguments
+    /// * `creator_id` - The principal of the user creating the links
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `input` - The link template and the number of links to create
+    /// * `created_at_ts` - The timestamp when the links are created
+    /// # Returns
+    /// * `Ok(CreateLinkBatchDto)` - The created links, their action and the ICRC-112 requests funding them
+    /// * `Err(CanisterError)` - If the template is not valid for a batch or the creation fails
+    pub async fn create_link_batch(
+        &mut self,
+        creator_id: Principal,
+        canister_id: Principal,
+        input: CreateLinkBatchInput,
+        created_at_ts: u64,
+    ) -> Result<CreateLinkBatchDto, CanisterError> {
+        if input.link_count == 0 || input.link_count > MAX_LINK_BATCH_SIZE {
+            return Err(CanisterError::InvalidInput(format!(
+                "link_count must be between 1 and {MAX_LINK_BATCH_SIZE}"
+            )));
+        }
+        if matches!(
+            input.link.link_type,
+            LinkType::ReceivePayment | LinkType::ReceiveSplitPayment
+        ) {
+            return Err(CanisterError::InvalidInput(
+                "Only send links can be created in a batch".to_string(),
+            ));
+        }
+        if input.link.asset_info.iter().any(|info| info.asset.is_nft()) {
+            return Err(CanisterError::InvalidInput(
+                "NFT links cannot be created in a batch".to_string(),
+            ));
+        }
+
+        let factory = LinkFactory::new(self.transaction_manager.clone());
+        let mut links = (0..input.link_count)
+            .map(|_| {
+                factory.create_link(creator_id, input.link.clone(), created_at_ts, canister_id)
+            })
+            .collect::<Result<Vec<Link>, CanisterError>>()?;
+        let link_ids: Vec<String> = links.iter().map(|link| link.id.clone()).collect();
+        let first_link = links
+            .first_mut()
+            .ok_or_else(|| CanisterError::HandleLogicError("The batch has no link".to_string()))?;
+        first_link.batch_link_ids = Some(link_ids.iter().skip(1).cloned().collect());
+
+        // create the action funding every link firstly, nothing is persisted until it succeeds
+        let token_fee_map = get_batch_tokens_fee_for_link(first_link).await?;
+        let fee_intent = create_links_fee_intent(first_link, input.link_count, canister_id).await?;
+        let create_action =
+            CreateAction::build_batch(&links, canister_id, &token_fee_map, fee_intent)?;
+        let create_action_result = self
+            .transaction_manager
+            .create_action(create_action.action, create_action.intents, None)
+            .await?;
+
+        // the gates are attached last, so a failed creation leaves no orphan gate
+        if let Some(gate_key) = input.link.gate {
+            for link_model in links.iter_mut() {
+                let gate = create_gate_for_link(&link_model.id, gate_key.clone()).await?;
+                link_model.gate_id = Some(gate.id);
+            }
+        }
+
+        for link_model in links {
+            self.user_link_repository.create(UserLink {
+                user_id: creator_id,
+                link_id: link_model.id.clone(),
+            });
+            self.link_repository.create(link_model);
+        }
+        self.store_create_action_result(&create_action_result)?;
+
+        Ok(CreateLinkBatchDto {
+            link_ids,
+            action_id: create_action_result.action.id,
+            icrc_112_requests: create_action_result.icrc112_requests.unwrap_or_default(),
+        })
+    }
+
+    /// Estimates the cost of creating a link, per token, without creating it.
+    /// Only cached ledger fees are used, tokens whose fee is not cached are flagged.
+    /// # Arguments
+    /// * `creator` - The principal of the user who would create the link
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `input` - The input data for creating the link
+    /// * `created_at_ts` - The current timestamp
+    /// * `link_fee_settings` - The link creation fee schedule
+    /// * `cached_token_fee` - Returns the cached ledger fee of a token
+    /// # Returns
+    /// * `Ok(CreateLinkEstimateDto)` - The funding, ledger fees and service fee of each token
+    /// * `Err(CanisterError)` - If the input would not create a valid link
+    pub fn estimate_create_link(
+        &self,
+        creator: Principal,
+        canister_id: Principal,
+        input: CreateLinkInput,
+        created_at_ts: u64,
+        link_fee_settings: &LinkFeeSettings,
+        cached_token_fee: impl Fn(Principal) -> Option<Nat>,
+    ) -> Result<CreateLinkEstimateDto, CanisterError> {
+        let factory = LinkFactory::new(self.transaction_manager.clone());
+        let link = factory.create_link(creator, input, created_at_ts, canister_id)?;
+
+        let link_tokens: Vec<Principal> = link
+            .asset_info
+            .iter()
+            .filter(|info| !info.asset.is_nft())
+            .map(|info| info.asset.address())
+            .collect();
+        let link_fee =
+            link_fee_settings.get_link_creation_fee(&creator, link.link_type, &link_tokens);
+
+        let fee_map: HashMap<Principal, Nat> = link_tokens
+            .iter()
+            .copied()
+            .chain(link_fee.map(|fee| fee.token_id))
+            .filter_map(|token_id| cached_token_fee(token_id).map(|fee| (token_id, fee)))
+            .collect();
+
+        // receive links are not funded when they are created
+        let deposited_asset_info = match link.link_type {
+            LinkType::ReceivePayment | LinkType::ReceiveSplitPayment => &[][..],
+            _ => link.asset_info.as_slice(),
+        };
+        let cost_map = calculate_create_link_cost(
+            deposited_asset_info,
+            link_fee.as_ref(),
+            &fee_map,
+            link.link_use_action_max_count,
+        );
+
+        let tokens = cost_map
+            .into_iter()
+            .map(|(token_id, cost)| TokenCostEstimateDto {
+                token_id,
+                total_amount: cost.total_amount(),
+                funding_amount: cost.funding_amount,
+                ledger_fee_amount: cost.ledger_fee_amount,
+                service_fee_amount: cost.service_fee_amount,
+                approve_amount: cost.approve_amount,
+                is_ledger_fee_cached: fee_map.contains_key(&token_id),
+            })
+            .collect();
+
+        Ok(CreateLinkEstimateDto {
+            link_type: link.link_type,
+            tokens,
+        })
+    }
+
     /// Disables an existing link V2
     /// # Arguments
     /// * `caller` - The principal of the user disabling the link
@@ -113,9 +301,9 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
             ));
         }
 
-        if link.state != LinkState::Active {
+        if !matches!(link.state, LinkState::Active | LinkState::Scheduled) {
             return Err(CanisterError::ValidationErrors(
-                "Only active links can be disabled".to_string(),
+                "Only active or scheduled links can be disabled".to_string(),
             ));
         }
 
@@ -126,57 +314,422 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         Ok(LinkDto::from(link))
     }
 
+    /// Re-enables a disabled link V2 that still holds enough funds for its remaining uses
+    /// # Arguments
+    /// * `caller` - The principal of the user enabling the link
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `link_id` - The ID of the link to enable
+    /// * `now` - The current timestamp
+    /// # Returns
+    /// * `Ok(LinkDto)` - The enabled link data
+    /// * `Err(CanisterError)` - If enabling fails or unauthorized
+    pub async fn enable_link(
+        &mut self,
+        caller: Principal,
+        canister_id: Principal,
+        link_id: &str,
+        now: u64,
+    ) -> Result<LinkDto, CanisterError> {
+        let link = self
+            .link_repository
+            .get(&link_id.to_string())
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if link.creator != caller {
+            return Err(CanisterError::Unauthorized(
+                "Only the creator can enable the link".to_string(),
+            ));
+        }
+
+        if link.state != LinkState::Inactive {
+            return Err(CanisterError::ValidationErrors(
+                "Only inactive links can be enabled".to_string(),
+            ));
+        }
+
+        if link.is_expired(now) {
+            return Err(CanisterError::ValidationErrors(
+                "Link has expired".to_string(),
+            ));
+        }
+
+        if link.link_use_action_counter >= link.link_use_action_max_count {
+            return Err(CanisterError::ValidationErrors(
+                "Link has no remaining uses".to_string(),
+            ));
+        }
+
+        ensure_link_funded_for_remaining_uses(&link, canister_id).await?;
+
+        // reload the link as it may have changed while the balances were fetched
+        let mut link = self
+            .link_repository
+            .get(&link_id.to_string())
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+        if link.state != LinkState::Inactive {
+            return Err(CanisterError::ValidationErrors(
+                "Only inactive links can be enabled".to_string(),
+            ));
+        }
+
+        // a link disabled before its start time waits for it again
+        link.state = if link.is_started(now) {
+            LinkState::Active
+        } else {
+            LinkState::Scheduled
+        };
+        self.link_repository.update(link.clone());
+
+        Ok(LinkDto::from(link))
+    }
+
+    /// Updates the metadata of an existing link V2.
+    /// Financial fields (assets, amounts, use counts) cannot be changed.
+    /// # Arguments
+    /// * `caller` - The principal of the user updating the link
+    /// * `input` - The metadata changes to apply
+    /// * `now` - The current timestamp
+    /// # Returns
+    /// * `Ok(LinkDto)` - The updated link data
+    /// * `Err(CanisterError)` - If the update is invalid or unauthorized
+    pub fn update_link(
+        &mut self,
+        caller: Principal,
+        input: UpdateLinkV2Input,
+        now: u64,
+    ) -> Result<LinkDto, CanisterError> {
+        let mut link = self
+            .link_repository
+            .get(&input.link_id)
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if link.creator != caller {
+            return Err(CanisterError::Unauthorized(
+                "Only the creator can update the link".to_string(),
+            ));
+        }
+
+        if !matches!(
+            link.state,
+            LinkState::CreateLink | LinkState::Scheduled | LinkState::Active
+        ) {
+            return Err(CanisterError::ValidationErrors(
+                "Only links that have not ended can be updated".to_string(),
+            ));
+        }
+
+        if let Some(title) = input.title {
+            if title.trim().is_empty() {
+                return Err(CanisterError::InvalidInput(
+                    "title must not be empty".to_string(),
+                ));
+            }
+            link.title = title;
+        }
+
+        if let Some(expires_at) = input.expires_at {
+            if expires_at <= now {
+                return Err(CanisterError::InvalidInput(
+                    "expires_at must be in the future".to_string(),
+                ));
+            }
+            if link
+                .starts_at
+                .is_some_and(|starts_at| starts_at >= expires_at)
+            {
+                return Err(CanisterError::InvalidInput(
+                    "starts_at must be earlier than expires_at".to_string(),
+                ));
+            }
+            link.expires_at = Some(expires_at);
+        }
+
+        if let Some(description) = input.description {
+            link.description = Some(description);
+        }
+        if let Some(image_url) = input.image_url {
+            link.image_url = Some(image_url);
+        }
+
+        self.link_repository.update(link.clone());
+
+        Ok(LinkDto::from(link))
+    }
+
+    /// Ends an active link whose expiry has passed, leaving the remaining
+    /// balance to be withdrawn by the creator.
+    /// # Arguments
+    /// * `link_id` - The ID of the link to expire
+    /// * `now` - The current timestamp
+    /// # Returns
+    /// * `Ok(LinkDto)` - The expired link data
+    /// * `Err(CanisterError)` - If the link is not found, not active or not yet expired
+    pub fn expire_link(&mut self, link_id: &str, now: u64) -> Result<LinkDto, CanisterError> {
+        let mut link = self
+            .link_repository
+            .get(&link_id.to_string())
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if link.state != LinkState::Active {
+            return Err(CanisterError::ValidationErrors(
+                "Only active links can expire".to_string(),
+            ));
+        }
+
+        if !link.is_expired(now) {
+            return Err(CanisterError::ValidationErrors(
+                "Link has not expired yet".to_string(),
+            ));
+        }
+
+        // the creator withdraws the leftovers from the inactive link, which then ends
+        link.state = LinkState::Inactive;
+        self.link_repository.update(link.clone());
+
+        Ok(LinkDto::from(link))
+    }
+
+    /// Opens a scheduled link once its start time has been reached.
+    /// # Arguments
+    /// * `link_id` - The ID of the link to open
+    /// * `now` - The current timestamp
+    /// # Returns
+    /// * `Ok(LinkDto)` - The opened link data
+    /// * `Err(CanisterError)` - If the link is not found, not scheduled or not yet started
+    pub fn start_link(&mut self, link_id: &str, now: u64) -> Result<LinkDto, CanisterError> {
+        let mut link = self
+            .link_repository
+            .get(&link_id.to_string())
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if link.state != LinkState::Scheduled {
+            return Err(CanisterError::ValidationErrors(
+                "Only scheduled links can be started".to_string(),
+            ));
+        }
+
+        if !link.is_started(now) {
+            return Err(CanisterError::ValidationErrors(
+                "Link has not started yet".to_string(),
+            ));
+        }
+
+        link.state = LinkState::Active;
+        self.link_repository.update(link.clone());
+
+        Ok(LinkDto::from(link))
+    }
+
+    /// Retrieves, from a chunk of links taken in ID order, the links still waiting for
+    /// their start or expiry time.
+    /// # Arguments
+    /// * `after` - The ID of the last link of the previous chunk, None to start from the first link
+    /// * `limit` - The number of links in the chunk
+    /// # Returns
+    /// * `(Vec<Link>, Option<String>)` - The waiting links, and the ID to continue from
+    ///   if more links are left
+    pub fn get_links_waiting_for_timers(
+        &self,
+        after: Option<&str>,
+        limit: usize,
+    ) -> (Vec<Link>, Option<String>) {
+        let links = self.link_repository.get_links_after(after, limit);
+        let next = if links.len() == limit {
+            links.last().map(|link| link.id.clone())
+        } else {
+            None
+        };
+
+        let waiting_links = links
+            .into_iter()
+            .filter(|link| {
+                let waits_for_start =
+                    link.state == LinkState::Scheduled && link.starts_at.is_some();
+                let waits_for_expiry =
+                    matches!(link.state, LinkState::Active | LinkState::Scheduled)
+                        && link.expires_at.is_some();
+                waits_for_start || waits_for_expiry
+            })
+            .collect();
+        (waiting_links, next)
+    }
+
     /// Creates a new action V2.
     /// # Arguments
     /// * `caller` - The principal of the user creating the action
     /// * `canister_id` - The canister ID of the token contract
     /// * `link_id` - The ID of the link for which the action is created
     /// * `action_type` - The type of action to be created
+    /// * `amount` - The amount chosen by the caller, only for variable-amount links
+    /// * `top_up_use_count` - The number of uses funded, only for TOP_UP actions
+    /// * `btc_address` - The Bitcoin address paid instead of the caller's wallet, only for RECEIVE actions
+    /// * `destination` - The wallet paid instead of the caller's principal, for RECEIVE actions
+    /// * `now` - The current timestamp
     /// # Returns
     /// * `Ok(ActionDto)` - The created action data
     /// * `Err(CanisterError)` - If action creation fails or validation errors occur
+    #[allow(clippy::too_many_arguments)]
     pub async fn create_action(
         &mut self,
         caller: Principal,
         canister_id: Principal,
         link_id: &str,
         action_type: ActionType,
+        amount: Option<Nat>,
+        top_up_use_count: Option<u64>,
+        btc_address: Option<String>,
+        destination: Option<Wallet>,
+        now: u64,
     ) -> Result<ActionDto, CanisterError> {
-        // Check if action already exists for this user, link, and action type
-        self.action_service
-            .check_action_exists_for_user(caller, link_id, &action_type)?;
-
-        let link_model = self
+        let mut link_model = self
             .link_repository
             .get(&link_id.to_string())
             .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
 
+        if action_type == ActionType::Receive {
+            self.release_expired_reservations(&mut link_model, now);
+        }
+
+        if amount.is_some() && link_model.amount_bounds.is_none() {
+            return Err(CanisterError::InvalidInput(
+                "This link does not accept a custom amount".to_string(),
+            ));
+        }
+
+        if top_up_use_count.is_some() && action_type != ActionType::TopUp {
+            return Err(CanisterError::InvalidInput(
+                "Only TOP_UP actions can fund more uses".to_string(),
+            ));
+        }
+
+        if btc_address.is_some() && action_type != ActionType::Receive {
+            return Err(CanisterError::InvalidInput(
+                "Only RECEIVE actions can pay out to a Bitcoin address".to_string(),
+            ));
+        }
+
+        if destination.is_some() && action_type != ActionType::Receive {
+            return Err(CanisterError::InvalidInput(
+                "Only RECEIVE actions can pay out to another wallet".to_string(),
+            ));
+        }
+
+        if btc_address.is_some() && destination.is_some() {
+            return Err(CanisterError::InvalidInput(
+                "A Bitcoin address and a destination wallet cannot both be set".to_string(),
+            ));
+        }
+
+        // Check if the user still can create an action of this type on the link
+        let max_actions_per_user = match action_type {
+            ActionType::Receive => link_model.max_use_per_user.unwrap_or(1),
+            ActionType::TopUp => u64::MAX,
+            _ => 1,
+        };
+        self.action_service.check_action_exists_for_user(
+            caller,
+            link_id,
+            &action_type,
+            max_actions_per_user,
+        )?;
+
+        let link_model_random_pot = link_model.random_pot.clone();
+        let link_model_asset_info = link_model.asset_info.clone();
         let factory = LinkFactory::new(self.transaction_manager.clone());
         let link = factory.create_from_link(link_model, canister_id)?;
-        let result = link.create_action(caller, action_type).await?;
+        let result = link
+            .create_action(
+                caller,
+                action_type,
+                amount,
+                top_up_use_count,
+                btc_address,
+                destination,
+            )
+            .await?;
+
+        // random links reserve a share of their pot and NFT links a token id when an action is created
+        if result.link.random_pot != link_model_random_pot
+            || result.link.asset_info != link_model_asset_info
+        {
+            self.link_repository.update(result.link.clone());
+        }
 
         // save data to DB
+        self.store_create_action_result(&result.create_action_result)?;
+
+        let action_dto: ActionDto = result.create_action_result.into();
+
+        Ok(action_dto)
+    }
+
+    /// Gives the expired random shares and NFT token ids reserved by claims back to the link,
+    /// so they can be claimed again. The claims holding them are abandoned, which lets their
+    /// creators claim again too. Claims being processed or that already sent part of their
+    /// share keep their reservations.
+    /// # Arguments
+    /// * `link` - The link, updated and persisted if a reservation is released
+    /// * `now` - The current timestamp
+    fn release_expired_reservations(&mut self, link: &mut Link, now: u64) {
+        let expired_action_ids: BTreeSet<String> = link
+            .random_pot
+            .iter()
+            .flat_map(|random_pot| random_pot.expired_reservations(now))
+            .chain(
+                link.asset_info
+                    .iter()
+                    .flat_map(|asset_info| asset_info.expired_token_id_reservations(now)),
+            )
+            .collect();
+
+        let mut is_released = false;
+        for action_id in expired_action_ids {
+            if self.action_service.abandon_action(&action_id).is_err() {
+                continue;
+            }
+            if let Some(random_pot) = link.random_pot.as_mut() {
+                is_released |= random_pot.release(&action_id);
+            }
+            for asset_info in link.asset_info.iter_mut() {
+                is_released |= asset_info.release_token_ids(&action_id);
+            }
+        }
+
+        if is_released {
+            self.link_repository.update(link.clone());
+        }
+    }
+
+    /// Stores a newly created action with its intents and transactions.
+    /// # Arguments
+    /// * `create_action_result` - The created action
+    /// # Returns
+    /// * `Ok(())` - If the action is stored
+    /// * `Err(CanisterError)` - If storing the action data fails
+    fn store_create_action_result(
+        &mut self,
+        create_action_result: &CreateActionResult,
+    ) -> Result<(), CanisterError> {
+        let action = &create_action_result.action;
         let link_action = LinkAction {
-            link_id: link_id.to_string(),
-            action_type: result.create_action_result.action.r#type.clone(),
-            action_id: result.create_action_result.action.id.clone(),
-            user_id: result.create_action_result.action.creator,
+            link_id: action.link_id.clone(),
+            action_type: action.r#type.clone(),
+            action_id: action.id.clone(),
+            user_id: action.creator,
             link_user_state: None,
         };
 
         self.action_service.store_action_data(
             link_action.clone(),
-            result.create_action_result.action.clone(),
-            result.create_action_result.intents.clone(),
-            result.create_action_result.intent_txs_map.clone(),
-            result.create_action_result.action.creator,
+            action.clone(),
+            create_action_result.intents.clone(),
+            create_action_result.intent_txs_map.clone(),
+            action.creator,
         )?;
 
         self.user_link_action_repository.create(link_action);
 
-        let action_dto: ActionDto = result.create_action_result.into();
-
-        Ok(action_dto)
+        Ok(())
     }
 
     /// Processes a created action V2.
@@ -184,6 +737,7 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
     /// * `caller` - The principal of the user processing the action
     /// * `canister_id` - The canister ID of the token contract
     /// * `action_id` - The ID of the action to be processed
+    /// * `now` - The current timestamp
     /// # Returns
     /// * `Ok(ProcessActionDto)` - The processed action data
     /// * `Err(CanisterError)` - If action processing fails or validation errors occur
@@ -192,6 +746,7 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         caller: Principal,
         canister_id: Principal,
         action_id: &str,
+        now: u64,
     ) -> Result<ProcessActionDto, CanisterError> {
         let action_data = self
             .action_service
@@ -205,23 +760,67 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
 
         let factory = LinkFactory::new(self.transaction_manager.clone());
         let link = factory.create_from_link(link_model, canister_id)?;
-        let result = link
+
+        // persisted at the first await, so an interrupted call leaves the action recoverable
+        self.action_service
+            .mark_action_processing(&action_data, now);
+        let process_result = link
             .process_action(
                 caller,
-                action_data.action,
-                action_data.intents,
-                action_data.intent_txs,
+                action_data.action.clone(),
+                action_data.intents.clone(),
+                action_data.intent_txs.clone(),
             )
-            .await?;
+            .await;
+        let mut result = match process_result {
+            Ok(result) => result,
+            Err(err) => {
+                // the action was not processed, restore its previous state
+                self.action_service.update_action_data(
+                    action_data.action,
+                    action_data.intents,
+                    &action_data.intent_txs,
+                )?;
+                return Err(err);
+            }
+        };
+
+        // keep random pot and NFT reservations made by actions created while this one was processed
+        if let Some(stored_link) = self.link_repository.get(&result.link.id) {
+            result.link.random_pot = stored_link.random_pot;
+            result.link.asset_info = stored_link.asset_info;
+        }
+
+        // the share and NFTs of a processed claim are sent and no longer held
+        if result.process_action_result.is_success {
+            if let Some(random_pot) = result.link.random_pot.as_mut() {
+                random_pot.settle(action_id);
+            }
+            for asset_info in result.link.asset_info.iter_mut() {
+                asset_info.settle_token_ids(action_id);
+            }
+        }
 
         // save data to DB
         self.link_repository.update(result.link.clone());
+        if result.process_action_result.is_success
+            && result.process_action_result.action.r#type == ActionType::CreateLink
+        {
+            self.activate_batch_links(&result.link);
+        }
         self.action_service.update_action_data(
             result.process_action_result.action.clone(),
             result.process_action_result.intents.clone(),
             &result.process_action_result.intent_txs_map,
         )?;
         self.action_service.update_link_user_state(&result);
+        self.fee_ledger_service.record_collected_fees(
+            &result.link,
+            &result.process_action_result.action,
+            &action_data.intents,
+            &result.process_action_result.intents,
+            now,
+        );
 
         // response dto
         let action_dto = ActionDto::build(
@@ -242,6 +841,258 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
         })
     }
 
+    /// Moves the other links funded by the CREATE_LINK action of a batch to the state
+    /// the link owning the action reached once funded.
+    /// # Arguments
+    /// * `link` - The link owning the processed CREATE_LINK action
+    fn activate_batch_links(&mut self, link: &Link) {
+        for link_id in link.batch_link_ids.iter().flatten() {
+            if let Some(mut batch_link) = self.link_repository.get(link_id)
+                && batch_link.state == LinkState::CreateLink
+            {
+                batch_link.state = link.state.clone();
+                self.link_repository.update(batch_link);
+            }
+        }
+    }
+
+    /// Retrieves the actions left in processing by calls that never completed.
+    /// # Arguments
+    /// * `now` - The current timestamp
+    /// * `timeout_ns` - The time in nanoseconds after which a processing action is stuck
+    /// # Returns
+    /// * `Vec<Action>` - The stuck actions
+    pub fn get_stuck_actions(&mut self, now: u64, timeout_ns: u64) -> Vec<Action> {
+        self.action_service.get_stuck_actions(now, timeout_ns)
+    }
+
+    /// Recovers an action left in processing by a call that trapped or timed out.
+    /// Its wallet transactions are validated against the ledgers and its state is
+    /// rolled up, no transfer is made again. An action whose transactions all succeeded
+    /// is completed on the link side as a processed action. An action shown not to have
+    /// happened, or still unfinished after processing for the given maximum age, is failed.
+    /// # Arguments
+    /// * `action_id` - The ID of the stuck action
+    /// * `canister_id` - The canister ID of the backend canister
+    /// * `now` - The current timestamp
+    /// * `max_age_ns` - The time in nanoseconds after which an unfinished action is failed
+    /// # Returns
+    /// * `Ok((ActionState, Option<ProcessActionDto>))` - The state of the action once recovered,
+    ///   with the processed action data if its link side was completed
+    /// * `Err(CanisterError)` - If the action is not processing or could not be recovered yet
+    pub async fn recover_action(
+        &mut self,
+        action_id: &str,
+        canister_id: Principal,
+        now: u64,
+        max_age_ns: u64,
+    ) -> Result<(ActionState, Option<ProcessActionDto>), CanisterError> {
+        let action_data = self
+            .action_service
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
+
+        if action_data.action.state != ActionState::Processing {
+            return Err(CanisterError::ValidationErrors(
+                "Only processing actions can be recovered".to_string(),
+            ));
+        }
+
+        let started_at = self.action_service.get_processing_started_at(action_id);
+        let is_too_old =
+            started_at.is_none_or(|started_at| now.saturating_sub(started_at) >= max_age_ns);
+        let creator = action_data.action.creator;
+
+        let recovery = match self
+            .transaction_manager
+            .validate_action(
+                action_data.action,
+                action_data.intents,
+                action_data.intent_txs,
+            )
+            .await
+        {
+            Ok(result) if result.action.state == ActionState::Fail => {
+                self.action_service.update_action_data(
+                    result.action,
+                    result.intents,
+                    &result.intent_txs_map,
+                )?;
+                // the transactions left in processing are failed with the action
+                self.action_service.fail_action(action_id)?;
+                return Ok((ActionState::Fail, None));
+            }
+            Ok(result) => {
+                let is_success = result.action.state == ActionState::Success;
+                let mut action = result.action;
+                action.state = ActionState::Processing;
+                self.action_service.update_action_data(
+                    action,
+                    result.intents,
+                    &result.intent_txs_map,
+                )?;
+
+                // executed transactions are not sent again, processing only completes the link side
+                if is_success {
+                    self.process_action(creator, canister_id, action_id, now)
+                        .await
+                        .map(Some)
+                } else {
+                    Ok(None)
+                }
+            }
+            Err(err) => Err(err),
+        };
+
+        let action_state = self
+            .action_service
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?
+            .action
+            .state;
+        if action_state != ActionState::Processing {
+            return Ok((action_state, recovery.ok().flatten()));
+        }
+
+        if is_too_old {
+            self.action_service.fail_action(action_id)?;
+            return Ok((ActionState::Fail, None));
+        }
+
+        // the age of the action is kept across the processing retried above
+        if let Some(started_at) = started_at {
+            self.action_service
+                .set_processing_started_at(action_id, started_at);
+        }
+        recovery.map(|_| (ActionState::Processing, None))
+    }
+
+    /// Retrieves the receipt of an action, listing every ledger transfer it made.
+    /// # Arguments
+    /// * `caller` - The principal of the user requesting the receipt
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `Ok(ActionReceiptDto)` - The receipt of the action
+    /// * `Err(CanisterError)` - If the action is not found or the caller is neither
+    ///   the action creator nor the link creator
+    pub fn get_action_receipt(
+        &self,
+        caller: Principal,
+        action_id: &str,
+    ) -> Result<ActionReceiptDto, CanisterError> {
+        let action_data = self
+            .action_service
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
+
+        let link = self
+            .link_repository
+            .get(&action_data.action.link_id)
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if caller != action_data.action.creator && caller != link.creator {
+            return Err(CanisterError::Unauthorized(
+                "Only the action creator or the link creator can view this receipt".to_string(),
+            ));
+        }
+
+        Ok(ActionReceiptDto::from(&action_data))
+    }
+
+    /// Lists the receive, send and withdraw actions made on a link.
+    /// # Arguments
+    /// * `caller` - The principal of the user requesting the history
+    /// * `link_id` - The ID of the link
+    /// * `paginate` - Optional pagination parameters
+    /// # Returns
+    /// * `Ok(PaginateResult<LinkActionHistoryDto>)` - The page of link actions
+    /// * `Err(CanisterError)` - If the link is not found or the caller is not the link creator
+    pub fn get_link_actions(
+        &self,
+        caller: Principal,
+        link_id: &str,
+        paginate: Option<PaginateInput>,
+    ) -> Result<PaginateResult<LinkActionHistoryDto>, CanisterError> {
+        let link = self
+            .link_repository
+            .get(&link_id.to_string())
+            .ok_or_else(|| CanisterError::NotFound("Link not found".to_string()))?;
+
+        if link.creator != caller {
+            return Err(CanisterError::Unauthorized(
+                "Only the creator can view the link actions".to_string(),
+            ));
+        }
+
+        let link_actions = self.action_service.get_link_actions(
+            link_id,
+            &[ActionType::Receive, ActionType::Send, ActionType::Withdraw],
+        );
+
+        let mut history = link_actions
+            .iter()
+            .map(|link_action| {
+                self.action_service
+                    .get_action_data(&link_action.action_id)
+                    .map(|action_data| LinkActionHistoryDto::build(link_action, &action_data))
+                    .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        // the link actions are keyed by user, list them by creation time instead
+        history.sort_by(|a, b| {
+            a.created_at
+                .cmp(&b.created_at)
+                .then_with(|| a.action_id.cmp(&b.action_id))
+        });
+
+        Ok(PaginateResult::paginate(
+            history,
+            &paginate.unwrap_or_default(),
+        ))
+    }
+
+    /// Simulates processing an action against the current ledger balances and allowances,
+    /// without making any transfer or changing the action.
+    /// # Arguments
+    /// * `caller` - The principal of the user simulating the action
+    /// * `action_id` - The ID of the action to simulate
+    /// # Returns
+    /// * `Ok(SimulateActionDto)` - The predicted outcome and the first blocking error
+    /// * `Err(CanisterError)` - If the action is not found, already processed or not the caller's
+    pub async fn simulate_action(
+        &self,
+        caller: Principal,
+        action_id: &str,
+    ) -> Result<SimulateActionDto, CanisterError> {
+        let action_data = self
+            .action_service
+            .get_action_data(action_id)
+            .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
+
+        if caller != action_data.action.creator {
+            return Err(CanisterError::Unauthorized(
+                "Only the action creator can simulate this action".to_string(),
+            ));
+        }
+
+        if action_data.action.state == ActionState::Success {
+            return Err(CanisterError::ValidationErrors(
+                "Action has already been processed".to_string(),
+            ));
+        }
+
+        let result = self
+            .transaction_manager
+            .simulate_action(
+                action_data.action,
+                action_data.intents,
+                Some(action_data.intent_txs),
+            )
+            .await?;
+
+        Ok(SimulateActionDto::from(result))
+    }
+
     /// Retrieves a paginated list of links of caller.
     /// # Arguments
     /// * `caller` - The principal of the user retrieving the links
@@ -303,11 +1154,10 @@ impl<R: Repositories, M: TransactionManager + 'static> LinkV2Service<R, M> {
                 .get_action_data(&action.id)
                 .map_err(|_e| CanisterError::NotFound("Action not found".to_string()))?;
 
-            let create_action_result = self.transaction_manager.create_action(
-                action,
-                action_data.intents,
-                Some(action_data.intent_txs),
-            )?;
+            let create_action_result = self
+                .transaction_manager
+                .create_action(action, action_data.intents, Some(action_data.intent_txs))
+                .await?;
 
             Some(create_action_result.into())
         } else {
@@ -3,6 +3,8 @@
 
 pub mod action;
 pub mod auth;
+pub mod fee_ledger;
+pub mod gate;
 pub mod link_v2;
 pub mod request_lock;
 pub mod settings;
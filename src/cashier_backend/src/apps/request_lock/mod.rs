@@ -47,6 +47,19 @@ impl<R: Repositories> RequestLockService<R> {
         Ok(key.to_owned())
     }
 
+    /// Takes a request lock that is released when the returned guard is dropped.
+    /// The guard is also dropped when the call holding it traps after an await,
+    /// as the call's future is cancelled and its destructors run.
+    /// Returns Err if lock already exists
+    pub fn lock(
+        mut self,
+        key: RequestLockKey,
+        timestamp: u64,
+    ) -> Result<RequestLockGuard<R>, CanisterError> {
+        self.create(&key, timestamp)?;
+        Ok(RequestLockGuard { service: self, key })
+    }
+
     /// Drop (delete) a request lock
     /// Returns Ok(()) regardless of whether the lock existed
     pub fn drop(&mut self, key: &RequestLockKey) -> Result<(), CanisterError> {
@@ -55,3 +68,62 @@ impl<R: Repositories> RequestLockService<R> {
         Ok(())
     }
 }
+
+/// A request lock held until the guard is dropped
+pub struct RequestLockGuard<R: Repositories> {
+    service: RequestLockService<R>,
+    key: RequestLockKey,
+}
+
+impl<R: Repositories> Drop for RequestLockGuard<R> {
+    fn drop(&mut self) {
+        let _ = self.service.drop(&self.key);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::tests::TestRepositories;
+    use cashier_common::test_utils::{random_id_string, random_principal_id};
+
+    fn process_action_key() -> RequestLockKey {
+        RequestLockKey::ProcessAction {
+            user_principal: random_principal_id(),
+            action_id: random_id_string(),
+        }
+    }
+
+    #[test]
+    fn it_should_release_the_lock_when_the_guard_is_dropped() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let key = process_action_key();
+        let guard = RequestLockService::new(&repo)
+            .lock(key.clone(), 1_000)
+            .unwrap();
+
+        // Act
+        drop(guard);
+
+        // Assert
+        assert!(!repo.request_lock().exists(&key));
+    }
+
+    #[test]
+    fn it_should_not_lock_a_held_lock() {
+        // Arrange
+        let repo = TestRepositories::new();
+        let key = process_action_key();
+        let _guard = RequestLockService::new(&repo)
+            .lock(key.clone(), 1_000)
+            .unwrap();
+
+        // Act
+        let result = RequestLockService::new(&repo).lock(key.clone(), 2_000);
+
+        // Assert
+        assert!(result.is_err());
+        assert!(repo.request_lock().exists(&key));
+    }
+}
@@ -1,4 +1,7 @@
 use crate::repositories::{Repositories, settings::SettingsRepository};
+use candid::Principal;
+use cashier_backend_types::init::{CkBtcMinterSettings, LinkFeeSettings};
+use cashier_common::constant::DEFAULT_ACTION_PROCESSING_TIMEOUT_NS;
 
 /// The settings service
 pub struct SettingsService<R: Repositories> {
@@ -25,4 +28,58 @@ impl<R: Repositories> SettingsService<R> {
             settings.inspect_message_enabled = inspect_message_enabled;
         });
     }
+
+    /// Get the gate_service canister id
+    pub fn get_gate_service_canister_id(&self) -> Option<Principal> {
+        self.settings_repo
+            .read(|settings| settings.gate_service_canister_id)
+    }
+
+    /// Set the gate_service canister id
+    pub fn set_gate_service_canister_id(&mut self, canister_id: Principal) {
+        self.settings_repo.update(|settings| {
+            settings.gate_service_canister_id = Some(canister_id);
+        });
+    }
+
+    /// Get the time after which an action stuck in processing is recovered
+    pub fn get_action_processing_timeout_ns(&self) -> u64 {
+        self.settings_repo.read(|settings| {
+            settings
+                .action_processing_timeout_ns
+                .unwrap_or(DEFAULT_ACTION_PROCESSING_TIMEOUT_NS)
+        })
+    }
+
+    /// Set the time after which an action stuck in processing is recovered
+    pub fn set_action_processing_timeout_ns(&mut self, timeout_ns: u64) {
+        self.settings_repo.update(|settings| {
+            settings.action_processing_timeout_ns = Some(timeout_ns);
+        });
+    }
+
+    /// Get the ckBTC minter used to pay out claims to Bitcoin addresses
+    pub fn get_ckbtc_minter(&self) -> Option<CkBtcMinterSettings> {
+        self.settings_repo.read(|settings| settings.ckbtc_minter)
+    }
+
+    /// Set the ckBTC minter used to pay out claims to Bitcoin addresses
+    pub fn set_ckbtc_minter(&mut self, ckbtc_minter: CkBtcMinterSettings) {
+        self.settings_repo.update(|settings| {
+            settings.ckbtc_minter = Some(ckbtc_minter);
+        });
+    }
+
+    /// Get the link creation fee schedule
+    pub fn get_link_fee(&self) -> LinkFeeSettings {
+        self.settings_repo
+            .read(|settings| settings.link_fee.clone().unwrap_or_default())
+    }
+
+    /// Set the link creation fee schedule
+    pub fn set_link_fee(&mut self, link_fee: LinkFeeSettings) {
+        self.settings_repo.update(|settings| {
+            settings.link_fee = Some(link_fee);
+        });
+    }
 }
@@ -94,6 +94,22 @@ impl<R: Repositories, E: IcEnvironment, F: TokenFetcher> TokenFeeService<R, E, F
             < TOKEN_FEE_TTL_NS.with(|cell| *cell.borrow())
     }
 
+    /// Returns the cached fee of a token without fetching it.
+    ///
+    /// # Arguments
+    ///
+    /// * `token_id` - The `Principal` of the token canister
+    ///
+    /// # Returns
+    ///
+    /// Returns the fee if it is cached and not expired, `None` otherwise.
+    pub fn get_cached_token_fee(&self, token_id: Principal) -> Option<Nat> {
+        self.token_fee_repo
+            .get(&token_id.to_text())
+            .filter(|cached| self.is_valid(cached))
+            .map(|cached| cached.fee)
+    }
+
     /// Retrieves fees for multiple tokens, leveraging cache when available.
     ///
     /// For each asset:
@@ -102,6 +118,8 @@ impl<R: Repositories, E: IcEnvironment, F: TokenFetcher> TokenFeeService<R, E, F
     /// 3. If not cached or expired, fetches fresh fee from the token canister via the fetcher
     /// 4. Stores the newly fetched fee in the cache with current timestamp
     ///
+    /// NFT collections have no fee, they are returned as 0 without any call.
+    ///
     /// # Arguments
     ///
     /// * `assets` - Slice of assets to retrieve fees for
@@ -124,7 +142,12 @@ impl<R: Repositories, E: IcEnvironment, F: TokenFetcher> TokenFeeService<R, E, F
 
         for asset in assets {
             let address = match asset {
-                Asset::IC { address, .. } => *address,
+                Asset::IC { address } => *address,
+                // NFT collections charge no transfer fee
+                Asset::IcNft { address } => {
+                    fee_map_result.insert(*address, Nat::from(0u64));
+                    continue;
+                }
             };
             let key = address.to_text();
 
@@ -324,6 +347,24 @@ mod tests {
         assert!(result.is_empty());
     }
 
+    #[tokio::test]
+    async fn should_success_return_zero_fee_for_nft_without_fetching() {
+        setup_ttl(DEFAULT_TOKEN_FEE_TTL_NS);
+
+        let fetcher = MockTokenFetcher::new();
+        let collection = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+
+        let mut service = create_service_with_fetcher(1768451390000000300, fetcher.clone());
+        let assets = vec![Asset::IcNft {
+            address: collection,
+        }];
+
+        let result: HashMap<Principal, Nat> = service.get_batch_tokens_fee(&assets).await.unwrap();
+
+        assert_eq!(result.get(&collection), Some(&Nat::from(0u64)));
+        assert_eq!(fetcher.get_call_count(&collection), 0);
+    }
+
     #[tokio::test]
     async fn should_error_propagate_fetch_error() {
         setup_ttl(DEFAULT_TOKEN_FEE_TTL_NS);
@@ -339,4 +380,43 @@ mod tests {
             service.get_batch_tokens_fee(&assets).await;
         assert!(result.is_err());
     }
+
+    #[test]
+    fn should_success_return_cached_fee_without_fetching() {
+        setup_ttl(DEFAULT_TOKEN_FEE_TTL_NS);
+        let fetcher = MockTokenFetcher::new();
+        let p1 = Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap();
+        let p2 = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+
+        let mut service = create_service_with_fetcher(1000, fetcher.clone());
+        service.token_fee_repo.insert(
+            &p1.to_text(),
+            CachedFee {
+                fee: Nat::from(100u64),
+                updated_at: 1000,
+            },
+        );
+
+        assert_eq!(service.get_cached_token_fee(p1), Some(Nat::from(100u64)));
+        assert_eq!(service.get_cached_token_fee(p2), None);
+        assert_eq!(fetcher.get_call_count(&p1), 0);
+        assert_eq!(fetcher.get_call_count(&p2), 0);
+    }
+
+    #[test]
+    fn should_success_ignore_expired_cached_fee() {
+        setup_ttl(100);
+        let p1 = Principal::from_text("ryjl3-tyaaa-aaaaa-aaaba-cai").unwrap();
+
+        let mut service = create_service(1000);
+        service.token_fee_repo.insert(
+            &p1.to_text(),
+            CachedFee {
+                fee: Nat::from(100u64),
+                updated_at: 500,
+            },
+        );
+
+        assert_eq!(service.get_cached_token_fee(p1), None);
+    }
 }
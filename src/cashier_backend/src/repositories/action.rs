@@ -60,6 +60,7 @@ mod tests {
             state: ActionState::Processing,
             creator: random_principal_id(),
             link_id: "link1".to_string(),
+            top_up_count: None,
         };
 
         // Act
@@ -82,6 +83,7 @@ mod tests {
             state: ActionState::Processing,
             creator: random_principal_id(),
             link_id: "link1".to_string(),
+            top_up_count: None,
         };
         repo.create(action);
 
@@ -91,6 +93,7 @@ mod tests {
             state: ActionState::Success,
             creator: random_principal_id(),
             link_id: "link2".to_string(),
+            top_up_count: None,
         };
 
         // Act
@@ -128,6 +131,7 @@ mod tests {
             state: ActionState::Created,
             creator: random_principal_id(),
             link_id: "link1".to_string(),
+            top_up_count: None,
         };
         repo.create(action);
 
@@ -0,0 +1,198 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use cashier_backend_types::{
+    repository::fee_ledger::v1::{FeeLedgerCodec, FeeLedgerEntry},
+    service::link::{PaginateInput, PaginateResult, PaginateResultMetadata},
+};
+use ic_mple_log::service::Storage;
+use ic_mple_structures::{BTreeMapIteratorStructure, BTreeMapStructure, VersionedBTreeMap};
+use ic_stable_structures::{DefaultMemoryImpl, memory_manager::VirtualMemory};
+use std::ops::Range;
+
+pub type FeeLedgerRepositoryStorage =
+    VersionedBTreeMap<String, FeeLedgerEntry, FeeLedgerCodec, VirtualMemory<DefaultMemoryImpl>>;
+
+/// Orders the entries by collection time, the zero padding keeps the string order numeric
+struct FeeLedgerKey<'a> {
+    pub collected_at: u64,
+    pub intent_id: &'a str,
+}
+
+impl<'a> FeeLedgerKey<'a> {
+    pub fn to_str(&self) -> String {
+        format!("{:020}#INTENT#{}", self.collected_at, self.intent_id)
+    }
+}
+
+/// Returns the range of keys of the entries collected in the given period
+fn period_key_range(start_at: u64, end_at: u64) -> Range<String> {
+    let start = FeeLedgerKey {
+        collected_at: start_at,
+        intent_id: "",
+    };
+    let end = FeeLedgerKey {
+        collected_at: end_at,
+        intent_id: "",
+    };
+    start.to_str()..end.to_str()
+}
+
+#[derive(Clone)]
+pub struct FeeLedgerRepository<S: Storage<FeeLedgerRepositoryStorage>> {
+    storage: S,
+}
+
+impl<S: Storage<FeeLedgerRepositoryStorage>> FeeLedgerRepository<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Records a collected fee, keyed by its collection time and fee intent
+    /// # Arguments
+    /// * `entry` - The FeeLedgerEntry to be recorded
+    pub fn create(&mut self, entry: FeeLedgerEntry) {
+        self.storage.with_borrow_mut(|store| {
+            let id = FeeLedgerKey {
+                collected_at: entry.collected_at,
+                intent_id: &entry.intent_id,
+            };
+            store.insert(id.to_str(), entry);
+        });
+    }
+
+    /// Returns the fees collected in the given period
+    /// # Arguments
+    /// * `start_at` - The start of the period, inclusive
+    /// * `end_at` - The end of the period, exclusive
+    /// # Returns
+    /// * `Vec<FeeLedgerEntry>` - The fees collected in the period, ordered by collection time
+    pub fn get_entries_by_period(&self, start_at: u64, end_at: u64) -> Vec<FeeLedgerEntry> {
+        self.storage.with_borrow(|store| {
+            store
+                .range(period_key_range(start_at, end_at))
+                .map(|(_, entry)| entry)
+                .collect()
+        })
+    }
+
+    /// Returns a page of the fees collected in the given period
+    /// # Arguments
+    /// * `start_at` - The start of the period, inclusive
+    /// * `end_at` - The end of the period, exclusive
+    /// * `paginate` - The pagination parameters
+    /// # Returns
+    /// * `PaginateResult<FeeLedgerEntry>` - The page of fees, ordered by collection time
+    pub fn get_entries_page_by_period(
+        &self,
+        start_at: u64,
+        end_at: u64,
+        paginate: &PaginateInput,
+    ) -> PaginateResult<FeeLedgerEntry> {
+        self.storage.with_borrow(|store| {
+            let total = store.range(period_key_range(start_at, end_at)).count();
+            let offset = paginate.offset;
+            let limit = paginate.limit;
+            let data = store
+                .range(period_key_range(start_at, end_at))
+                .skip(offset)
+                .take(limit)
+                .map(|(_, entry)| entry)
+                .collect();
+
+            PaginateResult {
+                data,
+                metadata: PaginateResultMetadata {
+                    total,
+                    offset,
+                    limit,
+                    is_next: offset.saturating_add(limit) < total,
+                    is_prev: offset > 0,
+                },
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{Repositories, tests::TestRepositories};
+    use candid::Nat;
+    use cashier_backend_types::repository::link::v1::LinkType;
+    use cashier_common::test_utils::{random_id_string, random_principal_id};
+
+    fn fee_entry(collected_at: u64) -> FeeLedgerEntry {
+        FeeLedgerEntry {
+            intent_id: random_id_string(),
+            action_id: random_id_string(),
+            link_id: random_id_string(),
+            link_type: LinkType::SendTip,
+            payer: random_principal_id(),
+            token_id: random_principal_id(),
+            amount: Nat::from(10_000u64),
+            collected_at,
+        }
+    }
+
+    #[test]
+    fn it_should_create_a_fee_entry() {
+        // Arrange
+        let mut repo = TestRepositories::new().fee_ledger();
+        let entry = fee_entry(100);
+
+        // Act
+        repo.create(entry.clone());
+
+        // Assert
+        let retrieved_entries = repo.get_entries_by_period(100, 101);
+        assert_eq!(retrieved_entries, vec![entry]);
+    }
+
+    #[test]
+    fn it_should_get_entries_by_period() {
+        // Arrange
+        let mut repo = TestRepositories::new().fee_ledger();
+        let before = fee_entry(99);
+        let late = fee_entry(150);
+        let early = fee_entry(100);
+        let after = fee_entry(200);
+        repo.create(before);
+        repo.create(late.clone());
+        repo.create(early.clone());
+        repo.create(after);
+
+        // Act
+        let entries = repo.get_entries_by_period(100, 200);
+
+        // Assert
+        assert_eq!(entries, vec![early, late]);
+    }
+
+    #[test]
+    fn it_should_get_a_page_of_entries_by_period() {
+        // Arrange
+        let mut repo = TestRepositories::new().fee_ledger();
+        let entries: Vec<FeeLedgerEntry> =
+            [300, 100, 200, 1_000].into_iter().map(fee_entry).collect();
+        for entry in entries.iter() {
+            repo.create(entry.clone());
+        }
+
+        // Act
+        let page = repo.get_entries_page_by_period(
+            100,
+            1_000,
+            &PaginateInput {
+                offset: 1,
+                limit: 1,
+            },
+        );
+
+        // Assert
+        assert_eq!(page.data, vec![entries[2].clone()]);
+        assert_eq!(page.metadata.total, 3);
+        assert!(page.metadata.is_next);
+        assert!(page.metadata.is_prev);
+    }
+}
@@ -76,6 +76,7 @@ mod tests {
                 amount: Nat::from_str("0").unwrap(),
             }),
             label: "Test Intent".to_string(),
+            link_id: None,
         };
         let intent2 = Intent {
             id: intent_id2.clone(),
@@ -93,6 +94,7 @@ mod tests {
                 amount: Nat::from_str("100").unwrap(),
             }),
             label: "Another Test Intent".to_string(),
+            link_id: None,
         };
 
         // Act
@@ -130,6 +132,7 @@ mod tests {
                 amount: Nat::from_str("0").unwrap(),
             }),
             label: "Test Intent".to_string(),
+            link_id: None,
         };
 
         let intent2 = Intent {
@@ -148,6 +151,7 @@ mod tests {
                 amount: Nat::from_str("100").unwrap(),
             }),
             label: "Updated Test Intent".to_string(),
+            link_id: None,
         };
         repo.batch_create(vec![intent1, intent2]);
 
@@ -167,6 +171,7 @@ mod tests {
                 amount: Nat::from_str("100").unwrap(),
             }),
             label: "Updated Intent".to_string(),
+            link_id: None,
         };
         let update_intent2 = Intent {
             id: intent_id2.clone(),
@@ -184,6 +189,7 @@ mod tests {
                 amount: Nat::from_str("200").unwrap(),
             }),
             label: "Updated Another Test Intent".to_string(),
+            link_id: None,
         };
 
         // Act
@@ -220,6 +226,7 @@ mod tests {
                 amount: Nat::from_str("0").unwrap(),
             }),
             label: "Test Intent".to_string(),
+            link_id: None,
         };
         repo.batch_create(vec![intent]);
 
@@ -6,8 +6,9 @@ use cashier_backend_types::repository::{
     link::v1::{Link, LinkCodec},
 };
 use ic_mple_log::service::Storage;
-use ic_mple_structures::{BTreeMapStructure, VersionedBTreeMap};
+use ic_mple_structures::{BTreeMapIteratorStructure, BTreeMapStructure, VersionedBTreeMap};
 use ic_stable_structures::{DefaultMemoryImpl, memory_manager::VirtualMemory};
+use std::ops::Bound;
 
 pub type LinkRepositoryStorage =
     VersionedBTreeMap<String, Link, LinkCodec, VirtualMemory<DefaultMemoryImpl>>;
@@ -43,6 +44,24 @@ impl<S: Storage<LinkRepositoryStorage>> LinkRepository<S> {
             store.insert(id, link);
         });
     }
+
+    /// Returns up to `limit` links stored after the given link ID, in ID order,
+    /// so that every link can be walked in chunks.
+    /// # Arguments
+    /// * `after` - The ID of the last link of the previous chunk, None to start from the first link
+    /// * `limit` - The maximum number of links to return
+    /// # Returns
+    /// * `Vec<Link>` - The links of the chunk
+    pub fn get_links_after(&self, after: Option<&str>, limit: usize) -> Vec<Link> {
+        let start = after.map_or(Bound::Unbounded, |id| Bound::Excluded(id.to_string()));
+        self.storage.with_borrow(|store| {
+            store
+                .range((start, Bound::Unbounded))
+                .take(limit)
+                .map(|(_, link)| link)
+                .collect()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -68,6 +87,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 0,
             link_use_action_max_count: 10,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
 
         // Act
@@ -97,6 +127,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 0,
             link_use_action_max_count: 10,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
         repo.create(link);
 
@@ -110,6 +151,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 1,
             link_use_action_max_count: 20,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
 
         // Act
@@ -141,6 +193,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 0,
             link_use_action_max_count: 10,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
         let link2 = Link {
             id: link_id2.clone(),
@@ -152,6 +215,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 1,
             link_use_action_max_count: 20,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
         repo.create(link1);
         repo.create(link2);
@@ -183,6 +257,17 @@ mod tests {
             create_at: 1622547800,
             link_use_action_counter: 0,
             link_use_action_max_count: 10,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
         };
         repo.create(link);
 
@@ -193,4 +278,52 @@ mod tests {
         assert!(fetched_link.is_some());
         assert_eq!(fetched_link.unwrap().id, link_id);
     }
+
+    #[test]
+    fn it_should_get_links_after_a_link_in_chunks() {
+        // Arrange
+        let mut repo = TestRepositories::new().link();
+        let creator = random_principal_id();
+        let mut link_ids: Vec<String> = (0..5).map(|_| random_id_string()).collect();
+        for link_id in link_ids.iter() {
+            repo.create(Link {
+                id: link_id.clone(),
+                state: LinkState::Scheduled,
+                title: "Scheduled Link".to_string(),
+                link_type: LinkType::SendAirdrop,
+                asset_info: vec![],
+                creator,
+                create_at: 1622547800,
+                link_use_action_counter: 0,
+                link_use_action_max_count: 10,
+                expires_at: Some(1622548000),
+                gate_id: None,
+                starts_at: Some(1622547900),
+                max_use_per_user: None,
+                allow_list: None,
+                random_pot: None,
+                split_recipients: None,
+                amount_bounds: None,
+                description: None,
+                image_url: None,
+                batch_link_ids: None,
+            });
+        }
+        link_ids.sort();
+
+        // Act
+        let first_chunk = repo.get_links_after(None, 2);
+        let second_chunk = repo.get_links_after(first_chunk.last().map(|link| link.id.as_str()), 2);
+        let last_chunk = repo.get_links_after(second_chunk.last().map(|link| link.id.as_str()), 2);
+
+        // Assert
+        let walked_ids: Vec<String> = first_chunk
+            .iter()
+            .chain(second_chunk.iter())
+            .chain(last_chunk.iter())
+            .map(|link| link.id.clone())
+            .collect();
+        assert_eq!(last_chunk.len(), 1);
+        assert_eq!(walked_ids, link_ids);
+    }
 }
@@ -7,7 +7,7 @@ use cashier_backend_types::repository::{
     link_action::v1::{LinkAction, LinkActionCodec},
 };
 use ic_mple_log::service::Storage;
-use ic_mple_structures::{BTreeMapStructure, VersionedBTreeMap};
+use ic_mple_structures::{BTreeMapIteratorStructure, BTreeMapStructure, VersionedBTreeMap};
 use ic_stable_structures::{DefaultMemoryImpl, memory_manager::VirtualMemory};
 
 pub type LinkActionRepositoryStorage =
@@ -51,4 +51,70 @@ impl<S: Storage<LinkActionRepositoryStorage>> LinkActionRepository<S> {
             store.insert(id.to_str(), link_action);
         });
     }
+
+    /// Returns the actions of the given types made on a link, in key order
+    /// # Arguments
+    /// * `link_id` - The ID of the link
+    /// * `action_types` - The types of the actions to return
+    /// # Returns
+    /// * `Vec<LinkAction>` - The link actions, grouped by user
+    pub fn get_link_actions_by_types(
+        &self,
+        link_id: &str,
+        action_types: &[ActionType],
+    ) -> Vec<LinkAction> {
+        self.storage.with_borrow(|store| {
+            let prefix = format!("LINK#{link_id}#");
+            store
+                .range(prefix.clone()..)
+                .take_while(|(key, _value)| key.starts_with(&prefix))
+                .map(|(_key, value)| value)
+                .filter(|link_action| action_types.contains(&link_action.action_type))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repositories::{Repositories, tests::TestRepositories};
+    use cashier_common::test_utils::{random_id_string, random_principal_id};
+
+    fn link_action(link_id: &str, action_type: ActionType) -> LinkAction {
+        LinkAction {
+            link_id: link_id.to_string(),
+            action_id: random_id_string(),
+            action_type,
+            user_id: random_principal_id(),
+            link_user_state: None,
+        }
+    }
+
+    #[test]
+    fn it_should_get_link_actions_by_types() {
+        // Arrange
+        let mut repo = TestRepositories::new().link_action();
+        let link_id = random_id_string();
+        let receive = link_action(&link_id, ActionType::Receive);
+        let withdraw = link_action(&link_id, ActionType::Withdraw);
+        repo.create(link_action(&link_id, ActionType::CreateLink));
+        repo.create(receive.clone());
+        repo.create(withdraw.clone());
+        repo.create(link_action(&random_id_string(), ActionType::Receive));
+
+        // Act
+        let result =
+            repo.get_link_actions_by_types(&link_id, &[ActionType::Receive, ActionType::Withdraw]);
+
+        // Assert
+        let mut action_ids: Vec<String> = result
+            .iter()
+            .map(|link_action| link_action.action_id.clone())
+            .collect();
+        action_ids.sort();
+        let mut expected_ids = vec![receive.action_id, withdraw.action_id];
+        expected_ids.sort();
+        assert_eq!(action_ids, expected_ids);
+    }
 }
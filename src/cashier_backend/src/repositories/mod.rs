@@ -6,6 +6,7 @@ use std::thread::LocalKey;
 
 use cashier_backend_types::repository::action::v1::ActionCodec;
 use cashier_backend_types::repository::action_intent::v1::ActionIntentCodec;
+use cashier_backend_types::repository::fee_ledger::v1::FeeLedgerCodec;
 use cashier_backend_types::repository::intent::v1::IntentCodec;
 use cashier_backend_types::repository::intent_transaction::v1::IntentTransactionCodec;
 use cashier_backend_types::repository::link::v1::LinkCodec;
@@ -22,8 +23,8 @@ use ic_stable_structures::memory_manager::{MemoryId, MemoryManager, VirtualMemor
 use ic_stable_structures::{DefaultMemoryImpl, StableBTreeMap, StableCell};
 
 use cashier_backend_types::repository::{
-    action::v1::Action, action_intent::v1::ActionIntent, intent::v1::Intent,
-    intent_transaction::v1::IntentTransaction, keys::*, link::v1::Link,
+    action::v1::Action, action_intent::v1::ActionIntent, fee_ledger::v1::FeeLedgerEntry,
+    intent::v1::Intent, intent_transaction::v1::IntentTransaction, keys::*, link::v1::Link,
     link_action::v1::LinkAction, request_lock::RequestLock, transaction::v1::Transaction,
     user_action::v1::UserAction, user_link::v1::UserLink,
 };
@@ -31,12 +32,16 @@ use cashier_backend_types::repository::{
 use crate::repositories::action::{ActionRepository, ActionRepositoryStorage};
 use crate::repositories::action_intent::{ActionIntentRepository, ActionIntentRepositoryStorage};
 use crate::repositories::auth::AuthServiceStorage;
+use crate::repositories::fee_ledger::{FeeLedgerRepository, FeeLedgerRepositoryStorage};
 use crate::repositories::intent::{IntentRepository, IntentRepositoryStorage};
 use crate::repositories::intent_transaction::{
     IntentTransactionRepository, IntentTransactionRepositoryStorage,
 };
 use crate::repositories::link::{LinkRepository, LinkRepositoryStorage};
 use crate::repositories::link_action::{LinkActionRepository, LinkActionRepositoryStorage};
+use crate::repositories::processing_action::{
+    ProcessingActionRepository, ProcessingActionRepositoryStorage,
+};
 use crate::repositories::request_lock::{RequestLockRepository, RequestLockRepositoryStorage};
 use crate::repositories::settings::{
     Settings, SettingsCodec, SettingsRepository, SettingsRepositoryStorage,
@@ -52,10 +57,12 @@ use crate::repositories::user_link_action::{
 pub mod action;
 pub mod action_intent;
 pub mod auth;
+pub mod fee_ledger;
 pub mod intent;
 pub mod intent_transaction;
 pub mod link;
 pub mod link_action;
+pub mod processing_action;
 pub mod request_lock;
 pub mod settings;
 pub mod token_fee;
@@ -78,6 +85,8 @@ const LOG_SETTINGS_MEMORY_ID: MemoryId = MemoryId::new(11);
 const AUTH_SERVICE_MEMORY_ID: MemoryId = MemoryId::new(12);
 const SETTINGS_MEMORY_ID: MemoryId = MemoryId::new(13);
 const USER_LINK_ACTION_MEMORY_ID: MemoryId = MemoryId::new(14);
+const FEE_LEDGER_MEMORY_ID: MemoryId = MemoryId::new(15);
+const PROCESSING_ACTION_MEMORY_ID: MemoryId = MemoryId::new(16);
 
 pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 
@@ -85,10 +94,12 @@ pub type Memory = VirtualMemory<DefaultMemoryImpl>;
 pub trait Repositories {
     type ActionIntent: Storage<ActionIntentRepositoryStorage>;
     type Action: Storage<ActionRepositoryStorage>;
+    type FeeLedger: Storage<FeeLedgerRepositoryStorage>;
     type Intent: Storage<IntentRepositoryStorage>;
     type IntentTransaction: Storage<IntentTransactionRepositoryStorage>;
     type Link: Storage<LinkRepositoryStorage>;
     type LinkAction: Storage<LinkActionRepositoryStorage>;
+    type ProcessingAction: Storage<ProcessingActionRepositoryStorage>;
     type RequestLock: Storage<RequestLockRepositoryStorage>;
     type Settings: Storage<SettingsRepositoryStorage>;
     type TokenFee: Storage<TokenFeeRepositoryStorage>;
@@ -99,10 +110,12 @@ pub trait Repositories {
 
     fn action_intent(&self) -> ActionIntentRepository<Self::ActionIntent>;
     fn action(&self) -> ActionRepository<Self::Action>;
+    fn fee_ledger(&self) -> FeeLedgerRepository<Self::FeeLedger>;
     fn intent(&self) -> IntentRepository<Self::Intent>;
     fn intent_transaction(&self) -> IntentTransactionRepository<Self::IntentTransaction>;
     fn link(&self) -> LinkRepository<Self::Link>;
     fn link_action(&self) -> LinkActionRepository<Self::LinkAction>;
+    fn processing_action(&self) -> ProcessingActionRepository<Self::ProcessingAction>;
     fn request_lock(&self) -> RequestLockRepository<Self::RequestLock>;
     fn settings(&self) -> SettingsRepository<Self::Settings>;
     fn token_fee(&self) -> TokenFeeRepository<Self::TokenFee>;
@@ -118,10 +131,12 @@ pub struct ThreadlocalRepositories;
 impl Repositories for ThreadlocalRepositories {
     type ActionIntent = &'static LocalKey<RefCell<ActionIntentRepositoryStorage>>;
     type Action = &'static LocalKey<RefCell<ActionRepositoryStorage>>;
+    type FeeLedger = &'static LocalKey<RefCell<FeeLedgerRepositoryStorage>>;
     type Intent = &'static LocalKey<RefCell<IntentRepositoryStorage>>;
     type IntentTransaction = &'static LocalKey<RefCell<IntentTransactionRepositoryStorage>>;
     type Link = &'static LocalKey<RefCell<LinkRepositoryStorage>>;
     type LinkAction = &'static LocalKey<RefCell<LinkActionRepositoryStorage>>;
+    type ProcessingAction = &'static LocalKey<RefCell<ProcessingActionRepositoryStorage>>;
     type RequestLock = &'static LocalKey<RefCell<RequestLockRepositoryStorage>>;
     type Settings = &'static LocalKey<RefCell<SettingsRepositoryStorage>>;
     type TokenFee = &'static LocalKey<RefCell<TokenFeeRepositoryStorage>>;
@@ -138,6 +153,10 @@ impl Repositories for ThreadlocalRepositories {
         ActionRepository::new(&ACTION_STORE)
     }
 
+    fn fee_ledger(&self) -> FeeLedgerRepository<Self::FeeLedger> {
+        FeeLedgerRepository::new(&FEE_LEDGER_STORE)
+    }
+
     fn intent(&self) -> IntentRepository<Self::Intent> {
         IntentRepository::new(&INTENT_STORE)
     }
@@ -154,6 +173,10 @@ impl Repositories for ThreadlocalRepositories {
         LinkActionRepository::new(&LINK_ACTION_STORE)
     }
 
+    fn processing_action(&self) -> ProcessingActionRepository<Self::ProcessingAction> {
+        ProcessingActionRepository::new(&PROCESSING_ACTION_STORE)
+    }
+
     fn request_lock(&self) -> RequestLockRepository<Self::RequestLock> {
         RequestLockRepository::new(&REQUEST_LOCK_STORE)
     }
@@ -270,6 +293,12 @@ thread_local! {
         )
     );
 
+    static PROCESSING_ACTION_STORE: RefCell<ProcessingActionRepositoryStorage> = RefCell::new(
+        StableBTreeMap::init(
+            MEMORY_MANAGER.with_borrow(|m| m.get(PROCESSING_ACTION_MEMORY_ID)),
+        )
+    );
+
     static ACTION_INTENT_STORE: RefCell<VersionedBTreeMap<
         String,
         ActionIntent,
@@ -281,6 +310,17 @@ thread_local! {
         )
     );
 
+    static FEE_LEDGER_STORE: RefCell<VersionedBTreeMap<
+        String,
+        FeeLedgerEntry,
+        FeeLedgerCodec,
+        Memory
+    >> = RefCell::new(
+        VersionedBTreeMap::init(
+            MEMORY_MANAGER.with_borrow(|m| m.get(FEE_LEDGER_MEMORY_ID)),
+        )
+    );
+
     static INTENT_STORE: RefCell<VersionedBTreeMap<
         String,
         Intent,
@@ -350,10 +390,12 @@ pub mod tests {
     pub struct TestRepositories {
         action_intent: Rc<RefCell<ActionIntentRepositoryStorage>>,
         action: Rc<RefCell<ActionRepositoryStorage>>,
+        fee_ledger: Rc<RefCell<FeeLedgerRepositoryStorage>>,
         intent: Rc<RefCell<IntentRepositoryStorage>>,
         intent_transaction: Rc<RefCell<IntentTransactionRepositoryStorage>>,
         link: Rc<RefCell<LinkRepositoryStorage>>,
         link_action: Rc<RefCell<LinkActionRepositoryStorage>>,
+        processing_action: Rc<RefCell<ProcessingActionRepositoryStorage>>,
         request_lock: Rc<RefCell<RequestLockRepositoryStorage>>,
         settings: Rc<RefCell<SettingsRepositoryStorage>>,
         token_fee: Rc<RefCell<TokenFeeRepositoryStorage>>,
@@ -377,6 +419,9 @@ pub mod tests {
                 action: Rc::new(RefCell::new(VersionedBTreeMap::init(
                     mm.get(ACTION_MEMORY_ID),
                 ))),
+                fee_ledger: Rc::new(RefCell::new(VersionedBTreeMap::init(
+                    mm.get(FEE_LEDGER_MEMORY_ID),
+                ))),
                 intent: Rc::new(RefCell::new(VersionedBTreeMap::init(
                     mm.get(INTENT_MEMORY_ID),
                 ))),
@@ -389,6 +434,9 @@ pub mod tests {
                 link_action: Rc::new(RefCell::new(VersionedBTreeMap::init(
                     mm.get(LINK_ACTION_MEMORY_ID),
                 ))),
+                processing_action: Rc::new(RefCell::new(StableBTreeMap::init(
+                    mm.get(PROCESSING_ACTION_MEMORY_ID),
+                ))),
                 request_lock: Rc::new(RefCell::new(VersionedBTreeMap::init(
                     mm.get(REQUEST_LOCK_MEMORY_ID),
                 ))),
@@ -416,10 +464,12 @@ pub mod tests {
     impl Repositories for TestRepositories {
         type ActionIntent = Rc<RefCell<ActionIntentRepositoryStorage>>;
         type Action = Rc<RefCell<ActionRepositoryStorage>>;
+        type FeeLedger = Rc<RefCell<FeeLedgerRepositoryStorage>>;
         type Intent = Rc<RefCell<IntentRepositoryStorage>>;
         type IntentTransaction = Rc<RefCell<IntentTransactionRepositoryStorage>>;
         type Link = Rc<RefCell<LinkRepositoryStorage>>;
         type LinkAction = Rc<RefCell<LinkActionRepositoryStorage>>;
+        type ProcessingAction = Rc<RefCell<ProcessingActionRepositoryStorage>>;
         type RequestLock = Rc<RefCell<RequestLockRepositoryStorage>>;
         type Settings = Rc<RefCell<SettingsRepositoryStorage>>;
         type TokenFee = Rc<RefCell<TokenFeeRepositoryStorage>>;
@@ -436,6 +486,10 @@ pub mod tests {
             ActionRepository::new(self.action.clone())
         }
 
+        fn fee_ledger(&self) -> FeeLedgerRepository<Self::FeeLedger> {
+            FeeLedgerRepository::new(self.fee_ledger.clone())
+        }
+
         fn intent(&self) -> IntentRepository<Self::Intent> {
             IntentRepository::new(self.intent.clone())
         }
@@ -452,6 +506,10 @@ pub mod tests {
             LinkActionRepository::new(self.link_action.clone())
         }
 
+        fn processing_action(&self) -> ProcessingActionRepository<Self::ProcessingAction> {
+            ProcessingActionRepository::new(self.processing_action.clone())
+        }
+
         fn request_lock(&self) -> RequestLockRepository<Self::RequestLock> {
             RequestLockRepository::new(self.request_lock.clone())
         }
@@ -0,0 +1,97 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use cashier_backend_types::repository::keys::ActionKey;
+use ic_mple_log::service::Storage;
+use ic_stable_structures::{DefaultMemoryImpl, StableBTreeMap, memory_manager::VirtualMemory};
+
+/// Index of the actions in processing, mapped to the time their processing started
+pub type ProcessingActionRepositoryStorage =
+    StableBTreeMap<ActionKey, u64, VirtualMemory<DefaultMemoryImpl>>;
+
+#[derive(Clone)]
+pub struct ProcessingActionRepository<S: Storage<ProcessingActionRepositoryStorage>> {
+    storage: S,
+}
+
+impl<S: Storage<ProcessingActionRepositoryStorage>> ProcessingActionRepository<S> {
+    pub fn new(storage: S) -> Self {
+        Self { storage }
+    }
+
+    /// Records that the processing of an action started
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// * `started_at` - The time the processing started
+    pub fn insert(&mut self, action_id: &str, started_at: u64) {
+        self.storage.with_borrow_mut(|store| {
+            store.insert(action_id.to_string(), started_at);
+        });
+    }
+
+    /// Returns the time the processing of an action started
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `Option<u64>` - The start time, if the action is processing
+    pub fn get(&self, action_id: &str) -> Option<u64> {
+        self.storage
+            .with_borrow(|store| store.get(&action_id.to_string()))
+    }
+
+    /// Removes an action from the index once it is no longer processing
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    pub fn remove(&mut self, action_id: &str) {
+        self.storage.with_borrow_mut(|store| {
+            store.remove(&action_id.to_string());
+        });
+    }
+
+    /// Returns the actions whose processing started at or before the given time
+    /// # Arguments
+    /// * `started_until` - The time, inclusive
+    /// # Returns
+    /// * `Vec<ActionKey>` - The IDs of the actions
+    pub fn get_started_until(&self, started_until: u64) -> Vec<ActionKey> {
+        self.storage.with_borrow(|store| {
+            store
+                .iter()
+                .filter_map(|entry| (entry.value() <= started_until).then(|| entry.key().clone()))
+                .collect()
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::repositories::{Repositories, tests::TestRepositories};
+
+    #[test]
+    fn it_should_get_actions_started_until() {
+        // Arrange
+        let mut repo = TestRepositories::new().processing_action();
+        repo.insert("action1", 1_000);
+        repo.insert("action2", 2_000);
+
+        // Act
+        let action_ids = repo.get_started_until(1_500);
+
+        // Assert
+        assert_eq!(action_ids, vec!["action1".to_string()]);
+    }
+
+    #[test]
+    fn it_should_remove_an_action() {
+        // Arrange
+        let mut repo = TestRepositories::new().processing_action();
+        repo.insert("action1", 1_000);
+
+        // Act
+        repo.remove("action1");
+
+        // Assert
+        assert!(repo.get_started_until(u64::MAX).is_empty());
+        assert_eq!(repo.get("action1"), None);
+    }
+}
@@ -1,6 +1,7 @@
 use std::borrow::Cow;
 
-use candid::CandidType;
+use candid::{CandidType, Principal};
+use cashier_backend_types::init::{CkBtcMinterSettings, LinkFeeSettings};
 use cashier_macros::storable;
 use ic_mple_log::service::Storage;
 use ic_mple_structures::{CellStructure, RefCodec, VersionedStableCell};
@@ -12,6 +13,14 @@ use ic_stable_structures::{DefaultMemoryImpl, memory_manager::VirtualMemory};
 pub struct Settings {
     /// Whether the inspect message is enabled
     pub inspect_message_enabled: bool,
+    /// The gate_service canister used to enforce link gates
+    pub gate_service_canister_id: Option<Principal>,
+    /// The time in nanoseconds after which an action stuck in processing is recovered
+    pub action_processing_timeout_ns: Option<u64>,
+    /// The ckBTC minter used to pay out claims to Bitcoin addresses
+    pub ckbtc_minter: Option<CkBtcMinterSettings>,
+    /// The link creation fee schedule
+    pub link_fee: Option<LinkFeeSettings>,
 }
 
 #[storable]
@@ -35,6 +44,10 @@ impl Default for Settings {
     fn default() -> Self {
         Self {
             inspect_message_enabled: true,
+            gate_service_canister_id: None,
+            action_processing_timeout_ns: None,
+            ckbtc_minter: None,
+            link_fee: None,
         }
     }
 }
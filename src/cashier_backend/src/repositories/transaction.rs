@@ -77,6 +77,7 @@ mod tests {
                 ts: Some(1622547800),
             })),
             start_ts: None,
+            block_index: None,
         };
         repo.batch_create(vec![transaction.clone()]);
 
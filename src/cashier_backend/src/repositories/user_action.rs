@@ -1,12 +1,13 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use candid::Principal;
 use cashier_backend_types::repository::{
-    keys::UserActionKey,
+    keys::{ActionKey, UserActionKey},
     user_action::v1::{UserAction, UserActionCodec},
 };
 use ic_mple_log::service::Storage;
-use ic_mple_structures::{BTreeMapStructure, VersionedBTreeMap};
+use ic_mple_structures::{BTreeMapIteratorStructure, BTreeMapStructure, VersionedBTreeMap};
 use ic_stable_structures::{DefaultMemoryImpl, memory_manager::VirtualMemory};
 
 pub type UserActionRepositoryStorage =
@@ -31,6 +32,28 @@ impl<S: Storage<UserActionRepositoryStorage>> UserActionRepository<S> {
             store.insert(id.to_str(), user_intent);
         });
     }
+
+    /// Returns the IDs of the actions created by a user
+    /// # Arguments
+    /// * `user_id` - The principal of the user
+    /// # Returns
+    /// * `Vec<ActionKey>` - The IDs of the actions
+    pub fn get_action_ids_by_user(&self, user_id: Principal) -> Vec<ActionKey> {
+        self.storage.with_borrow(|store| {
+            let key = UserActionKey {
+                user_id,
+                action_id: String::new(),
+            };
+
+            let prefix = key.to_str();
+
+            store
+                .range(prefix.clone()..)
+                .take_while(|entry| entry.0.starts_with(&prefix))
+                .map(|entry| entry.1.action_id)
+                .collect()
+        })
+    }
 }
 
 #[cfg(test)]
@@ -64,4 +87,28 @@ mod tests {
         assert!(retrieved_action.is_some());
         assert_eq!(retrieved_action.unwrap().user_id, user_id);
     }
+
+    #[test]
+    fn it_should_get_action_ids_by_user() {
+        // Arrange
+        let mut repo = TestRepositories::new().user_action();
+        let user_id1 = random_principal_id();
+        let user_id2 = random_principal_id();
+        let action_id1 = random_id_string();
+        let action_id2 = random_id_string();
+        repo.create(UserAction {
+            user_id: user_id1,
+            action_id: action_id1.clone(),
+        });
+        repo.create(UserAction {
+            user_id: user_id2,
+            action_id: action_id2,
+        });
+
+        // Act
+        let action_ids = repo.get_action_ids_by_user(user_id1);
+
+        // Assert
+        assert_eq!(action_ids, vec![action_id1]);
+    }
 }
@@ -81,6 +81,32 @@ impl<S: Storage<UserLinkActionRepositoryStorage>> UserLinkActionRepository<S> {
         });
     }
 
+    /// Delete a user link action
+    /// # Arguments
+    /// * `link_action` - The LinkAction to be deleted, matched by its action_id
+    /// # Returns
+    /// * `()` - if successful
+    pub fn delete(&mut self, link_action: &LinkAction) {
+        self.storage.with_borrow_mut(|store| {
+            let id = UserLinkActionKey {
+                user_id: link_action.user_id,
+                link_id: link_action.link_id.clone(),
+                action_type: link_action.action_type.clone(),
+            };
+            let id_str = id.to_str();
+
+            let Some(mut actions) = store.get(&id_str) else {
+                return;
+            };
+            actions.retain(|a| a.action_id != link_action.action_id);
+            if actions.is_empty() {
+                store.remove(&id_str);
+            } else {
+                store.insert(id_str, actions);
+            }
+        });
+    }
+
     /// Get all link actions for a given user, link, and action type
     /// # Arguments
     /// * `user_id` - The Principal ID of the user
@@ -251,4 +277,43 @@ mod tests {
             Some(&LinkUserState::Completed)
         );
     }
+
+    #[test]
+    fn it_should_delete_user_link_action() {
+        // Arrange
+        let mut repo = TestRepositories::new().user_link_action();
+        let link_id = random_id_string();
+        let user1 = random_principal_id();
+
+        let link_action1 = LinkAction {
+            link_id: link_id.clone(),
+            action_type: ActionType::Receive,
+            action_id: random_id_string(),
+            user_id: user1,
+            link_user_state: None,
+        };
+        let link_action2 = LinkAction {
+            action_id: random_id_string(),
+            ..link_action1.clone()
+        };
+        repo.create(link_action1.clone());
+        repo.create(link_action2.clone());
+
+        // Act
+        repo.delete(&link_action1);
+
+        // Assert
+        let actions = repo
+            .get_actions_by_user_link_and_type(user1, &link_id, &ActionType::Receive)
+            .unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0].action_id, link_action2.action_id);
+
+        // Act
+        repo.delete(&link_action2);
+
+        // Assert
+        let actions = repo.get_actions_by_user_link_and_type(user1, &link_id, &ActionType::Receive);
+        assert!(actions.is_none());
+    }
 }
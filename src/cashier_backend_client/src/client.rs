@@ -3,10 +3,16 @@ use cashier_backend_types::{
     auth::Permission,
     dto::{
         action::{ActionDto, CreateActionInput, ProcessActionInput, UpdateActionInput},
+        fee_ledger::{FeeLedgerEntryDto, FeeLedgerInput, FeeRevenueInput, PeriodRevenueDto},
         link::{CreateLinkInput, GetLinkOptions, GetLinkResp, LinkDto, UpdateLinkInput},
     },
     error::CanisterError,
-    link_v2::dto::{CreateLinkDto, ProcessActionDto, ProcessActionV2Input},
+    init::{CkBtcMinterSettings, LinkFeeSettings},
+    link_v2::dto::{
+        ActionReceiptDto, CreateLinkBatchDto, CreateLinkBatchInput, CreateLinkDto,
+        CreateLinkEstimateDto, LinkActionHistoryDto, ProcessActionDto, ProcessActionV2Input,
+        SimulateActionDto, UpdateLinkV2Input,
+    },
     service::link::{PaginateInput, PaginateResult},
 };
 use cashier_common::{build_data::BuildData, icrc::Icrc114ValidateArgs};
@@ -88,6 +94,65 @@ impl<C: CanisterClient> CashierBackendClient<C> {
             .await
     }
 
+    /// Sets the gate_service canister used to enforce link gates.
+    pub async fn admin_gate_service_set(
+        &self,
+        canister_id: Principal,
+    ) -> CanisterClientResult<Result<(), CanisterError>> {
+        self.client
+            .update("admin_gate_service_set", (canister_id,))
+            .await
+    }
+
+    /// Returns the gate_service canister used to enforce link gates.
+    pub async fn gate_service_get(&self) -> CanisterClientResult<Option<Principal>> {
+        self.client.query("gate_service_get", ()).await
+    }
+
+    /// Sets the ckBTC minter used to pay out claims to Bitcoin addresses.
+    pub async fn admin_ckbtc_minter_set(
+        &self,
+        ckbtc_minter: CkBtcMinterSettings,
+    ) -> CanisterClientResult<Result<(), CanisterError>> {
+        self.client
+            .update("admin_ckbtc_minter_set", (ckbtc_minter,))
+            .await
+    }
+
+    /// Returns the ckBTC minter used to pay out claims to Bitcoin addresses.
+    pub async fn ckbtc_minter_get(&self) -> CanisterClientResult<Option<CkBtcMinterSettings>> {
+        self.client.query("ckbtc_minter_get", ()).await
+    }
+
+    /// Sets the link creation fee schedule.
+    pub async fn admin_link_fee_set(
+        &self,
+        link_fee: LinkFeeSettings,
+    ) -> CanisterClientResult<Result<(), CanisterError>> {
+        self.client.update("admin_link_fee_set", (link_fee,)).await
+    }
+
+    /// Returns the link creation fee schedule.
+    pub async fn link_fee_get(&self) -> CanisterClientResult<LinkFeeSettings> {
+        self.client.query("link_fee_get", ()).await
+    }
+
+    /// Returns a page of the fees collected into the treasury in the given range.
+    pub async fn admin_fee_ledger_get(
+        &self,
+        input: FeeLedgerInput,
+    ) -> CanisterClientResult<Result<PaginateResult<FeeLedgerEntryDto>, CanisterError>> {
+        self.client.query("admin_fee_ledger_get", (input,)).await
+    }
+
+    /// Returns the fees collected into the treasury, aggregated per period and per token.
+    pub async fn admin_fee_revenue_get(
+        &self,
+        input: FeeRevenueInput,
+    ) -> CanisterClientResult<Result<Vec<PeriodRevenueDto>, CanisterError>> {
+        self.client.query("admin_fee_revenue_get", (input,)).await
+    }
+
     /// Returns the inspect message status.
     pub async fn is_inspect_message_enabled(&self) -> CanisterClientResult<bool> {
         self.client.query("is_inspect_message_enabled", ()).await
@@ -119,6 +184,34 @@ impl<C: CanisterClient> CashierBackendClient<C> {
         self.client.update("user_create_link_v2", ((input),)).await
     }
 
+    /// Creates a batch of links from the same template, funded by a single batch of ICRC-112 requests.
+    /// # Arguments
+    /// * `input` - The link template and the number of links to create
+    /// # Returns
+    /// * `Ok(CreateLinkBatchDto)` - The created link ids, their actions and the ICRC-112 requests
+    /// * `Err(CanisterError)` - If link creation fails or validation errors occur
+    pub async fn user_create_link_batch_v2(
+        &self,
+        input: CreateLinkBatchInput,
+    ) -> CanisterClientResult<Result<CreateLinkBatchDto, CanisterError>> {
+        self.client
+            .update("user_create_link_batch_v2", (input,))
+            .await
+    }
+
+    /// Estimates the cost of creating a link, per token.
+    /// # Arguments
+    /// * `input` - Link creation data
+    /// # Returns
+    /// * `Ok(CreateLinkEstimateDto)` - The cost breakdown of each token
+    /// * `Err(CanisterError)` - If the input would not create a valid link
+    pub async fn estimate_create_link(
+        &self,
+        input: CreateLinkInput,
+    ) -> CanisterClientResult<Result<CreateLinkEstimateDto, CanisterError>> {
+        self.client.query("estimate_create_link", (input,)).await
+    }
+
     /// Disables a link V2.
     /// # Arguments
     /// * `link_id` - The ID of the link to disable
@@ -132,6 +225,79 @@ impl<C: CanisterClient> CashierBackendClient<C> {
         self.client.update("user_disable_link_v2", (link_id,)).await
     }
 
+    /// Re-enables a disabled link V2.
+    /// # Arguments
+    /// * `link_id` - The ID of the link to enable
+    /// # Returns
+    /// * `Ok(LinkDto)` - The enabled link data
+    /// * `Err(CanisterError)` - If enabling fails or unauthorized
+    pub async fn user_enable_link_v2(
+        &self,
+        link_id: &str,
+    ) -> CanisterClientResult<Result<LinkDto, CanisterError>> {
+        self.client.update("user_enable_link_v2", (link_id,)).await
+    }
+
+    /// Updates the metadata of a link V2.
+    /// # Arguments
+    /// * `input` - The metadata changes to apply
+    /// # Returns
+    /// * `Ok(LinkDto)` - The updated link data
+    /// * `Err(CanisterError)` - If the update is invalid or unauthorized
+    pub async fn user_update_link_v2(
+        &self,
+        input: UpdateLinkV2Input,
+    ) -> CanisterClientResult<Result<LinkDto, CanisterError>> {
+        self.client.update("user_update_link_v2", ((input),)).await
+    }
+
+    /// Retrieves the receipt of an action V2.
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `Ok(ActionReceiptDto)` - The receipt of the action
+    /// * `Err(CanisterError)` - If the action is not found or access is denied
+    pub async fn user_get_action_receipt_v2(
+        &self,
+        action_id: &str,
+    ) -> CanisterClientResult<Result<ActionReceiptDto, CanisterError>> {
+        self.client
+            .query("user_get_action_receipt_v2", (action_id,))
+            .await
+    }
+
+    /// Lists the receive, send and withdraw actions made on a link V2.
+    /// # Arguments
+    /// * `link_id` - The ID of the link
+    /// * `input` - Optional pagination parameters
+    /// # Returns
+    /// * `Ok(PaginateResult<LinkActionHistoryDto>)` - The page of link actions
+    /// * `Err(CanisterError)` - If the link is not found or the caller is not its creator
+    pub async fn user_get_link_actions_v2(
+        &self,
+        link_id: &str,
+        input: Option<PaginateInput>,
+    ) -> CanisterClientResult<Result<PaginateResult<LinkActionHistoryDto>, CanisterError>> {
+        self.client
+            .query("user_get_link_actions_v2", (link_id, input))
+            .await
+    }
+
+    /// Simulates processing an action V2 without making any transfer.
+    /// # Arguments
+    /// * `action_id` - The ID of the action to simulate
+    /// # Returns
+    /// * `Ok(SimulateActionDto)` - The predicted outcome and the first blocking error
+    /// * `Err(CanisterError)` - If the action is not found or not the caller's
+    pub async fn user_simulate_action_v2(
+        &self,
+        action_id: &str,
+    ) -> CanisterClientResult<Result<SimulateActionDto, CanisterError>> {
+        self.client
+            .update("user_simulate_action_v2", (action_id,))
+            .await
+    }
+
     /// Creates a new action V2.
     /// # Arguments
     /// * `input` - Action creation data
@@ -3,7 +3,7 @@
 
 use std::collections::HashMap;
 
-use candid::{CandidType, Principal};
+use candid::{CandidType, Nat, Principal};
 
 use icrc_ledger_types::icrc1::account::Subaccount;
 use serde::{Deserialize, Serialize};
@@ -22,6 +22,14 @@ use crate::{
 pub struct CreateActionInput {
     pub link_id: String,
     pub action_type: ActionType,
+    pub amount: Option<Nat>,
+    /// The number of uses funded by a TOP_UP action
+    pub top_up_use_count: Option<u64>,
+    /// The Bitcoin address paid instead of the caller's wallet, for RECEIVE actions on ckBTC links
+    pub btc_address: Option<String>,
+    /// The wallet paid instead of the caller's principal, for RECEIVE actions:
+    /// an account with a subaccount, or an ICP account identifier for ICP links
+    pub destination: Option<Wallet>,
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
@@ -89,6 +97,7 @@ pub struct TransactionDto {
     pub group: u16,
     pub from_call_type: FromCallType,
     pub protocol: Protocol,
+    pub block_index: Option<Nat>,
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq)]
@@ -97,6 +106,9 @@ pub enum WalletDto {
         address: Principal,
         subaccount: Option<Subaccount>,
     },
+    IcpAccountIdentifier {
+        account_identifier: String,
+    },
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq)]
@@ -194,6 +206,9 @@ impl From<Wallet> for WalletDto {
                 address,
                 subaccount,
             },
+            Wallet::IcpAccountIdentifier { account_identifier } => {
+                WalletDto::IcpAccountIdentifier { account_identifier }
+            }
         }
     }
 }
@@ -228,6 +243,7 @@ impl From<Transaction> for TransactionDto {
             group: transaction.group,
             from_call_type: transaction.from_call_type,
             protocol: transaction.protocol,
+            block_index: transaction.block_index,
         }
     }
 }
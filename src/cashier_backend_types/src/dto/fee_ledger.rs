@@ -0,0 +1,80 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{CandidType, Nat, Principal};
+use serde::{Deserialize, Serialize};
+
+use crate::repository::{fee_ledger::v1::FeeLedgerEntry, link::v1::LinkType};
+use crate::service::link::PaginateInput;
+
+/// The calendar period fees are grouped by, in UTC. Weeks start on Monday.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, CandidType)]
+pub enum RevenuePeriod {
+    Day,
+    Week,
+    Month,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, CandidType)]
+pub struct FeeLedgerInput {
+    /// The start of the range in nanoseconds, inclusive
+    pub start_at: u64,
+    /// The end of the range in nanoseconds, exclusive
+    pub end_at: u64,
+    /// The page of fees to return, the first 10 by default
+    pub paginate: Option<PaginateInput>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, CandidType)]
+pub struct FeeRevenueInput {
+    /// The start of the range in nanoseconds, inclusive
+    pub start_at: u64,
+    /// The end of the range in nanoseconds, exclusive
+    pub end_at: u64,
+    pub period: RevenuePeriod,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct FeeLedgerEntryDto {
+    pub intent_id: String,
+    pub action_id: String,
+    pub link_id: String,
+    pub link_type: LinkType,
+    pub payer: Principal,
+    pub token_id: Principal,
+    pub amount: Nat,
+    pub collected_at: u64,
+}
+
+impl From<FeeLedgerEntry> for FeeLedgerEntryDto {
+    fn from(entry: FeeLedgerEntry) -> Self {
+        Self {
+            intent_id: entry.intent_id,
+            action_id: entry.action_id,
+            link_id: entry.link_id,
+            link_type: entry.link_type,
+            payer: entry.payer,
+            token_id: entry.token_id,
+            amount: entry.amount,
+            collected_at: entry.collected_at,
+        }
+    }
+}
+
+/// The fees collected in one token during a period
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct TokenRevenueDto {
+    pub token_id: Principal,
+    pub amount: Nat,
+    pub fee_count: u64,
+}
+
+/// The fees collected during a period, per token
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct PeriodRevenueDto {
+    /// The start of the period in nanoseconds, inclusive
+    pub period_start: u64,
+    /// The end of the period in nanoseconds, exclusive
+    pub period_end: u64,
+    pub tokens: Vec<TokenRevenueDto>,
+}
@@ -4,13 +4,16 @@
 use candid::{CandidType, Nat, Principal};
 
 use derive_more::Display;
+use gate_service_types::GateKey;
 use serde::{Deserialize, Serialize};
 
 use crate::dto::action::ActionDto;
 use crate::repository::action::v1::ActionType;
 use crate::repository::asset_info::AssetInfo;
 use crate::repository::common::Asset;
-use crate::repository::link::v1::{Link, LinkState, LinkType};
+use crate::repository::link::v1::{
+    AmountBounds, Link, LinkState, LinkType, RandomPot, SplitRecipient,
+};
 use crate::repository::link_action::v1::LinkUserState;
 
 // Structs and Enums
@@ -21,6 +24,13 @@ pub struct CreateLinkInput {
     pub link_use_action_max_count: u64,
     pub asset_info: Vec<LinkDetailUpdateAssetInfoInput>,
     pub link_type: LinkType,
+    pub expires_at: Option<u64>,
+    pub gate: Option<GateKey>,
+    pub starts_at: Option<u64>,
+    pub max_use_per_user: Option<u64>,
+    pub allow_list: Option<Vec<Principal>>,
+    pub split_recipients: Option<Vec<SplitRecipient>>,
+    pub amount_bounds: Option<AmountBounds>,
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
@@ -28,6 +38,7 @@ pub struct LinkDetailUpdateAssetInfoInput {
     pub asset: Asset,
     pub label: String,
     pub amount_per_link_use_action: Nat,
+    pub token_ids: Option<Vec<Nat>>,
 }
 
 impl LinkDetailUpdateAssetInfoInput {
@@ -36,6 +47,8 @@ impl LinkDetailUpdateAssetInfoInput {
             asset: self.asset.clone(),
             label: self.label.clone(),
             amount_per_link_use_action: self.amount_per_link_use_action.clone(),
+            token_ids: self.token_ids.clone(),
+            reserved_token_ids: None,
         }
     }
 
@@ -72,6 +85,18 @@ pub struct LinkDto {
     pub create_at: u64,
     pub link_use_action_counter: u64,
     pub link_use_action_max_count: u64,
+    pub expires_at: Option<u64>,
+    pub gate_id: Option<String>,
+    pub starts_at: Option<u64>,
+    pub max_use_per_user: Option<u64>,
+    pub allow_list: Option<Vec<Principal>>,
+    pub random_pot: Option<RandomPot>,
+    pub split_recipients: Option<Vec<SplitRecipient>>,
+    pub amount_bounds: Option<AmountBounds>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    /// The other links activated by the CREATE_LINK action of this link, for links created in a batch
+    pub batch_link_ids: Option<Vec<String>>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, CandidType)]
@@ -79,6 +104,7 @@ pub struct AssetInfoDto {
     pub asset: Asset,
     pub label: String,
     pub amount_per_link_use_action: Nat,
+    pub token_ids: Option<Vec<Nat>>,
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
@@ -118,6 +144,7 @@ impl From<&AssetInfo> for AssetInfoDto {
             asset: input.asset.clone(),
             label: input.label.clone(),
             amount_per_link_use_action: input.amount_per_link_use_action.clone(),
+            token_ids: input.token_ids.clone(),
         }
     }
 }
@@ -134,6 +161,17 @@ impl From<Link> for LinkDto {
             create_at: link.create_at,
             link_use_action_counter: link.link_use_action_counter,
             link_use_action_max_count: link.link_use_action_max_count,
+            expires_at: link.expires_at,
+            gate_id: link.gate_id,
+            starts_at: link.starts_at,
+            max_use_per_user: link.max_use_per_user,
+            allow_list: link.allow_list,
+            random_pot: link.random_pot,
+            split_recipients: link.split_recipients,
+            amount_bounds: link.amount_bounds,
+            description: link.description,
+            image_url: link.image_url,
+            batch_link_ids: link.batch_link_ids,
         }
     }
 }
@@ -146,6 +184,7 @@ impl From<&LinkDetailUpdateAssetInfoInput> for AssetInfoDto {
             asset: input.asset.clone(),
             label: input.label.clone(),
             amount_per_link_use_action: input.amount_per_link_use_action.clone(),
+            token_ids: input.token_ids.clone(),
         }
     }
 }
@@ -1,2 +1,3 @@
 pub mod action;
+pub mod fee_ledger;
 pub mod link;
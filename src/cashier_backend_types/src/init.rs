@@ -1,6 +1,9 @@
+use crate::repository::link::v1::LinkType;
 use candid::{CandidType, Principal};
+use cashier_common::constant::{CREATE_LINK_FEE, FEE_TREASURY_PRINCIPAL, ICP_CANISTER_PRINCIPAL};
 use ic_mple_log::service::LogServiceSettings;
-use serde::Deserialize;
+use icrc_ledger_types::icrc1::account::Account;
+use serde::{Deserialize, Serialize};
 
 /// These are the arguments which are taken by the cashier_backend canister init fn
 #[derive(Debug, Clone, CandidType, Deserialize)]
@@ -12,6 +15,15 @@ pub struct CashierBackendInitData {
     /// Token fee cache TTL in nanoseconds (default: 168 hours / 7 days)
     #[serde(default)]
     pub token_fee_ttl_ns: Option<u64>,
+    /// Time in nanoseconds after which an action stuck in processing is recovered (default: 10 minutes)
+    #[serde(default)]
+    pub action_processing_timeout_ns: Option<u64>,
+    /// The ckBTC minter paying out claims to Bitcoin addresses (default: BTC withdrawals disabled)
+    #[serde(default)]
+    pub ckbtc_minter: Option<CkBtcMinterSettings>,
+    /// The link creation fee schedule (default: 10_000 e8s of ICP paid to the Cashier treasury)
+    #[serde(default)]
+    pub link_fee: Option<LinkFeeSettings>,
 }
 
 #[derive(Debug, Clone, CandidType, Deserialize)]
@@ -20,4 +32,177 @@ pub struct CashierBackendUpgradeData {
     /// Token fee cache TTL in nanoseconds (default: 168 hours / 7 days)
     #[serde(default)]
     pub token_fee_ttl_ns: Option<u64>,
+    /// Time in nanoseconds after which an action stuck in processing is recovered (default: 10 minutes)
+    #[serde(default)]
+    pub action_processing_timeout_ns: Option<u64>,
+    /// The ckBTC minter paying out claims to Bitcoin addresses (default: BTC withdrawals disabled)
+    #[serde(default)]
+    pub ckbtc_minter: Option<CkBtcMinterSettings>,
+    /// The link creation fee schedule (default: 10_000 e8s of ICP paid to the Cashier treasury)
+    #[serde(default)]
+    pub link_fee: Option<LinkFeeSettings>,
+}
+
+/// The ckBTC minter and the ledger whose tokens it burns for BTC withdrawals
+#[derive(Debug, Clone, Copy, CandidType, Serialize, Deserialize, PartialEq, Eq)]
+pub struct CkBtcMinterSettings {
+    pub minter_id: Principal,
+    pub ledger_id: Principal,
+}
+
+/// A link creation fee charged in a given token
+#[derive(Debug, Clone, Copy, CandidType, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkCreationFee {
+    /// The ledger of the fee token
+    pub token_id: Principal,
+    /// The fee amount, excluding the ledger fees
+    pub amount: u64,
+}
+
+/// The link creation fees accepted for a link type
+#[derive(Debug, Clone, CandidType, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkTypeFees {
+    pub link_type: LinkType,
+    /// The accepted fees, in order of preference
+    pub fees: Vec<LinkCreationFee>,
+}
+
+/// The link creation fee schedule
+#[derive(Debug, Clone, CandidType, Serialize, Deserialize, PartialEq, Eq)]
+pub struct LinkFeeSettings {
+    /// The account receiving the link creation fees
+    pub treasury: Account,
+    /// The accepted fees for link types without specific fees, in order of preference
+    pub default_fees: Vec<LinkCreationFee>,
+    /// The accepted fees of specific link types, replacing `default_fees`
+    pub link_type_fees: Vec<LinkTypeFees>,
+    /// The creators exempted from the link creation fee
+    pub fee_free_creators: Vec<Principal>,
+}
+
+impl Default for LinkFeeSettings {
+    fn default() -> Self {
+        Self {
+            treasury: Account {
+                owner: FEE_TREASURY_PRINCIPAL,
+                subaccount: None,
+            },
+            default_fees: vec![LinkCreationFee {
+                token_id: ICP_CANISTER_PRINCIPAL,
+                amount: CREATE_LINK_FEE,
+            }],
+            link_type_fees: vec![],
+            fee_free_creators: vec![],
+        }
+    }
+}
+
+impl LinkFeeSettings {
+    /// Returns the fee charged to create a link.
+    /// The fee is charged in the first accepted token the link already holds,
+    /// falling back to the first accepted token.
+    /// # Arguments
+    /// * `creator` - The creator of the link
+    /// * `link_type` - The type of the link
+    /// * `link_tokens` - The ledgers of the tokens held by the link
+    /// # Returns
+    /// * `Option<LinkCreationFee>` - The fee to charge, None if the link is created for free
+    pub fn get_link_creation_fee(
+        &self,
+        creator: &Principal,
+        link_type: LinkType,
+        link_tokens: &[Principal],
+    ) -> Option<LinkCreationFee> {
+        if self.fee_free_creators.contains(creator) {
+            return None;
+        }
+
+        let fees = self
+            .link_type_fees
+            .iter()
+            .find(|type_fees| type_fees.link_type == link_type)
+            .map_or(&self.default_fees, |type_fees| &type_fees.fees);
+
+        fees.iter()
+            .find(|fee| link_tokens.contains(&fee.token_id))
+            .or_else(|| fees.first())
+            .filter(|fee| fee.amount > 0)
+            .copied()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_common::test_utils::random_principal_id;
+
+    fn fee(token_id: Principal, amount: u64) -> LinkCreationFee {
+        LinkCreationFee { token_id, amount }
+    }
+
+    #[test]
+    fn it_should_charge_default_fee() {
+        // Arrange
+        let settings = LinkFeeSettings::default();
+
+        // Act
+        let result = settings.get_link_creation_fee(&random_principal_id(), LinkType::SendTip, &[]);
+
+        // Assert
+        assert_eq!(result, Some(fee(ICP_CANISTER_PRINCIPAL, CREATE_LINK_FEE)));
+    }
+
+    #[test]
+    fn it_should_charge_link_type_fee_in_link_token() {
+        // Arrange
+        let ckbtc = random_principal_id();
+        let settings = LinkFeeSettings {
+            link_type_fees: vec![LinkTypeFees {
+                link_type: LinkType::SendAirdrop,
+                fees: vec![fee(ICP_CANISTER_PRINCIPAL, 20_000), fee(ckbtc, 10)],
+            }],
+            ..LinkFeeSettings::default()
+        };
+
+        // Act
+        let airdrop_fee =
+            settings.get_link_creation_fee(&random_principal_id(), LinkType::SendAirdrop, &[ckbtc]);
+        let tip_fee =
+            settings.get_link_creation_fee(&random_principal_id(), LinkType::SendTip, &[ckbtc]);
+
+        // Assert
+        assert_eq!(airdrop_fee, Some(fee(ckbtc, 10)));
+        assert_eq!(tip_fee, Some(fee(ICP_CANISTER_PRINCIPAL, CREATE_LINK_FEE)));
+    }
+
+    #[test]
+    fn it_should_not_charge_fee_free_creator() {
+        // Arrange
+        let creator = random_principal_id();
+        let settings = LinkFeeSettings {
+            fee_free_creators: vec![creator],
+            ..LinkFeeSettings::default()
+        };
+
+        // Act
+        let result = settings.get_link_creation_fee(&creator, LinkType::SendTip, &[]);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_should_not_charge_zero_fee() {
+        // Arrange
+        let settings = LinkFeeSettings {
+            default_fees: vec![fee(ICP_CANISTER_PRINCIPAL, 0)],
+            ..LinkFeeSettings::default()
+        };
+
+        // Act
+        let result = settings.get_link_creation_fee(&random_principal_id(), LinkType::SendTip, &[]);
+
+        // Assert
+        assert_eq!(result, None);
+    }
 }
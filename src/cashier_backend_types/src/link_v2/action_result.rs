@@ -72,3 +72,10 @@ impl From<ProcessActionResult> for ActionDto {
         }
     }
 }
+
+#[derive(Debug, Clone)]
+pub struct SimulateActionResult {
+    pub action: Action,
+    pub is_success: bool,
+    pub errors: Vec<String>,
+}
@@ -1,5 +1,20 @@
-use crate::dto::{action::ActionDto, link::LinkDto};
-use candid::CandidType;
+use crate::{
+    dto::{
+        action::{ActionDto, Icrc112Requests},
+        link::{CreateLinkInput, LinkDto},
+    },
+    link_v2::action_result::SimulateActionResult,
+    repository::{
+        action::v1::{ActionState, ActionType},
+        common::Wallet,
+        link::v1::LinkType,
+        link_action::v1::LinkAction,
+        transaction::v1::{IcTransaction, Protocol, Transaction, TransactionState},
+    },
+    service::action::ActionData,
+};
+use candid::{CandidType, Nat, Principal};
+use icrc_ledger_types::icrc1::account::Account;
 use serde::{Deserialize, Serialize};
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
@@ -8,6 +23,22 @@ pub struct CreateLinkDto {
     pub action: ActionDto,
 }
 
+/// Creates `link_count` links from the same template, funded together
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct CreateLinkBatchInput {
+    pub link: CreateLinkInput,
+    pub link_count: u64,
+}
+
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct CreateLinkBatchDto {
+    pub link_ids: Vec<String>,
+    /// The CREATE_LINK action funding every link, processing it activates them all
+    pub action_id: String,
+    /// The requests funding every link of the batch and paying its creation fee
+    pub icrc_112_requests: Icrc112Requests,
+}
+
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
 pub struct ProcessActionDto {
     pub link: LinkDto,
@@ -20,3 +51,346 @@ pub struct ProcessActionDto {
 pub struct ProcessActionV2Input {
     pub action_id: String,
 }
+
+/// Metadata changes applied by `user_update_link_v2`.
+/// Fields left as `None` are kept unchanged.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct UpdateLinkV2Input {
+    pub link_id: String,
+    pub title: Option<String>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    pub expires_at: Option<u64>,
+}
+
+/// A ledger transfer made for an action, as shown on its receipt.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct ReceiptTransferDto {
+    pub transaction_id: String,
+    pub state: TransactionState,
+    pub ledger: Principal,
+    pub block_index: Option<Nat>,
+    pub amount: Nat,
+    pub from: Account,
+    /// The account paid, `None` when a legacy ICP account identifier was paid
+    pub to: Option<Account>,
+    /// The ICP account identifier paid, for legacy ICP ledger transfers
+    pub to_account_identifier: Option<String>,
+    /// The Bitcoin address paid, for ckBTC withdrawals burned by the minter
+    pub btc_address: Option<String>,
+    /// The NFT sent, for ICRC-7 transfers which always move a single token
+    pub token_id: Option<Nat>,
+}
+
+impl ReceiptTransferDto {
+    /// Builds the receipt entry of a transfer transaction.
+    /// Approvals move no tokens, so they return `None`.
+    pub fn from_transaction(transaction: &Transaction) -> Option<Self> {
+        let (asset, amount, from, to, btc_address, token_id) = match &transaction.protocol {
+            Protocol::IC(IcTransaction::Icrc1Transfer(transfer)) => (
+                &transfer.asset,
+                transfer.amount.clone(),
+                &transfer.from,
+                &transfer.to,
+                None,
+                None,
+            ),
+            Protocol::IC(IcTransaction::Icrc2TransferFrom(transfer_from)) => (
+                &transfer_from.asset,
+                transfer_from.amount.clone(),
+                &transfer_from.from,
+                &transfer_from.to,
+                None,
+                None,
+            ),
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(retrieve_btc)) => (
+                &retrieve_btc.asset,
+                retrieve_btc.amount.clone(),
+                &retrieve_btc.from,
+                &retrieve_btc.minter,
+                Some(retrieve_btc.btc_address.clone()),
+                None,
+            ),
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => (
+                &icrc7_transfer.asset,
+                Nat::from(1u64),
+                &icrc7_transfer.from,
+                &icrc7_transfer.to,
+                None,
+                Some(icrc7_transfer.token_id.clone()),
+            ),
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => (
+                &icp_transfer.asset,
+                icp_transfer.amount.clone(),
+                &icp_transfer.from,
+                &icp_transfer.to,
+                None,
+                None,
+            ),
+            Protocol::IC(IcTransaction::Icrc2Approve(_)) => return None,
+        };
+
+        let to_account_identifier = match to {
+            Wallet::IcpAccountIdentifier { account_identifier } => Some(account_identifier.clone()),
+            Wallet::IC { .. } => None,
+        };
+
+        Some(Self {
+            transaction_id: transaction.id.clone(),
+            state: transaction.state.clone(),
+            ledger: asset.address(),
+            block_index: transaction.block_index.clone(),
+            amount,
+            from: from.get_account()?,
+            to: to.get_account(),
+            to_account_identifier,
+            btc_address,
+            token_id,
+        })
+    }
+}
+
+/// Every ledger transfer made for one action.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct ActionReceiptDto {
+    pub action_id: String,
+    pub link_id: String,
+    pub action_type: ActionType,
+    pub state: ActionState,
+    pub transfers: Vec<ReceiptTransferDto>,
+}
+
+impl From<&ActionData> for ActionReceiptDto {
+    fn from(action_data: &ActionData) -> Self {
+        let transfers = action_data
+            .intents
+            .iter()
+            .flat_map(|intent| action_data.intent_txs.get(&intent.id).into_iter().flatten())
+            .filter_map(ReceiptTransferDto::from_transaction)
+            .collect();
+
+        Self {
+            action_id: action_data.action.id.clone(),
+            link_id: action_data.action.link_id.clone(),
+            action_type: action_data.action.r#type.clone(),
+            state: action_data.action.state.clone(),
+            transfers,
+        }
+    }
+}
+
+/// An action made by a user on a link, returned to the link creator by `user_get_link_actions_v2`.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct LinkActionHistoryDto {
+    pub action_id: String,
+    pub action_type: ActionType,
+    pub state: ActionState,
+    pub user_id: Principal,
+    /// When the action was created
+    pub created_at: u64,
+    /// When the action was last processed, `None` until it is processed
+    pub processed_at: Option<u64>,
+    /// The transfers of the action, with their block index once they succeed
+    pub transfers: Vec<ReceiptTransferDto>,
+}
+
+impl LinkActionHistoryDto {
+    /// Builds the history entry of an action from its stored data.
+    pub fn build(link_action: &LinkAction, action_data: &ActionData) -> Self {
+        let transactions = action_data
+            .intents
+            .iter()
+            .flat_map(|intent| action_data.intent_txs.get(&intent.id).into_iter().flatten());
+
+        let created_at = transactions
+            .clone()
+            .map(|tx| tx.created_at)
+            .min()
+            .unwrap_or_default();
+        let processed_at = transactions.clone().filter_map(|tx| tx.start_ts).max();
+
+        Self {
+            action_id: action_data.action.id.clone(),
+            action_type: action_data.action.r#type.clone(),
+            state: action_data.action.state.clone(),
+            user_id: link_action.user_id,
+            created_at,
+            processed_at,
+            transfers: transactions
+                .filter_map(ReceiptTransferDto::from_transaction)
+                .collect(),
+        }
+    }
+}
+
+/// The predicted outcome of processing an action, returned by `user_simulate_action_v2`.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone)]
+pub struct SimulateActionDto {
+    pub action_id: String,
+    pub is_success: bool,
+    /// The first error that would make processing fail
+    pub blocking_error: Option<String>,
+    pub errors: Vec<String>,
+}
+
+impl From<SimulateActionResult> for SimulateActionDto {
+    fn from(result: SimulateActionResult) -> Self {
+        Self {
+            action_id: result.action.id,
+            is_success: result.is_success,
+            blocking_error: result.errors.first().cloned(),
+            errors: result.errors,
+        }
+    }
+}
+
+/// The cost of creating a link in one token.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq)]
+pub struct TokenCostEstimateDto {
+    pub token_id: Principal,
+    /// The amount shared with the link users
+    pub funding_amount: Nat,
+    /// The ledger fees of the deposit, the claims and the link creation fee payment
+    pub ledger_fee_amount: Nat,
+    /// The link creation fee
+    pub service_fee_amount: Nat,
+    /// The allowance requested to pay the link creation fee, if it is charged in this token
+    pub approve_amount: Option<Nat>,
+    /// The total amount debited from the creator wallet
+    pub total_amount: Nat,
+    /// Whether the ledger fee of the token is cached. When it is not, the ledger fees are counted as 0.
+    pub is_ledger_fee_cached: bool,
+}
+
+/// The cost of creating a link, returned by `estimate_create_link`.
+#[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq)]
+pub struct CreateLinkEstimateDto {
+    pub link_type: LinkType,
+    pub tokens: Vec<TokenCostEstimateDto>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::repository::{
+        common::Asset,
+        transaction::v1::{FromCallType, IcpTransfer, Icrc1Transfer, Icrc2Approve, Icrc7Transfer},
+    };
+
+    fn make_transaction(id: &str, protocol: Protocol) -> Transaction {
+        Transaction {
+            id: id.to_string(),
+            created_at: 0,
+            state: TransactionState::Success,
+            dependency: None,
+            group: 1,
+            from_call_type: FromCallType::Canister,
+            protocol,
+            start_ts: None,
+            block_index: Some(Nat::from(42u64)),
+        }
+    }
+
+    #[test]
+    fn it_should_build_receipt_entry_for_transfer() {
+        // Arrange
+        let ledger = Principal::anonymous();
+        let transaction = make_transaction(
+            "tx1",
+            Protocol::IC(IcTransaction::Icrc1Transfer(Icrc1Transfer {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                asset: Asset::IC { address: ledger },
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+        );
+
+        // Act
+        let result = ReceiptTransferDto::from_transaction(&transaction).unwrap();
+
+        // Assert
+        assert_eq!(result.transaction_id, "tx1");
+        assert_eq!(result.ledger, ledger);
+        assert_eq!(result.block_index, Some(Nat::from(42u64)));
+        assert_eq!(result.amount, Nat::from(100u64));
+    }
+
+    #[test]
+    fn it_should_build_receipt_entry_for_nft_transfer() {
+        // Arrange
+        let collection = Principal::anonymous();
+        let transaction = make_transaction(
+            "tx1",
+            Protocol::IC(IcTransaction::Icrc7Transfer(Icrc7Transfer {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                asset: Asset::IcNft {
+                    address: collection,
+                },
+                token_id: Nat::from(7u64),
+                memo: None,
+                ts: None,
+            })),
+        );
+
+        // Act
+        let result = ReceiptTransferDto::from_transaction(&transaction).unwrap();
+
+        // Assert
+        assert_eq!(result.ledger, collection);
+        assert_eq!(result.amount, Nat::from(1u64));
+        assert_eq!(result.token_id, Some(Nat::from(7u64)));
+    }
+
+    #[test]
+    fn it_should_build_receipt_entry_for_icp_account_identifier_transfer() {
+        // Arrange
+        let account_identifier =
+            "a2a794c66495083317e4be5197eb655b1e63015469d769e2338af3d3e3f3aa86".to_string();
+        let transaction = make_transaction(
+            "tx1",
+            Protocol::IC(IcTransaction::IcpTransfer(IcpTransfer {
+                from: Wallet::default(),
+                to: Wallet::IcpAccountIdentifier {
+                    account_identifier: account_identifier.clone(),
+                },
+                asset: Asset::default(),
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+        );
+
+        // Act
+        let result = ReceiptTransferDto::from_transaction(&transaction).unwrap();
+
+        // Assert
+        assert_eq!(result.amount, Nat::from(100u64));
+        assert_eq!(result.to, None);
+        assert_eq!(result.to_account_identifier, Some(account_identifier));
+    }
+
+    #[test]
+    fn it_should_skip_approvals_in_receipt() {
+        // Arrange
+        let transaction = make_transaction(
+            "tx1",
+            Protocol::IC(IcTransaction::Icrc2Approve(Icrc2Approve {
+                from: Wallet::default(),
+                spender: Wallet::default(),
+                asset: Asset::default(),
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+        );
+
+        // Act
+        let result = ReceiptTransferDto::from_transaction(&transaction);
+
+        // Assert
+        assert!(result.is_none());
+    }
+}
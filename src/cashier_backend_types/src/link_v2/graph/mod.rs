@@ -118,6 +118,7 @@ mod tests {
                 amount: Nat::from(100u64),
             }),
             label: "mock_intent".to_string(),
+            link_id: None,
         }
     }
 
@@ -139,6 +140,7 @@ mod tests {
                 ts: None,
             })),
             start_ts: None,
+            block_index: None,
         }
     }
 
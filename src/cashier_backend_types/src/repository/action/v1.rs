@@ -15,6 +15,8 @@ pub struct Action {
     pub state: ActionState,
     pub creator: Principal,
     pub link_id: String,
+    /// The number of uses a TOP_UP action funds, as requested when it was created
+    pub top_up_count: Option<u64>,
 }
 
 #[storable]
@@ -40,6 +42,7 @@ pub enum ActionType {
     Withdraw,
     Receive,
     Send,
+    TopUp,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, CandidType, PartialEq, Eq, Display)]
@@ -11,4 +11,182 @@ pub struct AssetInfo {
     pub asset: Asset,
     pub label: String,
     pub amount_per_link_use_action: Nat,
+    // NFT token ids held by the link and not yet reserved by a claim, NFT assets only
+    pub token_ids: Option<Vec<Nat>>,
+    // NFT token ids held by the link for claims not processed yet, NFT assets only
+    pub reserved_token_ids: Option<Vec<TokenIdReservation>>,
+}
+
+/// An NFT token id held for a RECEIVE action until it is processed or expires.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct TokenIdReservation {
+    pub action_id: String,
+    pub token_id: Nat,
+    pub expires_at: u64,
+}
+
+impl AssetInfo {
+    /// Moves an available token id to the reservations of an action.
+    /// # Arguments
+    /// * `action_id` - The ID of the action claiming the token id
+    /// * `token_id` - The token id sent by the action
+    /// * `expires_at` - The time after which the token id can be made available again
+    /// # Returns
+    /// * `bool` - True if the token id was available
+    pub fn reserve_token_id(&mut self, action_id: &str, token_id: &Nat, expires_at: u64) -> bool {
+        let Some(token_ids) = self.token_ids.as_mut() else {
+            return false;
+        };
+        let Some(index) = token_ids.iter().position(|id| id == token_id) else {
+            return false;
+        };
+
+        let token_id = token_ids.remove(index);
+        self.reserved_token_ids
+            .get_or_insert_default()
+            .push(TokenIdReservation {
+                action_id: action_id.to_string(),
+                token_id,
+                expires_at,
+            });
+        true
+    }
+
+    /// Makes the token ids reserved by an action available again.
+    /// # Arguments
+    /// * `action_id` - The ID of the action whose token ids are released
+    /// # Returns
+    /// * `bool` - True if any token id was reserved by the action
+    pub fn release_token_ids(&mut self, action_id: &str) -> bool {
+        let released = self.take_reservations(action_id);
+        if released.is_empty() {
+            return false;
+        }
+
+        self.token_ids
+            .get_or_insert_default()
+            .extend(released.into_iter().map(|reservation| reservation.token_id));
+        true
+    }
+
+    /// Drops the token ids reserved by an action once they have been sent.
+    /// # Arguments
+    /// * `action_id` - The ID of the processed action
+    pub fn settle_token_ids(&mut self, action_id: &str) {
+        self.take_reservations(action_id);
+    }
+
+    /// Returns true if a token id is reserved by the action.
+    pub fn is_token_id_reserved(&self, action_id: &str) -> bool {
+        self.reserved_token_ids
+            .iter()
+            .flatten()
+            .any(|reservation| reservation.action_id == action_id)
+    }
+
+    /// Returns the IDs of the actions whose reserved token ids have expired at `now`.
+    pub fn expired_token_id_reservations(&self, now: u64) -> Vec<String> {
+        self.reserved_token_ids
+            .iter()
+            .flatten()
+            .filter(|reservation| now >= reservation.expires_at)
+            .map(|reservation| reservation.action_id.clone())
+            .collect()
+    }
+
+    /// Returns the available and reserved token ids, all still held by the link.
+    pub fn held_token_ids(&self) -> Vec<Nat> {
+        self.token_ids
+            .iter()
+            .flatten()
+            .cloned()
+            .chain(
+                self.reserved_token_ids
+                    .iter()
+                    .flatten()
+                    .map(|reservation| reservation.token_id.clone()),
+            )
+            .collect()
+    }
+
+    fn take_reservations(&mut self, action_id: &str) -> Vec<TokenIdReservation> {
+        let Some(reservations) = self.reserved_token_ids.as_mut() else {
+            return vec![];
+        };
+
+        let (taken, kept) = reservations
+            .drain(..)
+            .partition(|reservation| reservation.action_id == action_id);
+        *reservations = kept;
+        taken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use candid::Principal;
+
+    #[test]
+    fn it_should_reserve_and_release_token_ids() {
+        // Arrange
+        let mut asset_info = AssetInfo {
+            asset: Asset::IcNft {
+                address: Principal::anonymous(),
+            },
+            label: "SEND_AIRDROP_ASSET".to_string(),
+            amount_per_link_use_action: Nat::from(1u64),
+            token_ids: Some(vec![Nat::from(4u64), Nat::from(9u64)]),
+            reserved_token_ids: None,
+        };
+
+        // Act
+        assert!(asset_info.reserve_token_id("action1", &Nat::from(4u64), 200));
+        assert!(!asset_info.reserve_token_id("action2", &Nat::from(4u64), 200));
+
+        // Assert
+        assert_eq!(asset_info.token_ids, Some(vec![Nat::from(9u64)]));
+        assert!(asset_info.is_token_id_reserved("action1"));
+        assert!(asset_info.expired_token_id_reservations(199).is_empty());
+        assert_eq!(
+            asset_info.expired_token_id_reservations(200),
+            vec!["action1"]
+        );
+        assert_eq!(
+            asset_info.held_token_ids(),
+            vec![Nat::from(9u64), Nat::from(4u64)]
+        );
+
+        // Act
+        assert!(asset_info.release_token_ids("action1"));
+
+        // Assert
+        assert!(!asset_info.is_token_id_reserved("action1"));
+        assert_eq!(
+            asset_info.token_ids,
+            Some(vec![Nat::from(9u64), Nat::from(4u64)])
+        );
+    }
+
+    #[test]
+    fn it_should_settle_token_ids() {
+        // Arrange
+        let mut asset_info = AssetInfo {
+            asset: Asset::IcNft {
+                address: Principal::anonymous(),
+            },
+            label: "SEND_AIRDROP_ASSET".to_string(),
+            amount_per_link_use_action: Nat::from(1u64),
+            token_ids: Some(vec![Nat::from(4u64)]),
+            reserved_token_ids: None,
+        };
+        asset_info.reserve_token_id("action1", &Nat::from(4u64), 200);
+
+        // Act
+        asset_info.settle_token_ids("action1");
+
+        // Assert
+        assert!(asset_info.held_token_ids().is_empty());
+        assert!(!asset_info.release_token_ids("action1"));
+    }
 }
@@ -2,6 +2,7 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{CandidType, Principal};
+use ic_ledger_types::{AccountIdentifier, Subaccount as IcpSubaccount};
 use icrc_ledger_types::icrc1::account::{Account, Subaccount};
 use serde::{Deserialize, Serialize};
 
@@ -9,7 +10,13 @@ pub type Chain = cashier_common::chain::Chain;
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq, Ord, PartialOrd)]
 pub enum Asset {
-    IC { address: Principal },
+    IC {
+        address: Principal,
+    },
+    /// An ICRC-7 NFT collection, the token ids sent are tracked on the link asset info
+    IcNft {
+        address: Principal,
+    },
 }
 
 impl Default for Asset {
@@ -24,9 +31,21 @@ impl Asset {
     /// Returns the chain of the asset
     pub fn chain(&self) -> Chain {
         match self {
-            Asset::IC { .. } => Chain::IC,
+            Asset::IC { .. } | Asset::IcNft { .. } => Chain::IC,
         }
     }
+
+    /// Returns the principal of the canister holding the asset
+    pub fn address(&self) -> Principal {
+        match self {
+            Asset::IC { address } | Asset::IcNft { address } => *address,
+        }
+    }
+
+    /// Returns true if the asset is an NFT collection
+    pub fn is_nft(&self) -> bool {
+        matches!(self, Asset::IcNft { .. })
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, CandidType, Clone, PartialEq, Eq, Ord, PartialOrd)]
@@ -35,6 +54,8 @@ pub enum Wallet {
         address: Principal,
         subaccount: Option<Subaccount>,
     },
+    /// A legacy ICP ledger account identifier, hex encoded, paid through the ICP `transfer` method
+    IcpAccountIdentifier { account_identifier: String },
 }
 
 impl Default for Wallet {
@@ -61,15 +82,48 @@ impl Wallet {
         }
     }
 
-    pub fn get_account(&self) -> Account {
+    /// Creates a wallet from a hex encoded ICP account identifier, checking its CRC32 checksum.
+    /// # Arguments
+    /// * `account_identifier` - The 32-byte account identifier, hex encoded
+    /// # Returns
+    /// * `Result<Wallet, String>` - The wallet or an error if the account identifier is invalid
+    pub fn from_icp_account_identifier(account_identifier: &str) -> Result<Self, String> {
+        let account_identifier = AccountIdentifier::from_hex(account_identifier)
+            .map_err(|e| format!("Invalid ICP account identifier: {e}"))?;
+        Ok(Wallet::IcpAccountIdentifier {
+            account_identifier: account_identifier.to_hex(),
+        })
+    }
+
+    /// Returns the ICRC account of the wallet, `None` for legacy ICP account identifiers
+    pub fn get_account(&self) -> Option<Account> {
         match self {
             Wallet::IC {
                 address,
                 subaccount,
-            } => Account {
+            } => Some(Account {
                 owner: *address,
                 subaccount: *subaccount,
-            },
+            }),
+            Wallet::IcpAccountIdentifier { .. } => None,
+        }
+    }
+
+    /// Returns the ICP ledger account identifier of the wallet
+    /// # Returns
+    /// * `Result<AccountIdentifier, String>` - The account identifier or an error if it is invalid
+    pub fn get_icp_account_identifier(&self) -> Result<AccountIdentifier, String> {
+        match self {
+            Wallet::IC {
+                address,
+                subaccount,
+            } => Ok(AccountIdentifier::new(
+                address,
+                &IcpSubaccount(subaccount.unwrap_or([0; 32])),
+            )),
+            Wallet::IcpAccountIdentifier { account_identifier } => {
+                AccountIdentifier::from_hex(account_identifier)
+            }
         }
     }
 }
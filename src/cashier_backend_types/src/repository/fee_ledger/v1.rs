@@ -0,0 +1,39 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_macros::storable;
+use ic_mple_structures::Codec;
+
+use crate::repository::link::v1::LinkType;
+
+/// A fee collected into the treasury, recorded when its fee transaction succeeds.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[storable]
+pub struct FeeLedgerEntry {
+    pub intent_id: String,
+    pub action_id: String,
+    pub link_id: String,
+    pub link_type: LinkType,
+    pub payer: Principal,
+    pub token_id: Principal,
+    pub amount: Nat,
+    pub collected_at: u64,
+}
+
+#[storable]
+pub enum FeeLedgerCodec {
+    V1(FeeLedgerEntry),
+}
+
+impl Codec<FeeLedgerEntry> for FeeLedgerCodec {
+    fn decode(source: Self) -> FeeLedgerEntry {
+        match source {
+            FeeLedgerCodec::V1(entry) => entry,
+        }
+    }
+
+    fn encode(dest: FeeLedgerEntry) -> Self {
+        FeeLedgerCodec::V1(dest)
+    }
+}
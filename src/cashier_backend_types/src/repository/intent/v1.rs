@@ -20,6 +20,8 @@ pub struct Intent {
     pub task: IntentTask,
     pub r#type: IntentType,
     pub label: String,
+    /// The link the intent moves funds for, when it is not the link of its action
+    pub link_id: Option<String>,
 }
 
 #[storable]
@@ -55,6 +57,7 @@ impl Default for Intent {
                 amount: Nat::from(0u64),
             }),
             label: "".to_string(),
+            link_id: None,
         }
     }
 }
@@ -73,6 +76,8 @@ pub enum IntentState {
 pub enum IntentType {
     Transfer(TransferData),
     TransferFrom(TransferFromData),
+    RetrieveBtc(RetrieveBtcData),
+    NftTransfer(NftTransferData),
 }
 
 impl IntentType {
@@ -80,6 +85,8 @@ impl IntentType {
         match self {
             IntentType::Transfer(data) => Some(data.asset.clone()),
             IntentType::TransferFrom(data) => Some(data.asset.clone()),
+            IntentType::RetrieveBtc(data) => Some(data.asset.clone()),
+            IntentType::NftTransfer(data) => Some(data.asset.clone()),
         }
     }
     pub fn as_transfer(&self) -> Option<TransferData> {
@@ -96,6 +103,20 @@ impl IntentType {
         }
     }
 
+    pub fn as_retrieve_btc(&self) -> Option<RetrieveBtcData> {
+        match self {
+            IntentType::RetrieveBtc(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
+    pub fn as_nft_transfer(&self) -> Option<NftTransferData> {
+        match self {
+            IntentType::NftTransfer(data) => Some(data.clone()),
+            _ => None,
+        }
+    }
+
     pub fn default_transfer() -> Self {
         IntentType::Transfer(TransferData {
             from: Wallet::default(),
@@ -140,6 +161,26 @@ pub struct TransferFromData {
     pub approve_amount: Option<Nat>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq, Ord, PartialOrd)]
+pub struct RetrieveBtcData {
+    pub from: Wallet,
+    // the ckBTC minter burning the amount
+    pub minter: Wallet,
+    pub asset: Asset,
+    pub amount: Nat,
+    // the Bitcoin address receiving the BTC
+    pub btc_address: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, CandidType, PartialEq, Eq, Ord, PartialOrd)]
+pub struct NftTransferData {
+    pub from: Wallet,
+    pub to: Wallet,
+    // the ICRC-7 collection
+    pub asset: Asset,
+    pub token_id: Nat,
+}
+
 #[derive(
     Serialize, Deserialize, Debug, Clone, CandidType, PartialEq, Eq, Ord, PartialOrd, Display,
 )]
@@ -147,4 +188,5 @@ pub enum IntentTask {
     TransferWalletToTreasury,
     TransferWalletToLink,
     TransferLinkToWallet,
+    TransferLinkToBitcoin,
 }
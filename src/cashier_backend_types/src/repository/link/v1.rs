@@ -1,13 +1,13 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use candid::{CandidType, Principal};
+use candid::{CandidType, Nat, Principal};
 use cashier_macros::storable;
 use derive_more::Display;
 use ic_mple_structures::Codec;
 use serde::{Deserialize, Serialize};
 
-use crate::repository::asset_info::AssetInfo;
+use crate::repository::{asset_info::AssetInfo, common::Wallet};
 
 #[derive(Debug, Clone)]
 #[storable]
@@ -21,26 +21,100 @@ pub struct Link {
     pub create_at: u64,
     pub link_use_action_counter: u64,
     pub link_use_action_max_count: u64,
+    pub expires_at: Option<u64>,
+    pub gate_id: Option<String>,
+    pub starts_at: Option<u64>,
+    pub max_use_per_user: Option<u64>,
+    pub allow_list: Option<Vec<Principal>>,
+    pub random_pot: Option<RandomPot>,
+    pub split_recipients: Option<Vec<SplitRecipient>>,
+    pub amount_bounds: Option<AmountBounds>,
+    pub description: Option<String>,
+    pub image_url: Option<String>,
+    // the other links funded by the CREATE_LINK action of this link, for links created in a batch
+    pub batch_link_ids: Option<Vec<String>>,
 }
 
+/// Link layout stored before `expires_at` was introduced.
+#[derive(Debug, Clone)]
+#[storable]
+pub struct LinkV1 {
+    pub id: String,
+    pub state: LinkState,
+    pub title: String,
+    pub link_type: LinkType,
+    pub asset_info: Vec<AssetInfo>,
+    pub creator: Principal,
+    pub create_at: u64,
+    pub link_use_action_counter: u64,
+    pub link_use_action_max_count: u64,
+}
+
+impl From<LinkV1> for Link {
+    fn from(link: LinkV1) -> Self {
+        Link {
+            id: link.id,
+            state: link.state,
+            title: link.title,
+            link_type: link.link_type,
+            asset_info: link.asset_info,
+            creator: link.creator,
+            create_at: link.create_at,
+            link_use_action_counter: link.link_use_action_counter,
+            link_use_action_max_count: link.link_use_action_max_count,
+            expires_at: None,
+            gate_id: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            random_pot: None,
+            split_recipients: None,
+            amount_bounds: None,
+            description: None,
+            image_url: None,
+            batch_link_ids: None,
+        }
+    }
+}
+
+#[allow(clippy::large_enum_variant)]
 #[storable]
 pub enum LinkCodec {
-    V1(Link),
+    V1(LinkV1),
+    V2(Link),
 }
 
 impl Codec<Link> for LinkCodec {
     fn decode(source: Self) -> Link {
         match source {
-            LinkCodec::V1(link) => link,
+            LinkCodec::V1(link) => link.into(),
+            LinkCodec::V2(link) => link,
         }
     }
 
     fn encode(dest: Link) -> Self {
-        LinkCodec::V1(dest)
+        LinkCodec::V2(dest)
     }
 }
 
 impl Link {
+    /// Returns true if the link has an expiry and it is at or before `now`.
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at.is_some_and(|expires_at| now >= expires_at)
+    }
+
+    /// Returns true if the link has no start time or it is at or before `now`.
+    pub fn is_started(&self, now: u64) -> bool {
+        self.starts_at.is_none_or(|starts_at| now >= starts_at)
+    }
+
+    /// Returns true if the link has no allow-list or `user` is on it.
+    pub fn is_allowed(&self, user: &Principal) -> bool {
+        self.allow_list
+            .as_ref()
+            .is_none_or(|allow_list| allow_list.contains(user))
+    }
+
     pub fn get_asset_by_label(&self, label: &str) -> Option<AssetInfo> {
         self.asset_info
             .iter()
@@ -55,12 +129,273 @@ pub enum LinkType {
     SendAirdrop,
     SendTokenBasket,
     ReceivePayment,
+    SendRandomAirdrop,
+    ReceiveSplitPayment,
+}
+
+/// What is left to share on a random-amount link.
+/// `remaining_amounts` follows the order of the link `asset_info`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct RandomPot {
+    pub remaining_amounts: Vec<Nat>,
+    pub remaining_claims: u64,
+    /// The shares drawn by claims that are not processed yet
+    #[serde(default)]
+    pub reservations: Vec<RandomShareReservation>,
+}
+
+/// A share of a random pot held for a RECEIVE action until it is processed or expires.
+/// `amounts` follows the order of the link `asset_info`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct RandomShareReservation {
+    pub action_id: String,
+    pub amounts: Vec<Nat>,
+    pub expires_at: u64,
+}
+
+impl RandomPot {
+    /// Takes a share out of the pot and holds it for an action.
+    /// # Arguments
+    /// * `action_id` - The ID of the action claiming the share
+    /// * `amounts` - The share, in the order of the link `asset_info`
+    /// * `expires_at` - The time after which the share can be given back to the pot
+    pub fn reserve(&mut self, action_id: &str, amounts: Vec<Nat>, expires_at: u64) {
+        self.remaining_amounts
+            .iter_mut()
+            .zip(&amounts)
+            .for_each(|(remaining_amount, amount)| *remaining_amount -= amount.clone());
+        self.remaining_claims = self.remaining_claims.saturating_sub(1);
+        self.reservations.push(RandomShareReservation {
+            action_id: action_id.to_string(),
+            amounts,
+            expires_at,
+        });
+    }
+
+    /// Gives the share held for an action back to the pot.
+    /// # Arguments
+    /// * `action_id` - The ID of the action whose share is released
+    /// # Returns
+    /// * `bool` - True if a share was held for the action
+    pub fn release(&mut self, action_id: &str) -> bool {
+        let Some(reservation) = self.take_reservation(action_id) else {
+            return false;
+        };
+        self.remaining_amounts
+            .iter_mut()
+            .zip(reservation.amounts)
+            .for_each(|(remaining_amount, amount)| *remaining_amount += amount);
+        self.remaining_claims += 1;
+        true
+    }
+
+    /// Drops the share held for an action once it has been paid out.
+    /// # Arguments
+    /// * `action_id` - The ID of the processed action
+    pub fn settle(&mut self, action_id: &str) {
+        self.take_reservation(action_id);
+    }
+
+    /// Returns true if a share is held for the action.
+    pub fn is_reserved(&self, action_id: &str) -> bool {
+        self.reservations
+            .iter()
+            .any(|reservation| reservation.action_id == action_id)
+    }
+
+    /// Returns the IDs of the actions whose share has expired at `now`.
+    pub fn expired_reservations(&self, now: u64) -> Vec<String> {
+        self.reservations
+            .iter()
+            .filter(|reservation| now >= reservation.expires_at)
+            .map(|reservation| reservation.action_id.clone())
+            .collect()
+    }
+
+    fn take_reservation(&mut self, action_id: &str) -> Option<RandomShareReservation> {
+        let index = self
+            .reservations
+            .iter()
+            .position(|reservation| reservation.action_id == action_id)?;
+        Some(self.reservations.remove(index))
+    }
+}
+
+/// The weights of split payment recipients are expressed in basis points and add up to this total.
+pub const SPLIT_TOTAL_WEIGHT_BPS: u16 = 10_000;
+
+/// A wallet receiving a share of every payment made to a split payment link.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct SplitRecipient {
+    pub wallet: Wallet,
+    pub weight_bps: u16,
+}
+
+/// The range of amounts a payer can choose from on a variable-amount payment link.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType)]
+pub struct AmountBounds {
+    pub min: Nat,
+    pub max: Nat,
+}
+
+impl AmountBounds {
+    /// Returns true if `amount` is within the bounds, both ends included.
+    pub fn contains(&self, amount: &Nat) -> bool {
+        *amount >= self.min && *amount <= self.max
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq, CandidType, Display)]
 pub enum LinkState {
     CreateLink,
+    Scheduled,
     Active,
     Inactive,
     InactiveEnded,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ic_stable_structures::Storable;
+
+    #[test]
+    fn it_should_decode_v1_link_without_expiry() {
+        // Arrange
+        let legacy = LinkV1 {
+            id: "link1".to_string(),
+            state: LinkState::Active,
+            title: "Legacy Link".to_string(),
+            link_type: LinkType::SendTip,
+            asset_info: vec![],
+            creator: Principal::anonymous(),
+            create_at: 1622547800,
+            link_use_action_counter: 1,
+            link_use_action_max_count: 10,
+        };
+        let bytes = LinkCodec::V1(legacy).into_bytes();
+
+        // Act
+        let link: Link = Codec::decode(LinkCodec::from_bytes(bytes.into()));
+
+        // Assert
+        assert_eq!(link.id, "link1");
+        assert_eq!(link.link_use_action_counter, 1);
+        assert_eq!(link.expires_at, None);
+    }
+
+    #[test]
+    fn it_should_report_expiry() {
+        // Arrange
+        let mut link: Link = LinkV1 {
+            id: "link1".to_string(),
+            state: LinkState::Active,
+            title: "Link".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![],
+            creator: Principal::anonymous(),
+            create_at: 100,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 10,
+        }
+        .into();
+
+        // Act & Assert
+        assert!(!link.is_expired(u64::MAX));
+        link.expires_at = Some(200);
+        assert!(!link.is_expired(199));
+        assert!(link.is_expired(200));
+    }
+
+    #[test]
+    fn it_should_report_start() {
+        // Arrange
+        let mut link: Link = LinkV1 {
+            id: "link1".to_string(),
+            state: LinkState::Scheduled,
+            title: "Link".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![],
+            creator: Principal::anonymous(),
+            create_at: 100,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 10,
+        }
+        .into();
+
+        // Act & Assert
+        assert!(link.is_started(0));
+        link.starts_at = Some(200);
+        assert!(!link.is_started(199));
+        assert!(link.is_started(200));
+    }
+
+    #[test]
+    fn it_should_report_allowed_users() {
+        // Arrange
+        let allowed = Principal::from_text("aaaaa-aa").unwrap();
+        let mut link: Link = LinkV1 {
+            id: "link1".to_string(),
+            state: LinkState::Active,
+            title: "Link".to_string(),
+            link_type: LinkType::SendAirdrop,
+            asset_info: vec![],
+            creator: Principal::anonymous(),
+            create_at: 100,
+            link_use_action_counter: 0,
+            link_use_action_max_count: 10,
+        }
+        .into();
+
+        // Act & Assert
+        assert!(link.is_allowed(&Principal::anonymous()));
+        link.allow_list = Some(vec![allowed]);
+        assert!(link.is_allowed(&allowed));
+        assert!(!link.is_allowed(&Principal::anonymous()));
+    }
+
+    #[test]
+    fn it_should_report_amount_within_bounds() {
+        // Arrange
+        let bounds = AmountBounds {
+            min: Nat::from(10u64),
+            max: Nat::from(20u64),
+        };
+
+        // Act & Assert
+        assert!(!bounds.contains(&Nat::from(9u64)));
+        assert!(bounds.contains(&Nat::from(10u64)));
+        assert!(bounds.contains(&Nat::from(20u64)));
+        assert!(!bounds.contains(&Nat::from(21u64)));
+    }
+
+    #[test]
+    fn it_should_reserve_and_release_random_shares() {
+        // Arrange
+        let mut random_pot = RandomPot {
+            remaining_amounts: vec![Nat::from(100u64)],
+            remaining_claims: 2,
+            reservations: vec![],
+        };
+
+        // Act
+        random_pot.reserve("action1", vec![Nat::from(30u64)], 200);
+        random_pot.reserve("action2", vec![Nat::from(50u64)], 300);
+
+        // Assert
+        assert_eq!(random_pot.remaining_amounts, vec![Nat::from(20u64)]);
+        assert_eq!(random_pot.remaining_claims, 0);
+        assert_eq!(random_pot.expired_reservations(199), Vec::<String>::new());
+        assert_eq!(random_pot.expired_reservations(200), vec!["action1"]);
+
+        // Act
+        assert!(random_pot.release("action1"));
+        random_pot.settle("action2");
+
+        // Assert
+        assert_eq!(random_pot.remaining_amounts, vec![Nat::from(50u64)]);
+        assert_eq!(random_pot.remaining_claims, 1);
+        assert!(random_pot.reservations.is_empty());
+        assert!(!random_pot.release("action1"));
+    }
+}
@@ -5,6 +5,7 @@ pub mod action;
 pub mod action_intent;
 pub mod asset_info;
 pub mod common;
+pub mod fee_ledger;
 pub mod intent;
 pub mod intent_transaction;
 pub mod keys;
@@ -27,22 +27,55 @@ pub struct Transaction {
     pub from_call_type: FromCallType,
     pub protocol: Protocol,
     pub start_ts: Option<u64>,
+    pub block_index: Option<Nat>,
+}
+
+/// Transaction layout stored before `block_index` was introduced.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[storable]
+pub struct TransactionV1 {
+    pub id: String,
+    pub created_at: u64,
+    pub state: TransactionState,
+    pub dependency: Option<Vec<String>>,
+    pub group: u16,
+    pub from_call_type: FromCallType,
+    pub protocol: Protocol,
+    pub start_ts: Option<u64>,
+}
+
+impl From<TransactionV1> for Transaction {
+    fn from(transaction: TransactionV1) -> Self {
+        Transaction {
+            id: transaction.id,
+            created_at: transaction.created_at,
+            state: transaction.state,
+            dependency: transaction.dependency,
+            group: transaction.group,
+            from_call_type: transaction.from_call_type,
+            protocol: transaction.protocol,
+            start_ts: transaction.start_ts,
+            block_index: None,
+        }
+    }
 }
 
 #[storable]
 pub enum TransactionCodec {
-    V1(Transaction),
+    V1(TransactionV1),
+    V2(Transaction),
 }
 
 impl Codec<Transaction> for TransactionCodec {
     fn decode(source: Self) -> Transaction {
         match source {
-            TransactionCodec::V1(link) => link,
+            TransactionCodec::V1(transaction) => transaction.into(),
+            TransactionCodec::V2(transaction) => transaction,
         }
     }
 
     fn encode(dest: Transaction) -> Self {
-        TransactionCodec::V1(dest)
+        TransactionCodec::V2(dest)
     }
 }
 
@@ -56,10 +89,17 @@ impl Transaction {
             Protocol::IC(IcTransaction::Icrc2TransferFrom(icrc2_transfer_from)) => {
                 icrc2_transfer_from.asset.clone()
             }
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc)) => {
+                ckbtc_retrieve_btc.asset.clone()
+            }
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => {
+                icrc7_transfer.asset.clone()
+            }
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => icp_transfer.asset.clone(),
         }
     }
 
-    pub fn get_from_account(&self) -> Account {
+    pub fn get_from_account(&self) -> Option<Account> {
         match &self.protocol {
             Protocol::IC(IcTransaction::Icrc1Transfer(icrc1_transfer)) => {
                 icrc1_transfer.from.clone().get_account()
@@ -70,6 +110,15 @@ impl Transaction {
             Protocol::IC(IcTransaction::Icrc2TransferFrom(icrc2_transfer_from)) => {
                 icrc2_transfer_from.from.clone().get_account()
             }
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc)) => {
+                ckbtc_retrieve_btc.from.clone().get_account()
+            }
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => {
+                icrc7_transfer.from.clone().get_account()
+            }
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => {
+                icp_transfer.from.clone().get_account()
+            }
         }
     }
 
@@ -84,6 +133,15 @@ impl Transaction {
             Protocol::IC(IcTransaction::Icrc2TransferFrom(icrc2_transfer_from)) => {
                 icrc2_transfer_from.from = from_account.into()
             }
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc)) => {
+                ckbtc_retrieve_btc.from = from_account.into()
+            }
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => {
+                icrc7_transfer.from = from_account.into()
+            }
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => {
+                icp_transfer.from = from_account.into()
+            }
         }
     }
 
@@ -95,6 +153,12 @@ impl Transaction {
             Protocol::IC(IcTransaction::Icrc2TransferFrom(icrc2_transfer_from)) => {
                 icrc2_transfer_from.to = to_account.into()
             }
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => {
+                icrc7_transfer.to = to_account.into()
+            }
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => {
+                icp_transfer.to = to_account.into()
+            }
             _ => {}
         }
     }
@@ -119,6 +183,9 @@ pub enum IcTransaction {
     Icrc1Transfer(Icrc1Transfer),
     Icrc2Approve(Icrc2Approve),
     Icrc2TransferFrom(Icrc2TransferFrom),
+    CkBtcRetrieveBtc(CkBtcRetrieveBtc),
+    Icrc7Transfer(Icrc7Transfer),
+    IcpTransfer(IcpTransfer),
 }
 
 impl IcTransaction {
@@ -142,6 +209,27 @@ impl IcTransaction {
             _ => None,
         }
     }
+
+    pub fn as_ckbtc_retrieve_btc(&self) -> Option<&CkBtcRetrieveBtc> {
+        match self {
+            IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc) => Some(ckbtc_retrieve_btc),
+            _ => None,
+        }
+    }
+
+    pub fn as_icrc7_transfer(&self) -> Option<&Icrc7Transfer> {
+        match self {
+            IcTransaction::Icrc7Transfer(icrc7_transfer) => Some(icrc7_transfer),
+            _ => None,
+        }
+    }
+
+    pub fn as_icp_transfer(&self) -> Option<&IcpTransfer> {
+        match self {
+            IcTransaction::IcpTransfer(icp_transfer) => Some(icp_transfer),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, CandidType)]
@@ -158,9 +246,15 @@ impl TryFrom<Icrc1Transfer> for TransferArg {
     type Error = String;
 
     fn try_from(value: Icrc1Transfer) -> Result<Self, Self::Error> {
-        let from = value.from.get_account();
+        let from = value
+            .from
+            .get_account()
+            .ok_or("ICRC-1 transfer source must be an ICRC account")?;
 
-        let to = value.to.get_account();
+        let to = value
+            .to
+            .get_account()
+            .ok_or("ICRC-1 transfer destination must be an ICRC account")?;
 
         let amount = value.amount;
         let memo = value.memo;
@@ -201,11 +295,20 @@ impl TryFrom<Icrc2TransferFrom> for TransferFromArgs {
     type Error = String;
 
     fn try_from(value: Icrc2TransferFrom) -> Result<Self, Self::Error> {
-        let spender_account = value.spender.get_account();
+        let spender_account = value
+            .spender
+            .get_account()
+            .ok_or("ICRC-2 spender must be an ICRC account")?;
 
-        let from = value.from.get_account();
+        let from = value
+            .from
+            .get_account()
+            .ok_or("ICRC-2 transfer source must be an ICRC account")?;
 
-        let to = value.to.get_account();
+        let to = value
+            .to
+            .get_account()
+            .ok_or("ICRC-2 transfer destination must be an ICRC account")?;
 
         let amount = value.amount;
         let memo = value.memo;
@@ -222,6 +325,42 @@ impl TryFrom<Icrc2TransferFrom> for TransferFromArgs {
     }
 }
 
+/// Withdrawal of ckBTC to a Bitcoin address: the `from` account approves the ckBTC minter,
+/// which then burns the amount and sends the equivalent BTC to `btc_address`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, CandidType)]
+pub struct CkBtcRetrieveBtc {
+    pub from: Wallet,
+    pub minter: Wallet,
+    pub asset: Asset,
+    pub amount: Nat,
+    pub btc_address: String,
+    pub memo: Option<Memo>,
+    pub ts: Option<u64>,
+}
+
+/// Transfer of a single ICRC-7 NFT, `asset` is the collection holding `token_id`.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, CandidType)]
+pub struct Icrc7Transfer {
+    pub from: Wallet,
+    pub to: Wallet,
+    pub asset: Asset,
+    pub token_id: Nat,
+    pub memo: Option<Memo>,
+    pub ts: Option<u64>,
+}
+
+/// Transfer through the legacy ICP ledger `transfer` method, which pays an account identifier.
+/// Its 64-bit memo cannot hold the link memo, so it is derived from the transaction ID alone.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, CandidType)]
+pub struct IcpTransfer {
+    pub from: Wallet,
+    pub to: Wallet,
+    pub asset: Asset,
+    pub amount: Nat,
+    pub memo: Option<u64>,
+    pub ts: Option<u64>,
+}
+
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, CandidType, Eq, Display)]
 pub enum FromCallType {
     Canister,
@@ -233,6 +372,9 @@ pub enum TransactionProtocol {
     Irrc1Transfer,
     Icrc2Approve,
     Icrc2TransferFrom,
+    CkBtcRetrieveBtc,
+    Icrc7Transfer,
+    IcpTransfer,
 }
 
 #[derive(Serialize, Deserialize, Debug, Clone, PartialEq, CandidType, Eq, Display)]
@@ -242,3 +384,40 @@ pub enum TransactionState {
     Success,
     Fail,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ic_stable_structures::Storable;
+
+    #[test]
+    fn it_should_decode_v1_transaction_without_block_index() {
+        // Arrange
+        let legacy = TransactionV1 {
+            id: "tx1".to_string(),
+            created_at: 1622547800,
+            state: TransactionState::Success,
+            dependency: None,
+            group: 1,
+            from_call_type: FromCallType::Canister,
+            protocol: Protocol::IC(IcTransaction::Icrc1Transfer(Icrc1Transfer {
+                from: Wallet::default(),
+                to: Wallet::default(),
+                asset: Asset::default(),
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+            start_ts: None,
+        };
+        let bytes = TransactionCodec::V1(legacy).into_bytes();
+
+        // Act
+        let transaction: Transaction = Codec::decode(TransactionCodec::from_bytes(bytes.into()));
+
+        // Assert
+        assert_eq!(transaction.id, "tx1");
+        assert_eq!(transaction.state, TransactionState::Success);
+        assert_eq!(transaction.block_index, None);
+    }
+}
@@ -26,6 +26,7 @@ impl TemporaryAction {
             state: self.state.clone(),
             creator: self.creator,
             link_id: self.link_id.clone(),
+            top_up_count: None,
         }
     }
 }
@@ -34,9 +35,9 @@ impl TemporaryAction {
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{CandidType, Principal};
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 
-#[derive(Clone, Debug, CandidType, Deserialize)]
+#[derive(Clone, Debug, CandidType, Serialize, Deserialize)]
 pub struct PaginateInput {
     pub offset: usize,
     pub limit: usize,
@@ -79,6 +80,30 @@ impl<T> PaginateResult<T> {
         Self { data, metadata }
     }
 
+    /// Builds the page of `items` selected by `paginate`.
+    /// # Arguments
+    /// * `items` - All the items, in their display order
+    /// * `paginate` - The pagination parameters
+    /// # Returns
+    /// * `PaginateResult<T>` - The selected page and its metadata
+    pub fn paginate(items: Vec<T>, paginate: &PaginateInput) -> Self {
+        let total = items.len();
+        let offset = paginate.offset;
+        let limit = paginate.limit;
+        let data = items.into_iter().skip(offset).take(limit).collect();
+
+        Self {
+            data,
+            metadata: PaginateResultMetadata {
+                total,
+                offset,
+                limit,
+                is_next: offset.saturating_add(limit) < total,
+                is_prev: offset > 0,
+            },
+        }
+    }
+
     pub fn map<U, F>(self, f: F) -> PaginateResult<U>
     where
         F: FnMut(T) -> U,
@@ -131,3 +156,45 @@ impl Default for PaginateResultMetadata {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_paginate_items() {
+        // Arrange
+        let items = vec![1, 2, 3];
+
+        // Act
+        let result = PaginateResult::paginate(
+            items,
+            &PaginateInput {
+                offset: 2,
+                limit: 2,
+            },
+        );
+
+        // Assert
+        assert_eq!(result.data, vec![3]);
+        assert_eq!(result.metadata.total, 3);
+        assert!(!result.metadata.is_next);
+        assert!(result.metadata.is_prev);
+    }
+
+    #[test]
+    fn it_should_not_overflow_on_large_pagination_input() {
+        // Act
+        let result = PaginateResult::paginate(
+            vec![1, 2, 3],
+            &PaginateInput {
+                offset: 1,
+                limit: usize::MAX,
+            },
+        );
+
+        // Assert
+        assert_eq!(result.data, vec![2, 3]);
+        assert!(!result.metadata.is_next);
+    }
+}
@@ -10,7 +10,10 @@ use cashier_backend_types::{
         },
     },
     error::CanisterError,
-    link_v2::dto::{CreateLinkDto, ProcessActionDto, ProcessActionV2Input},
+    link_v2::dto::{
+        ActionReceiptDto, CreateLinkDto, LinkActionHistoryDto, ProcessActionDto,
+        ProcessActionV2Input, SimulateActionDto, UpdateLinkV2Input,
+    },
     repository::common::Asset,
     service::link::{PaginateInput, PaginateResult},
 };
@@ -90,6 +93,87 @@ impl LinkTestFixtureV2 {
             .unwrap()
     }
 
+    /// Enable link v2
+    /// # Arguments
+    /// * `link_id` - The ID of the link to enable
+    /// # Returns
+    /// * `LinkDto` - The enabled link data
+    pub async fn enable_link_v2(&self, link_id: &str) -> Result<LinkDto, CanisterError> {
+        self.cashier_backend_client
+            .as_ref()
+            .unwrap()
+            .user_enable_link_v2(link_id)
+            .await
+            .unwrap()
+    }
+
+    /// Update link v2
+    /// # Arguments
+    /// * `input` - The metadata changes to apply
+    /// # Returns
+    /// * `LinkDto` - The updated link data
+    pub async fn update_link_v2(&self, input: UpdateLinkV2Input) -> Result<LinkDto, CanisterError> {
+        self.cashier_backend_client
+            .as_ref()
+            .unwrap()
+            .user_update_link_v2(input)
+            .await
+            .unwrap()
+    }
+
+    /// Get action receipt v2
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `ActionReceiptDto` - The receipt of the action
+    pub async fn get_action_receipt_v2(
+        &self,
+        action_id: &str,
+    ) -> Result<ActionReceiptDto, CanisterError> {
+        self.cashier_backend_client
+            .as_ref()
+            .unwrap()
+            .user_get_action_receipt_v2(action_id)
+            .await
+            .unwrap()
+    }
+
+    /// Get link actions v2
+    /// # Arguments
+    /// * `link_id` - The ID of the link
+    /// * `input` - Optional pagination parameters
+    /// # Returns
+    /// * `PaginateResult<LinkActionHistoryDto>` - The page of link actions
+    pub async fn get_link_actions_v2(
+        &self,
+        link_id: &str,
+        input: Option<PaginateInput>,
+    ) -> Result<PaginateResult<LinkActionHistoryDto>, CanisterError> {
+        self.cashier_backend_client
+            .as_ref()
+            .unwrap()
+            .user_get_link_actions_v2(link_id, input)
+            .await
+            .unwrap()
+    }
+
+    /// Simulate action v2
+    /// # Arguments
+    /// * `action_id` - The ID of the action
+    /// # Returns
+    /// * `SimulateActionDto` - The predicted outcome of processing the action
+    pub async fn simulate_action_v2(
+        &self,
+        action_id: &str,
+    ) -> Result<SimulateActionDto, CanisterError> {
+        self.cashier_backend_client
+            .as_ref()
+            .unwrap()
+            .user_simulate_action_v2(action_id)
+            .await
+            .unwrap()
+    }
+
     /// Create action v2
     /// # Arguments
     /// * `input` - The input data for creating the action
@@ -249,6 +333,7 @@ impl LinkTestFixtureV2 {
                             },
                             label: format!("{}_{}", label, self.ctx.icp_ledger_principal.to_text()),
                             amount_per_link_use_action: amount,
+                            token_ids: None,
                         })
                     } else {
                         Ok(LinkDetailUpdateAssetInfoInput {
@@ -257,6 +342,7 @@ impl LinkTestFixtureV2 {
                             },
                             label: label.to_string(),
                             amount_per_link_use_action: amount,
+                            token_ids: None,
                         })
                     }
                 }
@@ -269,6 +355,7 @@ impl LinkTestFixtureV2 {
                                 },
                                 label: format!("{}_{}", label, token_principal.to_text()),
                                 amount_per_link_use_action: amount,
+                                token_ids: None,
                             })
                         } else {
                             Ok(LinkDetailUpdateAssetInfoInput {
@@ -277,6 +364,7 @@ impl LinkTestFixtureV2 {
                                 },
                                 label: label.to_string(),
                                 amount_per_link_use_action: amount,
+                                token_ids: None,
                             })
                         }
                     }
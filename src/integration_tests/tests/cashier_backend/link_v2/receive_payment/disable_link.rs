@@ -30,7 +30,7 @@ async fn it_should_fail_disable_icp_token_payment_linkv2_if_link_not_active() {
         assert!(disable_link_result.is_err());
         if let Err(CanisterError::ValidationErrors(msg)) = disable_link_result {
             assert!(
-                msg.contains("Only active links can be disabled"),
+                msg.contains("Only active or scheduled links can be disabled"),
                 "Unexpected error message: {}",
                 msg
             );
@@ -10,7 +10,10 @@ use cashier_backend_types::{
     constant,
     dto::{action::CreateActionInput, link::CreateLinkInput},
     link_v2::dto::{CreateLinkDto, ProcessActionDto},
-    repository::{action::v1::ActionType, link::v1::LinkType},
+    repository::{
+        action::v1::ActionType,
+        link::v1::{AmountBounds, LinkType, SplitRecipient},
+    },
 };
 use std::sync::Arc;
 
@@ -18,6 +21,8 @@ pub struct PaymentLinkV2Fixture {
     pub caller: Principal,
     pub tokens: Vec<String>,
     pub amounts: Vec<Nat>,
+    pub split_recipients: Option<Vec<SplitRecipient>>,
+    pub amount_bounds: Option<AmountBounds>,
     pub link_fixture: LinkTestFixtureV2,
 }
 
@@ -34,6 +39,8 @@ impl PaymentLinkV2Fixture {
             caller,
             tokens,
             amounts,
+            split_recipients: None,
+            amount_bounds: None,
             link_fixture,
         }
     }
@@ -94,7 +101,18 @@ impl PaymentLinkV2Fixture {
             title: "Test Receive Payment Link".to_string(),
             link_use_action_max_count: 1,
             asset_info,
-            link_type: LinkType::ReceivePayment,
+            link_type: if self.split_recipients.is_some() {
+                LinkType::ReceiveSplitPayment
+            } else {
+                LinkType::ReceivePayment
+            },
+            expires_at: None,
+            gate: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            split_recipients: self.split_recipients.clone(),
+            amount_bounds: self.amount_bounds.clone(),
         })
     }
 
@@ -189,6 +207,10 @@ pub async fn send_payment_link_v2_fixture(
     let create_action_input = CreateActionInput {
         link_id: link_id.clone(),
         action_type: ActionType::Send,
+        amount: None,
+        top_up_use_count: None,
+        btc_address: None,
+        destination: None,
     };
     let create_action_result = caller_fixture.create_action_v2(create_action_input).await;
 
@@ -270,6 +270,10 @@ async fn it_should_succeed_get_payment_linkv2_details_with_send_action() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Send,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = caller_fixture.create_action_v2(create_action_input).await;
 
@@ -370,6 +374,10 @@ async fn it_should_succeed_get_payment_linkv2_details_with_withdraw_action() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture
             .link_fixture
@@ -8,4 +8,6 @@ pub mod fixture;
 pub mod get_link_details;
 pub mod get_links;
 pub mod send_link;
+pub mod split_payment;
+pub mod variable_amount;
 pub mod withdraw_link;
@@ -41,6 +41,10 @@ async fn it_should_fail_send_icp_token_payment_linkv2_if_link_not_active() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Send,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -91,6 +95,10 @@ async fn it_should_succeed_send_icp_token_payment_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Send,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = caller_fixture.create_action_v2(create_action_input).await;
 
@@ -250,6 +258,10 @@ async fn it_should_succeed_send_icrc_token_payment_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Send,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = caller_fixture.create_action_v2(create_action_input).await;
 
@@ -400,6 +412,10 @@ async fn it_should_return_error_when_create_send_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Send,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let first_result = caller_fixture
             .create_action_v2(create_action_input.clone())
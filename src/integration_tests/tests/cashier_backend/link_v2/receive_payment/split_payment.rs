@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::receive_payment::fixture::PaymentLinkV2Fixture;
+use crate::utils::icrc_112;
+use crate::utils::principal::TestUser;
+use crate::utils::{link_id_to_account::link_id_to_account, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::common::Wallet;
+use cashier_backend_types::repository::intent::v1::IntentTask;
+use cashier_backend_types::repository::link::v1::{LinkType, SplitRecipient};
+use cashier_common::test_utils;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_pay_out_split_payment_linkv2_to_recipients() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate a payment link split 70/30 between two recipients
+        let creator = TestUser::User1.get_principal();
+        let recipient1 = test_utils::random_principal_id();
+        let recipient2 = test_utils::random_principal_id();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            PaymentLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts).await;
+        creator_fixture.split_recipients = Some(vec![
+            SplitRecipient {
+                wallet: Wallet::new(recipient1),
+                weight_bps: 7_000,
+            },
+            SplitRecipient {
+                wallet: Wallet::new(recipient2),
+                weight_bps: 3_000,
+            },
+        ]);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert_eq!(
+            activate_link_result.link.link_type,
+            LinkType::ReceiveSplitPayment
+        );
+
+        let sender = TestUser::User2.get_principal();
+        let sender_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), sender).await;
+        creator_fixture.airdrop_icp_and_asset(sender).await;
+        let icp_ledger_client = ctx.new_icp_ledger_client(sender);
+
+        // Act: create and process the SEND action
+        let action = sender_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Send,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(action.intents.len(), 3);
+        assert_eq!(action.intents[0].task, IntentTask::TransferWalletToLink);
+        assert_eq!(action.intents[1].task, IntentTask::TransferLinkToWallet);
+        assert_eq!(action.intents[2].task, IntentTask::TransferLinkToWallet);
+
+        let icrc_112_requests = action.icrc_112_requests.unwrap();
+        let _icrc112_execution_result =
+            icrc_112::execute_icrc112_request(&icrc_112_requests, sender, &sender_fixture.ctx)
+                .await;
+        let process_action_result = sender_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+        let recipient1_balance = icp_ledger_client
+            .balance_of(&Wallet::new(recipient1).get_account().unwrap())
+            .await
+            .unwrap();
+        let recipient2_balance = icp_ledger_client
+            .balance_of(&Wallet::new(recipient2).get_account().unwrap())
+            .await
+            .unwrap();
+        assert_eq!(recipient1_balance, Nat::from(700_000u64));
+        assert_eq!(recipient2_balance, Nat::from(300_000u64));
+
+        let link_account = link_id_to_account(&sender_fixture.ctx, &link_id);
+        let link_balance = icp_ledger_client.balance_of(&link_account).await.unwrap();
+        assert_eq!(link_balance, Nat::from(0u64));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
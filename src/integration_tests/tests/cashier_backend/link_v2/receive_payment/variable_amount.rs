@@ -0,0 +1,135 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::receive_payment::fixture::PaymentLinkV2Fixture;
+use crate::utils::icrc_112;
+use crate::utils::principal::TestUser;
+use crate::utils::{link_id_to_account::link_id_to_account, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::link::v1::AmountBounds;
+use cashier_common::test_utils;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_fail_send_variable_amount_payment_linkv2_if_amount_out_of_bounds() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            PaymentLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts).await;
+        creator_fixture.amount_bounds = Some(AmountBounds {
+            min: Nat::from(100_000u64),
+            max: Nat::from(5_000_000u64),
+        });
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let sender = TestUser::User2.get_principal();
+        let sender_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), sender).await;
+
+        // Act
+        let missing_amount_result = sender_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Send,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+        let too_large_amount_result = sender_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Send,
+                amount: Some(Nat::from(5_000_001u64)),
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            missing_amount_result,
+            Err(CanisterError::InvalidInput(_))
+        ));
+        assert!(matches!(
+            too_large_amount_result,
+            Err(CanisterError::InvalidInput(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_succeed_send_variable_amount_payment_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            PaymentLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts).await;
+        creator_fixture.amount_bounds = Some(AmountBounds {
+            min: Nat::from(100_000u64),
+            max: Nat::from(5_000_000u64),
+        });
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let sender = TestUser::User2.get_principal();
+        let sender_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), sender).await;
+        creator_fixture.airdrop_icp_and_asset(sender).await;
+        let icp_ledger_client = ctx.new_icp_ledger_client(sender);
+        let ledger_fee = icp_ledger_client.fee().await.unwrap();
+        let chosen_amount = Nat::from(2_500_000u64);
+
+        // Act
+        let action = sender_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Send,
+                amount: Some(chosen_amount.clone()),
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        let icrc_112_requests = action.icrc_112_requests.unwrap();
+        let _icrc112_execution_result =
+            icrc_112::execute_icrc112_request(&icrc_112_requests, sender, &sender_fixture.ctx)
+                .await;
+        let process_action_result = sender_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+        let link_account = link_id_to_account(&sender_fixture.ctx, &link_id);
+        let link_balance = icp_ledger_client.balance_of(&link_account).await.unwrap();
+        assert_eq!(
+            link_balance,
+            test_utils::calculate_amount_for_wallet_to_link_transfer(chosen_amount, ledger_fee, 1)
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
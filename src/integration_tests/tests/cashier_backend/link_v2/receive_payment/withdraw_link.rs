@@ -32,6 +32,10 @@ async fn it_should_withdraw_icp_token_payment_linkv2_error_if_link_active() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture
             .link_fixture
@@ -99,6 +103,10 @@ async fn it_should_withdraw_icp_token_payment_linkv2_successfully() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture
             .link_fixture
@@ -225,6 +233,10 @@ async fn it_should_withdraw_icrc_token_payment_linkv2_successfully() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture
             .link_fixture
@@ -330,6 +342,10 @@ async fn it_should_error_when_create_withdraw_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let first = test_fixture
             .link_fixture
@@ -378,6 +394,10 @@ async fn it_should_error_when_non_creator_create_withdraw_action_payment_linkv2(
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = other_fixture.create_action_v2(create_action_input).await;
 
@@ -0,0 +1,130 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_airdrop::fixture::AirdropLinkV2Fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_fail_receive_airdrop_linkv2_if_caller_not_on_allow_list() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate an airdrop link restricted to User2
+        let creator = TestUser::User1.get_principal();
+        let allowed = TestUser::User2.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        creator_fixture.allow_list = Some(vec![allowed]);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert_eq!(activate_link_result.link.allow_list, Some(vec![allowed]));
+
+        let outsider = TestUser::User3.get_principal();
+        let outsider_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), outsider).await;
+        let allowed_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), allowed).await;
+
+        // Act
+        let outsider_result = outsider_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+        let allowed_result = allowed_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            outsider_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+        assert!(allowed_result.is_ok());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_receive_airdrop_linkv2_up_to_max_use_per_user() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate an airdrop link allowing two claims per user
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        creator_fixture.max_use_per_user = Some(2);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+
+        // Act: claim twice
+        for _ in 0..2 {
+            let action = receiver_fixture
+                .create_action_v2(CreateActionInput {
+                    link_id: link_id.clone(),
+                    action_type: ActionType::Receive,
+                    amount: None,
+                    top_up_use_count: None,
+                    btc_address: None,
+                    destination: None,
+                })
+                .await
+                .unwrap();
+            let process_action_result = receiver_fixture
+                .process_action_v2(ProcessActionV2Input {
+                    action_id: action.id,
+                })
+                .await
+                .unwrap();
+            assert_eq!(process_action_result.action.state, ActionState::Success);
+        }
+
+        // Act: claim a third time
+        let create_action_result = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            create_action_result,
+            Err(CanisterError::ValidationErrors(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
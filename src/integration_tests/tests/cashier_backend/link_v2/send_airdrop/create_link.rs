@@ -81,6 +81,10 @@ async fn it_should_error_when_create_createlink_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::CreateLink,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture
             .link_fixture
@@ -9,6 +9,7 @@ use cashier_backend_types::{
     link_v2::dto::{CreateLinkDto, ProcessActionDto},
     repository::link::v1::LinkType,
 };
+use gate_service_types::GateKey;
 use std::sync::Arc;
 
 pub struct AirdropLinkV2Fixture {
@@ -16,6 +17,12 @@ pub struct AirdropLinkV2Fixture {
     pub tokens: Vec<String>,
     pub amounts: Vec<Nat>,
     pub max_use_count: u64,
+    pub link_type: LinkType,
+    pub expires_at: Option<u64>,
+    pub gate: Option<GateKey>,
+    pub starts_at: Option<u64>,
+    pub max_use_per_user: Option<u64>,
+    pub allow_list: Option<Vec<Principal>>,
     pub link_fixture: LinkTestFixtureV2,
 }
 
@@ -34,6 +41,12 @@ impl AirdropLinkV2Fixture {
             tokens,
             amounts,
             max_use_count,
+            link_type: LinkType::SendAirdrop,
+            expires_at: None,
+            gate: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
             link_fixture,
         }
     }
@@ -93,7 +106,14 @@ impl AirdropLinkV2Fixture {
             title: "Test Airdrop Link".to_string(),
             link_use_action_max_count: self.max_use_count,
             asset_info,
-            link_type: LinkType::SendAirdrop,
+            link_type: self.link_type,
+            expires_at: self.expires_at,
+            gate: self.gate.clone(),
+            starts_at: self.starts_at,
+            max_use_per_user: self.max_use_per_user,
+            allow_list: self.allow_list.clone(),
+            split_recipients: None,
+            amount_bounds: None,
         })
     }
 
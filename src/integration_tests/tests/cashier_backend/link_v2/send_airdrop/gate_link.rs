@@ -0,0 +1,102 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_airdrop::fixture::AirdropLinkV2Fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::repository::action::v1::ActionType;
+use gate_service_types::GateKey;
+use std::sync::Arc;
+
+const GATE_PASSWORD: &str = "open-sesame";
+
+#[tokio::test]
+async fn it_should_fail_receive_gated_airdrop_linkv2_if_gate_not_opened() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate a password gated airdrop link
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        creator_fixture.gate = Some(GateKey::Password(GATE_PASSWORD.to_string()));
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert!(activate_link_result.link.gate_id.is_some());
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+
+        // Act: create RECEIVE action without opening the gate
+        let create_action_result = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            create_action_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_succeed_receive_gated_airdrop_linkv2_after_gate_opened() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate a password gated airdrop link
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        creator_fixture.gate = Some(GateKey::Password(GATE_PASSWORD.to_string()));
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        let gate_id = activate_link_result.link.gate_id.clone().unwrap();
+
+        // Arrange: receiver opens the gate with the password
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+        let open_gate_result = ctx
+            .new_gate_service_client(receiver)
+            .open_gate(gate_id, GateKey::Password(GATE_PASSWORD.to_string()))
+            .await
+            .unwrap();
+        assert!(open_gate_result.is_ok());
+
+        // Act: create RECEIVE action
+        let create_action_result = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(create_action_result.is_ok());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
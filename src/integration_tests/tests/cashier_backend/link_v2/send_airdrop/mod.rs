@@ -2,10 +2,16 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 pub mod activate_link;
+pub mod claim_limit;
 pub mod create_link;
 pub mod disable_link;
+pub mod expire_link;
 pub mod fixture;
+pub mod gate_link;
 pub mod get_link_details;
 pub mod get_links;
+pub mod random_link;
 pub mod receive_link;
+pub mod schedule_link;
+pub mod top_up_link;
 pub mod withdraw_link;
@@ -0,0 +1,176 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_airdrop::fixture::AirdropLinkV2Fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::link::v1::{LinkState, LinkType};
+use cashier_common::constant::CLAIM_RESERVATION_TTL_NS;
+use cashier_common::test_utils;
+use icrc_ledger_types::icrc1::account::Account;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[tokio::test]
+async fn it_should_share_the_whole_pot_of_random_airdrop_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate a random airdrop link with a pot of 3 * 1_000_000
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let max_use = 3;
+        let mut creator_fixture = AirdropLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            creator,
+            tokens,
+            amounts.clone(),
+            max_use,
+        )
+        .await;
+        creator_fixture.link_type = LinkType::SendRandomAirdrop;
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert_eq!(activate_link_result.link.state, LinkState::Active);
+
+        let pot = amounts[0].clone() * max_use;
+        let mut total_received = Nat::from(0u64);
+        let mut link_state = activate_link_result.link.state;
+
+        // Act: every claim is made by a different principal
+        for _ in 0..max_use {
+            let receiver = test_utils::random_principal_id();
+            let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+            let icp_ledger_client = ctx.new_icp_ledger_client(receiver);
+            let receiver_account = Account {
+                owner: receiver,
+                subaccount: None,
+            };
+
+            let action = receiver_fixture
+                .create_action_v2(CreateActionInput {
+                    link_id: link_id.clone(),
+                    action_type: ActionType::Receive,
+                    amount: None,
+                    top_up_use_count: None,
+                    btc_address: None,
+                    destination: None,
+                })
+                .await
+                .unwrap();
+            let process_action_result = receiver_fixture
+                .process_action_v2(ProcessActionV2Input {
+                    action_id: action.id,
+                })
+                .await
+                .unwrap();
+            assert_eq!(process_action_result.action.state, ActionState::Success);
+            link_state = process_action_result.link.state;
+
+            let received = icp_ledger_client
+                .balance_of(&receiver_account)
+                .await
+                .unwrap();
+            assert!(received > 0u64, "Every claimer should receive a share");
+            total_received += received;
+        }
+
+        // Assert
+        assert_eq!(total_received, pot, "Shares should add up to the pot");
+        assert_eq!(link_state, LinkState::InactiveEnded);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_release_the_share_of_an_unprocessed_random_claim() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate a random airdrop link with a single share
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let max_use = 1;
+        let mut creator_fixture = AirdropLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            creator,
+            tokens,
+            amounts.clone(),
+            max_use,
+        )
+        .await;
+        creator_fixture.link_type = LinkType::SendRandomAirdrop;
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        let create_receive_input = CreateActionInput {
+            link_id: link_id.clone(),
+            action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
+        };
+
+        // the first claimer reserves the share and never processes its claim
+        let idle_fixture =
+            LinkTestFixtureV2::new(Arc::new(ctx.clone()), test_utils::random_principal_id()).await;
+        let idle_action = idle_fixture
+            .create_action_v2(create_receive_input.clone())
+            .await
+            .unwrap();
+
+        let receiver = test_utils::random_principal_id();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+        let blocked = receiver_fixture
+            .create_action_v2(create_receive_input.clone())
+            .await;
+        assert!(blocked.is_err(), "The only share should be reserved");
+
+        // Act
+        ctx.advance_time(Duration::from_nanos(CLAIM_RESERVATION_TTL_NS) + Duration::from_secs(1))
+            .await;
+        let action = receiver_fixture
+            .create_action_v2(create_receive_input)
+            .await
+            .unwrap();
+        let process_action_result = receiver_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+        let received = ctx
+            .new_icp_ledger_client(receiver)
+            .balance_of(&Account {
+                owner: receiver,
+                subaccount: None,
+            })
+            .await
+            .unwrap();
+        assert_eq!(received, amounts[0]);
+
+        let expired_claim = idle_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: idle_action.id,
+            })
+            .await;
+        assert!(
+            expired_claim.is_err(),
+            "An expired claim should not be paid"
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
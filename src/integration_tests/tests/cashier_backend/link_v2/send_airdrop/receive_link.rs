@@ -41,6 +41,10 @@ async fn it_should_fail_receive_icp_token_airdrop_linkv2_if_link_not_active() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -80,6 +84,10 @@ async fn it_should_fail_receive_icp_token_airdrop_linkv2_if_requested_more_than_
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -98,6 +106,10 @@ async fn it_should_fail_receive_icp_token_airdrop_linkv2_if_requested_more_than_
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = other_fixture.create_action_v2(create_action_input).await;
 
@@ -146,6 +158,10 @@ async fn it_should_succeed_receive_icp_token_airdrop_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -280,6 +296,10 @@ async fn it_should_succeed_receive_icrc_token_airdrop_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -402,6 +422,10 @@ async fn it_should_error_when_create_receive_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let first = receiver_fixture
             .create_action_v2(create_action_input.clone())
@@ -0,0 +1,196 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_airdrop::fixture::AirdropLinkV2Fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::link::v1::LinkState;
+use std::{sync::Arc, time::Duration};
+
+const START_DELAY: Duration = Duration::from_secs(3600);
+
+#[tokio::test]
+async fn it_should_schedule_airdrop_linkv2_until_start_time() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        let now = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+        creator_fixture.starts_at = Some(now + START_DELAY.as_nanos() as u64);
+
+        // Act: fund the link before its start time
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        // Assert: the link is funded but not open yet
+        assert!(activate_link_result.is_success);
+        assert_eq!(activate_link_result.link.state, LinkState::Scheduled);
+        let link_details = creator_fixture
+            .link_fixture
+            .get_link_details_v2(&link_id, None)
+            .await
+            .unwrap();
+        assert_eq!(link_details.link.state, LinkState::Scheduled);
+
+        // Assert: RECEIVE action is rejected
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+        let create_action_result = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+        if let Err(CanisterError::ValidationErrors(msg)) = create_action_result {
+            assert_eq!(msg, "Link is not open yet", "Error message mismatch");
+        } else {
+            panic!("Expected ValidationErrors error");
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_open_scheduled_airdrop_linkv2_at_start_time() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        let now = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+        creator_fixture.starts_at = Some(now + START_DELAY.as_nanos() as u64);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        // Act: reach the start time
+        ctx.advance_time(START_DELAY + Duration::from_secs(1)).await;
+
+        // Assert: the link is open and can be received
+        let link_details = creator_fixture
+            .link_fixture
+            .get_link_details_v2(&link_id, None)
+            .await
+            .unwrap();
+        assert_eq!(link_details.link.state, LinkState::Active);
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), receiver).await;
+        let create_action_result = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+        assert!(create_action_result.is_ok());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_withdraw_scheduled_airdrop_linkv2_before_start_time() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: fund a link opening in one hour
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        let now = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+        creator_fixture.starts_at = Some(now + START_DELAY.as_nanos() as u64);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert_eq!(activate_link_result.link.state, LinkState::Scheduled);
+
+        // Act: the creator cancels the drop before it opens
+        let create_action_result = creator_fixture
+            .link_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Withdraw,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        let process_action_result = creator_fixture
+            .link_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: create_action_result.id,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(process_action_result.is_success);
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+        assert_eq!(process_action_result.link.state, LinkState::InactiveEnded);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_disable_and_reenable_scheduled_airdrop_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 10).await;
+        let now = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+        creator_fixture.starts_at = Some(now + START_DELAY.as_nanos() as u64);
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        // Act
+        let disable_link_result = creator_fixture
+            .link_fixture
+            .disable_link_v2(&link_id)
+            .await
+            .unwrap();
+        let enable_link_result = creator_fixture
+            .link_fixture
+            .enable_link_v2(&link_id)
+            .await
+            .unwrap();
+
+        // Assert: the re-enabled link still waits for its start time
+        assert_eq!(disable_link_result.state, LinkState::Inactive);
+        assert_eq!(enable_link_result.state, LinkState::Scheduled);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,184 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_airdrop::fixture::AirdropLinkV2Fixture;
+use crate::utils::icrc_112;
+use crate::utils::principal::TestUser;
+use crate::utils::{PocketIcTestContext, with_pocket_ic_context};
+use candid::{Nat, Principal};
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::{ProcessActionDto, ProcessActionV2Input};
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::link::v1::LinkState;
+use std::sync::Arc;
+
+async fn top_up_link(
+    creator: Principal,
+    creator_fixture: &AirdropLinkV2Fixture,
+    link_id: &str,
+    top_up_use_count: u64,
+) -> ProcessActionDto {
+    let top_up_action = creator_fixture
+        .link_fixture
+        .create_action_v2(CreateActionInput {
+            link_id: link_id.to_string(),
+            action_type: ActionType::TopUp,
+            amount: None,
+            top_up_use_count: Some(top_up_use_count),
+            btc_address: None,
+            destination: None,
+        })
+        .await
+        .unwrap();
+    let icrc_112_requests = top_up_action.icrc_112_requests.unwrap();
+    let _icrc112_execution_result = icrc_112::execute_icrc112_request(
+        &icrc_112_requests,
+        creator,
+        &creator_fixture.link_fixture.ctx,
+    )
+    .await;
+    creator_fixture
+        .link_fixture
+        .process_action_v2(ProcessActionV2Input {
+            action_id: top_up_action.id,
+        })
+        .await
+        .unwrap()
+}
+
+async fn receive_link(
+    ctx: &PocketIcTestContext,
+    user: TestUser,
+    link_id: &str,
+) -> ProcessActionDto {
+    let receiver_fixture =
+        LinkTestFixtureV2::new(Arc::new(ctx.clone()), user.get_principal()).await;
+    let action = receiver_fixture
+        .create_action_v2(CreateActionInput {
+            link_id: link_id.to_string(),
+            action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
+        })
+        .await
+        .unwrap();
+    receiver_fixture
+        .process_action_v2(ProcessActionV2Input {
+            action_id: action.id,
+        })
+        .await
+        .unwrap()
+}
+
+#[tokio::test]
+async fn it_should_fail_top_up_airdrop_linkv2_if_caller_not_creator() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 1).await;
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let other = TestUser::User2.get_principal();
+        let other_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), other).await;
+
+        // Act
+        let create_action_result = other_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::TopUp,
+                amount: None,
+                top_up_use_count: Some(1),
+                btc_address: None,
+                destination: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            create_action_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_top_up_airdrop_linkv2_and_allow_more_receives() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate an airdrop link with a single use
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 1).await;
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        assert_eq!(activate_link_result.link.link_use_action_max_count, 1);
+
+        // Act: top up one more use
+        let top_up_result = top_up_link(creator, &creator_fixture, &link_id, 1).await;
+
+        // Assert
+        assert_eq!(top_up_result.action.state, ActionState::Success);
+        assert_eq!(top_up_result.link.link_use_action_max_count, 2);
+        assert_eq!(top_up_result.link.state, LinkState::Active);
+
+        // Act: two different users receive
+        for user in [TestUser::User2, TestUser::User3] {
+            let process_action_result = receive_link(ctx, user, &link_id).await;
+
+            // Assert
+            assert_eq!(process_action_result.action.state, ActionState::Success);
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_top_up_an_ended_airdrop_linkv2_twice() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: end an airdrop link by receiving its single use
+        let creator = TestUser::User1.get_principal();
+        let tokens = vec![ICP_TOKEN.to_string()];
+        let amounts = vec![Nat::from(1_000_000u64)];
+        let mut creator_fixture =
+            AirdropLinkV2Fixture::new(Arc::new(ctx.clone()), creator, tokens, amounts, 1).await;
+        let activate_link_result = creator_fixture.activate_link().await;
+        let link_id = activate_link_result.link.id.clone();
+        let receive_result = receive_link(ctx, TestUser::User2, &link_id).await;
+        assert_eq!(receive_result.link.state, LinkState::InactiveEnded);
+
+        // Act
+        let first_top_up_result = top_up_link(creator, &creator_fixture, &link_id, 1).await;
+        let second_top_up_result = top_up_link(creator, &creator_fixture, &link_id, 2).await;
+
+        // Assert
+        assert_eq!(first_top_up_result.action.state, ActionState::Success);
+        assert_eq!(first_top_up_result.link.state, LinkState::Active);
+        assert_eq!(second_top_up_result.action.state, ActionState::Success);
+        assert_eq!(second_top_up_result.link.link_use_action_max_count, 4);
+
+        let receive_result = receive_link(ctx, TestUser::User3, &link_id).await;
+        assert_eq!(receive_result.action.state, ActionState::Success);
+        assert_eq!(receive_result.link.state, LinkState::Active);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
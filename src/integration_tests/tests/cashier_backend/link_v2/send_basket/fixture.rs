@@ -106,6 +106,13 @@ impl BasketLinkV2Fixture {
             link_use_action_max_count: 1,
             asset_info,
             link_type: LinkType::SendTokenBasket,
+            expires_at: None,
+            gate: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            split_recipients: None,
+            amount_bounds: None,
         })
     }
 
@@ -374,6 +374,10 @@ async fn it_should_succeed_get_linkv2_details_with_receive_action() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -451,6 +455,10 @@ async fn it_should_succeed_get_linkv2_details_with_withdraw_action() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture.create_action_v2(create_action_input).await;
 
@@ -40,6 +40,10 @@ async fn it_should_fail_receive_icp_token_basket_linkv2_if_link_not_active() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -78,6 +82,10 @@ async fn it_should_fail_receive_icp_token_basket_linkv2_if_requested_more_than_o
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -96,6 +104,10 @@ async fn it_should_fail_receive_icp_token_basket_linkv2_if_requested_more_than_o
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = other_fixture.create_action_v2(create_action_input).await;
 
@@ -142,6 +154,10 @@ async fn it_should_succeed_receive_icp_token_basket_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -270,6 +286,10 @@ async fn it_should_succeed_receive_icrc_token_basket_linkv2() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = receiver_fixture.create_action_v2(create_action_input).await;
 
@@ -387,6 +407,10 @@ async fn it_should_error_when_create_receive_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let first = receiver_fixture
             .create_action_v2(create_action_input.clone())
@@ -38,6 +38,10 @@ async fn it_should_error_when_non_creator_create_withdraw_action_basket_linkv2()
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = other_fixture.create_action_v2(create_action_input).await;
 
@@ -95,6 +99,10 @@ async fn it_should_withdraw_icp_token_basket_linkv2_successfully() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture.create_action_v2(create_action_input).await;
 
@@ -215,6 +223,10 @@ async fn it_should_withdraw_icrc_token_basket_linkv2_successfully() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let create_action_result = test_fixture.create_action_v2(create_action_input).await;
 
@@ -314,6 +326,10 @@ async fn it_should_error_when_create_withdraw_action_twice() {
         let create_action_input = CreateActionInput {
             link_id: link_id.clone(),
             action_type: ActionType::Withdraw,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let first = test_fixture
             .create_action_v2(create_action_input.clone())
@@ -0,0 +1,121 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::{link_id_to_account::link_id_to_account, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::transaction::v1::TransactionState;
+use icrc_ledger_types::icrc1::account::Account;
+
+#[tokio::test]
+async fn it_should_return_receipt_with_block_index_for_received_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: receive from an active tip link
+        let tip_amount = Nat::from(1_000_000u64);
+        let (creator_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount.clone()).await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), receiver).await;
+        let action = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        let process_action_result = receiver_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id.clone(),
+            })
+            .await
+            .unwrap();
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+
+        // Act
+        let receiver_receipt = receiver_fixture
+            .get_action_receipt_v2(&action.id)
+            .await
+            .unwrap();
+        let creator_receipt = creator_fixture.get_action_receipt_v2(&action.id).await;
+
+        // Assert
+        assert_eq!(receiver_receipt.action_id, action.id);
+        assert_eq!(receiver_receipt.link_id, link_id);
+        assert_eq!(receiver_receipt.state, ActionState::Success);
+        assert_eq!(receiver_receipt.transfers.len(), 1);
+        let transfer = &receiver_receipt.transfers[0];
+        assert_eq!(transfer.state, TransactionState::Success);
+        assert!(transfer.block_index.is_some());
+        assert_eq!(transfer.amount, tip_amount);
+        assert_eq!(
+            transfer.from,
+            link_id_to_account(&receiver_fixture.ctx, &link_id)
+        );
+        assert_eq!(
+            transfer.to,
+            Some(Account {
+                owner: receiver,
+                subaccount: None,
+            })
+        );
+        assert!(creator_receipt.is_ok());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_get_receipt_of_other_users_action_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (creator_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), receiver).await;
+        let action = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+
+        let other = TestUser::User3.get_principal();
+        let other_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), other).await;
+
+        // Act
+        let receipt_result = other_fixture.get_action_receipt_v2(&action.id).await;
+
+        // Assert
+        assert!(matches!(
+            receipt_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,192 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::TipLinkV2Fixture;
+use crate::utils::{icrc_112, principal::TestUser, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::CreateLinkBatchInput;
+use cashier_backend_types::repository::action::v1::ActionState;
+use cashier_backend_types::repository::link::v1::LinkState;
+use cashier_common::constant::MAX_LINK_BATCH_SIZE;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_create_and_fund_batch_of_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let input = CreateLinkBatchInput {
+            link: test_fixture.tip_link_input().unwrap(),
+            link_count: 3,
+        };
+
+        // Act
+        let batch = be_client
+            .user_create_link_batch_v2(input)
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert: one deposit per link and a single fee approve
+        assert_eq!(batch.link_ids.len(), 3);
+        let requests = batch.icrc_112_requests.iter().flatten();
+        let (approves, transfers): (Vec<_>, Vec<_>) =
+            requests.partition(|req| req.method == "icrc2_approve");
+        assert_eq!(approves.len(), 1);
+        assert_eq!(transfers.len(), 3);
+
+        // Act: fund every link at once with the single action of the batch
+        icrc_112::execute_icrc112_request(&batch.icrc_112_requests, caller, ctx)
+            .await
+            .unwrap();
+        let activate_result = test_fixture
+            .link_fixture
+            .activate_link_v2(&batch.action_id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(activate_result.action.state, ActionState::Success);
+        assert_eq!(activate_result.link.id, batch.link_ids[0]);
+        assert_eq!(
+            activate_result.link.batch_link_ids.as_deref(),
+            batch.link_ids.get(1..)
+        );
+        for link_id in batch.link_ids.iter() {
+            let link = be_client
+                .get_link_details_v2(link_id, None)
+                .await
+                .unwrap()
+                .unwrap()
+                .link;
+            assert_eq!(link.state, LinkState::Active);
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_error_create_link_batch_with_no_link() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let input = CreateLinkBatchInput {
+            link: test_fixture.tip_link_input().unwrap(),
+            link_count: 0,
+        };
+
+        // Act
+        let result = be_client.user_create_link_batch_v2(input).await.unwrap();
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_create_and_fund_batch_of_tip_linkv2_at_the_max_size() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let input = CreateLinkBatchInput {
+            link: test_fixture.tip_link_input().unwrap(),
+            link_count: MAX_LINK_BATCH_SIZE,
+        };
+
+        // Act
+        let batch = be_client
+            .user_create_link_batch_v2(input)
+            .await
+            .unwrap()
+            .unwrap();
+        icrc_112::execute_icrc112_request(&batch.icrc_112_requests, caller, ctx)
+            .await
+            .unwrap();
+        let activate_result = test_fixture
+            .link_fixture
+            .activate_link_v2(&batch.action_id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(batch.link_ids.len() as u64, MAX_LINK_BATCH_SIZE);
+        assert_eq!(activate_result.action.state, ActionState::Success);
+        let last_link_id = batch.link_ids.last().unwrap();
+        let last_link = be_client
+            .get_link_details_v2(last_link_id, None)
+            .await
+            .unwrap()
+            .unwrap()
+            .link;
+        assert_eq!(last_link.state, LinkState::Active);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_error_create_link_batch_over_the_max_size() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let input = CreateLinkBatchInput {
+            link: test_fixture.tip_link_input().unwrap(),
+            link_count: MAX_LINK_BATCH_SIZE + 1,
+        };
+
+        // Act
+        let result = be_client.user_create_link_batch_v2(input).await.unwrap();
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
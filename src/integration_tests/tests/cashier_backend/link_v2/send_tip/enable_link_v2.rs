@@ -0,0 +1,113 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::link::v1::LinkState;
+
+#[tokio::test]
+async fn it_should_fail_enable_icp_token_tip_linkv2_if_link_active() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (test_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+
+        // Act
+        let link_id = activate_link_result.link.id.clone();
+        let enable_link_result = test_fixture.enable_link_v2(&link_id).await;
+
+        // Assert
+        assert!(matches!(
+            enable_link_result,
+            Err(CanisterError::ValidationErrors(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_enable_icp_token_tip_linkv2_if_caller_is_not_creator() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (test_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+        let link_id = activate_link_result.link.id.clone();
+        test_fixture.disable_link_v2(&link_id).await.unwrap();
+
+        let caller = TestUser::User2.get_principal();
+        let caller_fixture = LinkTestFixtureV2::new(test_fixture.ctx.clone(), caller).await;
+
+        // Act
+        let enable_link_result = caller_fixture.enable_link_v2(&link_id).await;
+
+        // Assert
+        assert!(matches!(
+            enable_link_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_succeed_enable_icp_token_tip_linkv2_and_receive() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: activate then disable the tip link
+        let tip_amount = Nat::from(1_000_000u64);
+        let (test_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+        let link_id = activate_link_result.link.id.clone();
+        test_fixture.disable_link_v2(&link_id).await.unwrap();
+
+        // Act
+        let enable_link_result = test_fixture.enable_link_v2(&link_id).await;
+
+        // Assert
+        let link_dto = enable_link_result.unwrap();
+        assert_eq!(link_dto.state, LinkState::Active);
+
+        // Act: a user receives from the re-enabled link
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(test_fixture.ctx.clone(), receiver).await;
+        let action = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id,
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        let process_action_result = receiver_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id,
+            })
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,105 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::TipLinkV2Fixture;
+use crate::utils::{principal::TestUser, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant;
+use cashier_backend_types::repository::link::v1::LinkType;
+use cashier_common::constant::{CREATE_LINK_FEE, ICP_CANISTER_PRINCIPAL};
+use cashier_common::test_utils;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_estimate_icp_tip_linkv2_cost() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let tip_amount = Nat::from(1_000_000u64);
+        let test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            tip_amount.clone(),
+        )
+        .await;
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let icp_ledger_fee = ctx.new_icp_ledger_client(caller).fee().await.unwrap();
+
+        // Act: estimate before the ICP fee is cached
+        let cold_estimate = be_client
+            .estimate_create_link(test_fixture.tip_link_input().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        assert_eq!(cold_estimate.link_type, LinkType::SendTip);
+        assert_eq!(cold_estimate.tokens.len(), 1);
+        let cold_icp = &cold_estimate.tokens[0];
+        assert_eq!(cold_icp.token_id, ICP_CANISTER_PRINCIPAL);
+        assert!(!cold_icp.is_ledger_fee_cached);
+        assert_eq!(cold_icp.funding_amount, tip_amount);
+        assert_eq!(cold_icp.service_fee_amount, Nat::from(CREATE_LINK_FEE));
+
+        // Act: creating a link caches the ICP fee
+        test_fixture.create_link().await;
+        let estimate = be_client
+            .estimate_create_link(test_fixture.tip_link_input().unwrap())
+            .await
+            .unwrap()
+            .unwrap();
+
+        // Assert
+        let icp = &estimate.tokens[0];
+        assert!(icp.is_ledger_fee_cached);
+        assert_eq!(icp.funding_amount, tip_amount);
+        // one claim, the deposit, the approve and the fee transfer
+        assert_eq!(icp.ledger_fee_amount, icp_ledger_fee.clone() * 4u64);
+        assert_eq!(
+            icp.approve_amount,
+            Some(Nat::from(
+                test_utils::calculate_approval_amount_for_create_link(&icp_ledger_fee)
+            ))
+        );
+        assert_eq!(
+            icp.total_amount,
+            tip_amount + icp_ledger_fee * 4u64 + Nat::from(CREATE_LINK_FEE)
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_error_estimate_invalid_link() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        let mut input = test_fixture.tip_link_input().unwrap();
+        input.expires_at = Some(0);
+
+        // Act
+        let result = ctx
+            .new_cashier_backend_client(caller)
+            .estimate_create_link(input)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,89 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::{principal::TestUser, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::CKBTC_ICRC_TOKEN;
+use cashier_backend_types::dto::fee_ledger::{FeeLedgerInput, FeeRevenueInput, RevenuePeriod};
+use cashier_backend_types::repository::link::v1::LinkType;
+use cashier_common::constant::{CREATE_LINK_FEE, ICP_CANISTER_PRINCIPAL};
+
+#[tokio::test]
+async fn it_should_record_create_link_fee_in_fee_ledger() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let start_at = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+
+        // Act
+        let (_, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, CKBTC_ICRC_TOKEN, Nat::from(50_000u64)).await;
+
+        // Assert
+        let end_at = ctx.client.get_time().await.as_nanos_since_unix_epoch() + 1;
+        let admin_client =
+            ctx.new_cashier_backend_client(TestUser::CashierBackendAdmin.get_principal());
+        let entries = admin_client
+            .admin_fee_ledger_get(FeeLedgerInput {
+                start_at,
+                end_at,
+                paginate: None,
+            })
+            .await
+            .unwrap()
+            .unwrap()
+            .data;
+        assert_eq!(entries.len(), 1);
+        let entry = &entries[0];
+        assert_eq!(entry.link_id, activate_link_result.link.id);
+        assert_eq!(entry.action_id, activate_link_result.action.id);
+        assert_eq!(entry.link_type, LinkType::SendTip);
+        assert_eq!(entry.payer, TestUser::User1.get_principal());
+        assert_eq!(entry.token_id, ICP_CANISTER_PRINCIPAL);
+        assert_eq!(entry.amount, Nat::from(CREATE_LINK_FEE));
+
+        let revenue = admin_client
+            .admin_fee_revenue_get(FeeRevenueInput {
+                start_at,
+                end_at,
+                period: RevenuePeriod::Day,
+            })
+            .await
+            .unwrap()
+            .unwrap();
+        let tokens = revenue.iter().flat_map(|period| period.tokens.iter());
+        let icp_revenue: Nat = tokens
+            .filter(|token| token.token_id == ICP_CANISTER_PRINCIPAL)
+            .map(|token| token.amount.clone())
+            .fold(Nat::from(0u64), |total, amount| total + amount);
+        assert_eq!(icp_revenue, Nat::from(CREATE_LINK_FEE));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_not_allow_user_to_get_fee_revenue() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let user_client = ctx.new_cashier_backend_client(TestUser::User1.get_principal());
+
+        // Act
+        let result = user_client
+            .admin_fee_revenue_get(FeeRevenueInput {
+                start_at: 0,
+                end_at: u64::MAX,
+                period: RevenuePeriod::Month,
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -88,6 +88,13 @@ impl TipLinkV2Fixture {
             link_use_action_max_count: 1,
             asset_info,
             link_type: LinkType::SendTip,
+            expires_at: None,
+            gate: None,
+            starts_at: None,
+            max_use_per_user: None,
+            allow_list: None,
+            split_recipients: None,
+            amount_bounds: None,
         })
     }
 
@@ -0,0 +1,91 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+
+#[tokio::test]
+async fn it_should_list_receive_actions_of_tip_linkv2_for_creator() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: receive from an active tip link
+        let tip_amount = Nat::from(1_000_000u64);
+        let (creator_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount.clone()).await;
+        let link_id = activate_link_result.link.id.clone();
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), receiver).await;
+        let action = receiver_fixture
+            .create_action_v2(CreateActionInput {
+                link_id: link_id.clone(),
+                action_type: ActionType::Receive,
+                amount: None,
+                top_up_use_count: None,
+                btc_address: None,
+                destination: None,
+            })
+            .await
+            .unwrap();
+        receiver_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        // Act
+        let link_actions = creator_fixture
+            .get_link_actions_v2(&link_id, None)
+            .await
+            .unwrap();
+
+        // Assert: the create link action is not listed
+        assert_eq!(link_actions.metadata.total, 1);
+        assert_eq!(link_actions.data.len(), 1);
+        let link_action = &link_actions.data[0];
+        assert_eq!(link_action.action_id, action.id);
+        assert_eq!(link_action.action_type, ActionType::Receive);
+        assert_eq!(link_action.state, ActionState::Success);
+        assert_eq!(link_action.user_id, receiver);
+        assert!(link_action.processed_at.is_some());
+        assert_eq!(link_action.transfers.len(), 1);
+        assert_eq!(link_action.transfers[0].amount, tip_amount);
+        assert!(link_action.transfers[0].block_index.is_some());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_list_actions_of_other_users_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let (creator_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, Nat::from(1_000_000u64)).await;
+        let other_fixture =
+            LinkTestFixtureV2::new(creator_fixture.ctx.clone(), TestUser::User2.get_principal())
+                .await;
+
+        // Act
+        let result = other_fixture
+            .get_link_actions_v2(&activate_link_result.link.id, None)
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::Unauthorized(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
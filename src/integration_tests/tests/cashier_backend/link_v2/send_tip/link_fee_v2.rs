@@ -0,0 +1,162 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::TipLinkV2Fixture;
+use crate::utils::{PocketIcTestContext, principal::TestUser, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::CKBTC_ICRC_TOKEN;
+use cashier_backend_types::init::{LinkCreationFee, LinkFeeSettings, LinkTypeFees};
+use cashier_backend_types::repository::common::Wallet;
+use cashier_backend_types::repository::intent::v1::{IntentTask, IntentType};
+use cashier_backend_types::repository::link::v1::LinkType;
+use cashier_common::constant::ICP_CANISTER_PRINCIPAL;
+use icrc_ledger_types::icrc1::account::Account;
+use std::sync::Arc;
+
+/// The treasury account configured by the tests
+fn partner_treasury() -> Account {
+    Account {
+        owner: TestUser::User3.get_principal(),
+        subaccount: Some([7u8; 32]),
+    }
+}
+
+/// Sets the link creation fee schedule as the canister admin.
+async fn set_link_fee(ctx: &PocketIcTestContext, link_fee: LinkFeeSettings) {
+    let admin_client =
+        ctx.new_cashier_backend_client(TestUser::CashierBackendAdmin.get_principal());
+    admin_client
+        .admin_link_fee_set(link_fee)
+        .await
+        .unwrap()
+        .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_charge_configured_link_type_fee_to_treasury() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: tip links pay their fee in ckBTC when they hold ckBTC
+        let ckbtc = ctx.get_icrc_token_principal(CKBTC_ICRC_TOKEN).unwrap();
+        let ckbtc_fee = LinkCreationFee {
+            token_id: ckbtc,
+            amount: 1_000,
+        };
+        set_link_fee(
+            ctx,
+            LinkFeeSettings {
+                treasury: partner_treasury(),
+                link_type_fees: vec![LinkTypeFees {
+                    link_type: LinkType::SendTip,
+                    fees: vec![
+                        LinkCreationFee {
+                            token_id: ICP_CANISTER_PRINCIPAL,
+                            amount: 20_000,
+                        },
+                        ckbtc_fee,
+                    ],
+                }],
+                ..LinkFeeSettings::default()
+            },
+        )
+        .await;
+
+        let caller = TestUser::User1.get_principal();
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            CKBTC_ICRC_TOKEN,
+            Nat::from(50_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+
+        // Act
+        let create_link_result = test_fixture.create_link().await;
+
+        // Assert
+        let fee_intent = create_link_result
+            .action
+            .intents
+            .iter()
+            .find(|intent| intent.task == IntentTask::TransferWalletToTreasury)
+            .expect("Create link fee intent not found");
+        match fee_intent.r#type {
+            IntentType::TransferFrom(ref transfer_from) => {
+                assert_eq!(transfer_from.asset.address(), ckbtc);
+                assert_eq!(transfer_from.amount, Nat::from(ckbtc_fee.amount));
+                assert_eq!(transfer_from.to, Wallet::from(partner_treasury()));
+            }
+            _ => panic!("Expected TransferFrom intent type"),
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_not_charge_fee_free_creator() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        set_link_fee(
+            ctx,
+            LinkFeeSettings {
+                fee_free_creators: vec![caller],
+                ..LinkFeeSettings::default()
+            },
+        )
+        .await;
+
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            CKBTC_ICRC_TOKEN,
+            Nat::from(50_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+
+        // Act
+        let create_link_result = test_fixture.create_link().await;
+
+        // Assert
+        assert!(
+            create_link_result
+                .action
+                .intents
+                .iter()
+                .all(|intent| intent.task != IntentTask::TransferWalletToTreasury)
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_not_allow_user_to_set_link_fee() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let user_client = ctx.new_cashier_backend_client(TestUser::User1.get_principal());
+
+        // Act
+        let result = user_client
+            .admin_link_fee_set(LinkFeeSettings {
+                fee_free_creators: vec![TestUser::User1.get_principal()],
+                ..LinkFeeSettings::default()
+            })
+            .await;
+
+        // Assert
+        assert!(result.is_err());
+        let link_fee = user_client.link_fee_get().await.unwrap();
+        assert_eq!(link_fee, LinkFeeSettings::default());
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -1,14 +1,27 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+pub mod action_receipt_v2;
 pub mod activate_icp_link_retry;
 pub mod activate_icrc_link_retry;
 pub mod activate_link;
 pub mod activate_link_deduplication;
 pub mod create_link;
+pub mod create_link_batch_v2;
 pub mod disable_link_v2;
+pub mod enable_link_v2;
+pub mod estimate_create_link_v2;
+pub mod fee_ledger_v2;
 pub mod fixture;
+pub mod get_link_actions_v2;
 pub mod get_link_details_v2;
 pub mod get_links_v2;
+pub mod link_fee_v2;
+pub mod nft_link_v2;
 pub mod receive_link_v2;
+pub mod receive_to_account_identifier_v2;
+pub mod receive_to_bitcoin_v2;
+pub mod reuse_allowance_v2;
+pub mod simulate_action_v2;
+pub mod update_link_v2;
 pub mod withdraw_link_v2;
@@ -0,0 +1,123 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::token_storage::nft::fixture::UserNftFixture;
+use crate::utils::{
+    PocketIcTestContext, link_id_to_account::link_id_to_account, principal::TestUser,
+    with_pocket_ic_context,
+};
+use candid::Nat;
+use cashier_backend_types::{
+    constant,
+    dto::link::{CreateLinkInput, LinkDetailUpdateAssetInfoInput},
+    error::CanisterError,
+    repository::{
+        common::{Asset, Wallet},
+        intent::v1::{IntentTask, IntentType},
+        link::v1::LinkType,
+        transaction::v1::{IcTransaction, Protocol},
+    },
+};
+use std::sync::Arc;
+
+fn nft_tip_link_input(
+    ctx: &PocketIcTestContext,
+    token_ids: Vec<Nat>,
+    max_use_count: u64,
+) -> CreateLinkInput {
+    CreateLinkInput {
+        title: "Test NFT Tip Link".to_string(),
+        link_use_action_max_count: max_use_count,
+        asset_info: vec![LinkDetailUpdateAssetInfoInput {
+            asset: Asset::IcNft {
+                address: ctx.icrc7_ledger_principal,
+            },
+            label: constant::INTENT_LABEL_SEND_TIP_ASSET.to_string(),
+            amount_per_link_use_action: Nat::from(1u64),
+            token_ids: Some(token_ids),
+        }],
+        link_type: LinkType::SendTip,
+        expires_at: None,
+        gate: None,
+        starts_at: None,
+        max_use_per_user: None,
+        allow_list: None,
+        split_recipients: None,
+        amount_bounds: None,
+    }
+}
+
+#[tokio::test]
+async fn it_should_error_create_nft_tip_linkv2_if_token_ids_mismatch_max_use() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let be_client = ctx.new_cashier_backend_client(caller);
+        let token_ids = UserNftFixture::new(Arc::new(ctx.clone()))
+            .mint_nfts_to_user(caller, 2)
+            .await;
+        let input = nft_tip_link_input(ctx, token_ids, 1);
+
+        // Act
+        let result = be_client.user_create_link_v2(input).await.unwrap();
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::ValidationErrors(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_create_nft_tip_linkv2_with_one_deposit_per_token() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let mut link_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), caller).await;
+        link_fixture
+            .airdrop_icp(Nat::from(1_000_000_000u64), &caller)
+            .await;
+        let token_ids = UserNftFixture::new(Arc::new(ctx.clone()))
+            .mint_nfts_to_user(caller, 2)
+            .await;
+        let input = nft_tip_link_input(ctx, token_ids.clone(), 2);
+
+        // Act
+        let create_link_result = link_fixture.create_link_v2(input).await;
+
+        // Assert
+        let link = create_link_result.link;
+        let action = create_link_result.action;
+        assert_eq!(link.asset_info.len(), 1);
+        assert_eq!(link.asset_info[0].token_ids, Some(token_ids.clone()));
+
+        // one NFT deposit per token id, followed by the create link fee
+        assert_eq!(action.intents.len(), 3);
+        for (intent, token_id) in action.intents.iter().zip(token_ids.iter()) {
+            assert_eq!(intent.task, IntentTask::TransferWalletToLink);
+            match intent.r#type {
+                IntentType::NftTransfer(ref transfer) => {
+                    assert_eq!(transfer.from, Wallet::new(caller));
+                    assert_eq!(transfer.to, link_id_to_account(ctx, &link.id).into());
+                    assert_eq!(&transfer.token_id, token_id);
+                }
+                _ => panic!("Expected NftTransfer intent type"),
+            }
+            match intent.transactions[0].protocol {
+                Protocol::IC(IcTransaction::Icrc7Transfer(ref data)) => {
+                    assert_eq!(&data.token_id, token_id);
+                    assert_eq!(data.asset.address(), ctx.icrc7_ledger_principal);
+                }
+                _ => panic!("Expected Icrc7Transfer transaction"),
+            }
+        }
+        assert_eq!(action.intents[2].task, IntentTask::TransferWalletToTreasury);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,132 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::{CKBTC_ICRC_TOKEN, ICP_TOKEN};
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::ProcessActionV2Input;
+use cashier_backend_types::repository::action::v1::{ActionState, ActionType};
+use cashier_backend_types::repository::common::Wallet;
+use cashier_backend_types::repository::intent::v1::IntentType;
+use cashier_backend_types::repository::transaction::v1::{FromCallType, IcTransaction, Protocol};
+use icrc_ledger_types::icrc1::account::Account;
+
+/// The exchange deposit account paid by the claims, an ICP account identifier
+/// derived from a subaccount of `TestUser::User3`.
+fn exchange_deposit_account() -> Account {
+    Account {
+        owner: TestUser::User3.get_principal(),
+        subaccount: Some([5u8; 32]),
+    }
+}
+
+/// Creates the RECEIVE action of the link, paying out to the exchange deposit account identifier.
+fn receive_to_account_identifier_input(link_id: &str) -> CreateActionInput {
+    let account_identifier = Wallet::from(exchange_deposit_account())
+        .get_icp_account_identifier()
+        .unwrap()
+        .to_hex();
+    CreateActionInput {
+        link_id: link_id.to_string(),
+        action_type: ActionType::Receive,
+        amount: None,
+        top_up_use_count: None,
+        btc_address: None,
+        destination: Some(Wallet::IcpAccountIdentifier { account_identifier }),
+    }
+}
+
+#[tokio::test]
+async fn it_should_receive_icp_tip_linkv2_to_account_identifier() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (creator_fixture, create_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount.clone()).await;
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), receiver).await;
+        let link_id = create_link_result.link.id.clone();
+
+        let icp_ledger_client = ctx.new_icp_ledger_client(receiver);
+        let deposit_balance_before = icp_ledger_client
+            .balance_of(&exchange_deposit_account())
+            .await
+            .unwrap();
+
+        // Act: create RECEIVE action
+        let action = receiver_fixture
+            .create_action_v2(receive_to_account_identifier_input(&link_id))
+            .await
+            .unwrap();
+
+        // Assert: the tip is sent through the legacy ICP transfer
+        assert_eq!(action.intents.len(), 1);
+        let intent = &action.intents[0];
+        match intent.r#type {
+            IntentType::Transfer(ref transfer) => {
+                assert!(matches!(transfer.to, Wallet::IcpAccountIdentifier { .. }));
+                assert_eq!(transfer.amount, tip_amount);
+            }
+            _ => panic!("Expected Transfer intent type"),
+        }
+        assert_eq!(intent.transactions.len(), 1);
+        let tx = &intent.transactions[0];
+        assert_eq!(tx.from_call_type, FromCallType::Canister);
+        assert!(matches!(
+            tx.protocol,
+            Protocol::IC(IcTransaction::IcpTransfer(_))
+        ));
+
+        // Act: process RECEIVE action
+        let process_action_result = receiver_fixture
+            .process_action_v2(ProcessActionV2Input {
+                action_id: action.id.clone(),
+            })
+            .await
+            .unwrap();
+
+        // Assert: the exchange deposit account received the tip
+        assert_eq!(process_action_result.action.state, ActionState::Success);
+        let deposit_balance_after = icp_ledger_client
+            .balance_of(&exchange_deposit_account())
+            .await
+            .unwrap();
+        assert_eq!(deposit_balance_after, deposit_balance_before + tip_amount);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_receive_ckbtc_tip_linkv2_to_account_identifier() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let (creator_fixture, create_link_result) =
+            activate_tip_link_v2_fixture(ctx, CKBTC_ICRC_TOKEN, Nat::from(50_000u64)).await;
+        let receiver_fixture =
+            LinkTestFixtureV2::new(creator_fixture.ctx.clone(), TestUser::User2.get_principal())
+                .await;
+
+        // Act
+        let result = receiver_fixture
+            .create_action_v2(receive_to_account_identifier_input(
+                &create_link_result.link.id,
+            ))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
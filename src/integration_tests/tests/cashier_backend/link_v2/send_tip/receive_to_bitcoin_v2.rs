@@ -0,0 +1,150 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::activate_tip_link_v2_fixture;
+use crate::utils::link_id_to_account::link_id_to_account;
+use crate::utils::principal::TestUser;
+use crate::utils::{PocketIcTestContext, with_pocket_ic_context};
+use candid::Nat;
+use cashier_backend_types::constant::{CKBTC_ICRC_TOKEN, ICP_TOKEN};
+use cashier_backend_types::dto::action::CreateActionInput;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::init::CkBtcMinterSettings;
+use cashier_backend_types::repository::action::v1::ActionType;
+use cashier_backend_types::repository::common::Wallet;
+use cashier_backend_types::repository::intent::v1::{IntentTask, IntentType};
+use cashier_backend_types::repository::transaction::v1::{FromCallType, IcTransaction, Protocol};
+
+const BTC_ADDRESS: &str = "bcrt1qe8tj5cf8m2y8apvqs8s8sw5yrlmzrfxdnfj8d7";
+
+/// Enables BTC withdrawals with the ckBTC minter of the test context.
+async fn set_ckbtc_minter(ctx: &PocketIcTestContext) -> CkBtcMinterSettings {
+    let ckbtc_minter = CkBtcMinterSettings {
+        minter_id: ctx.ckbtc_minter_principal,
+        ledger_id: ctx.get_icrc_token_principal(CKBTC_ICRC_TOKEN).unwrap(),
+    };
+    let admin_client =
+        ctx.new_cashier_backend_client(TestUser::CashierBackendAdmin.get_principal());
+    admin_client
+        .admin_ckbtc_minter_set(ckbtc_minter)
+        .await
+        .unwrap()
+        .unwrap();
+    ckbtc_minter
+}
+
+/// Creates the RECEIVE action of the link, paying out to `BTC_ADDRESS`.
+fn receive_to_bitcoin_input(link_id: &str) -> CreateActionInput {
+    CreateActionInput {
+        link_id: link_id.to_string(),
+        action_type: ActionType::Receive,
+        amount: None,
+        top_up_use_count: None,
+        btc_address: Some(BTC_ADDRESS.to_string()),
+        destination: None,
+    }
+}
+
+#[tokio::test]
+async fn it_should_create_btc_withdrawal_receive_action_for_ckbtc_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let ckbtc_minter = set_ckbtc_minter(ctx).await;
+        let tip_amount = Nat::from(50_000u64);
+        let (creator_fixture, create_link_result) =
+            activate_tip_link_v2_fixture(ctx, CKBTC_ICRC_TOKEN, tip_amount.clone()).await;
+
+        let receiver = TestUser::User2.get_principal();
+        let receiver_fixture = LinkTestFixtureV2::new(creator_fixture.ctx.clone(), receiver).await;
+        let link_id = create_link_result.link.id.clone();
+
+        // Act
+        let action = receiver_fixture
+            .create_action_v2(receive_to_bitcoin_input(&link_id))
+            .await
+            .unwrap();
+
+        // Assert: a single intent withdraws the tip to the Bitcoin address
+        assert_eq!(action.r#type, ActionType::Receive);
+        assert_eq!(action.intents.len(), 1);
+        let intent = &action.intents[0];
+        assert_eq!(intent.task, IntentTask::TransferLinkToBitcoin);
+        match intent.r#type {
+            IntentType::RetrieveBtc(ref retrieve_btc) => {
+                assert_eq!(retrieve_btc.from, link_id_to_account(ctx, &link_id).into());
+                assert_eq!(retrieve_btc.minter, Wallet::new(ckbtc_minter.minter_id));
+                assert_eq!(retrieve_btc.amount, tip_amount);
+                assert_eq!(retrieve_btc.btc_address, BTC_ADDRESS);
+            }
+            _ => panic!("Expected RetrieveBtc intent type"),
+        }
+
+        // Assert: the withdrawal is executed by the canister
+        assert_eq!(intent.transactions.len(), 1);
+        let tx = &intent.transactions[0];
+        assert_eq!(tx.from_call_type, FromCallType::Canister);
+        match tx.protocol {
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(ref data)) => {
+                assert_eq!(data.minter, Wallet::new(ckbtc_minter.minter_id));
+                assert_eq!(data.amount, tip_amount);
+                assert_eq!(data.btc_address, BTC_ADDRESS);
+                assert!(data.memo.is_some());
+            }
+            _ => panic!("Expected CkBtcRetrieveBtc transaction"),
+        }
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_btc_withdrawal_from_icp_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        set_ckbtc_minter(ctx).await;
+        let (creator_fixture, create_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, Nat::from(1_000_000u64)).await;
+        let receiver_fixture =
+            LinkTestFixtureV2::new(creator_fixture.ctx.clone(), TestUser::User2.get_principal())
+                .await;
+
+        // Act
+        let result = receiver_fixture
+            .create_action_v2(receive_to_bitcoin_input(&create_link_result.link.id))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_btc_withdrawal_if_ckbtc_minter_not_set() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let (creator_fixture, create_link_result) =
+            activate_tip_link_v2_fixture(ctx, CKBTC_ICRC_TOKEN, Nat::from(50_000u64)).await;
+        let receiver_fixture =
+            LinkTestFixtureV2::new(creator_fixture.ctx.clone(), TestUser::User2.get_principal())
+                .await;
+
+        // Act
+        let result = receiver_fixture
+            .create_action_v2(receive_to_bitcoin_input(&create_link_result.link.id))
+            .await;
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::ValidationErrors(_))));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
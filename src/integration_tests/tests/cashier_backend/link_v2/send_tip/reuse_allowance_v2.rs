@@ -0,0 +1,127 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::TipLinkV2Fixture;
+use crate::utils::{icrc_112, principal::TestUser, with_pocket_ic_context};
+use candid::{Decode, Nat};
+use cashier_backend_types::constant;
+use cashier_backend_types::repository::action::v1::ActionState;
+use cashier_backend_types::repository::intent::v1::IntentTask;
+use cashier_backend_types::repository::transaction::v1::{
+    IcTransaction, Protocol, TransactionState,
+};
+use icrc_ledger_types::{icrc1::account::Account, icrc2::approve::ApproveArgs};
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_reuse_existing_allowance_when_creating_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: the creator already granted a large allowance to the backend
+        let caller = TestUser::User1.get_principal();
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+
+        let icp_ledger_client = ctx.new_icp_ledger_client(caller);
+        let backend_account = Account {
+            owner: ctx.cashier_backend_principal,
+            subaccount: None,
+        };
+        icp_ledger_client
+            .approve(backend_account, Nat::from(1_000_000_000u64), None)
+            .await
+            .unwrap();
+
+        // Act
+        let create_link_result = test_fixture.create_link().await;
+
+        // Assert: the create link fee approve is already covered
+        let action = create_link_result.action;
+        let fee_intent = action
+            .intents
+            .iter()
+            .find(|intent| intent.task == IntentTask::TransferWalletToTreasury)
+            .expect("Create link fee intent not found");
+        let approve_tx = fee_intent
+            .transactions
+            .iter()
+            .find(|tx| matches!(tx.protocol, Protocol::IC(IcTransaction::Icrc2Approve(_))))
+            .expect("Approve transaction not found");
+        assert_eq!(approve_tx.state, TransactionState::Success);
+
+        let icrc_112_requests = action.icrc_112_requests.unwrap();
+        assert!(
+            icrc_112_requests
+                .iter()
+                .flatten()
+                .all(|req| req.method != "icrc2_approve")
+        );
+
+        // Act: execute the remaining requests and activate the link
+        icrc_112::execute_icrc112_request(&icrc_112_requests, caller, ctx)
+            .await
+            .unwrap();
+        let activate_result = test_fixture
+            .link_fixture
+            .activate_link_v2(&action.id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert_eq!(activate_result.action.state, ActionState::Success);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_request_expiring_approve_when_allowance_insufficient() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: the creator granted an allowance too small for the create link fee
+        let caller = TestUser::User1.get_principal();
+        let mut test_fixture = TipLinkV2Fixture::new(
+            Arc::new(ctx.clone()),
+            caller,
+            constant::ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+        test_fixture.airdrop_icp_and_asset().await;
+
+        let icp_ledger_client = ctx.new_icp_ledger_client(caller);
+        let backend_account = Account {
+            owner: ctx.cashier_backend_principal,
+            subaccount: None,
+        };
+        icp_ledger_client
+            .approve(backend_account, Nat::from(1u64), None)
+            .await
+            .unwrap();
+
+        // Act
+        let create_link_result = test_fixture.create_link().await;
+
+        // Assert: an approve with an expiration is requested
+        let icrc_112_requests = create_link_result.action.icrc_112_requests.unwrap();
+        let approve_request = icrc_112_requests
+            .iter()
+            .flatten()
+            .find(|req| req.method == "icrc2_approve")
+            .expect("icrc2_approve request not found");
+        let approve_arg = Decode!(&approve_request.arg, ApproveArgs)
+            .expect("Failed to decode icrc2_approve args");
+        assert!(approve_arg.expires_at.is_some());
+        assert!(approve_arg.expires_at > approve_arg.created_at_time);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
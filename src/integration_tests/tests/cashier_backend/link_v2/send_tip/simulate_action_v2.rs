@@ -0,0 +1,101 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::send_tip::fixture::create_tip_linkv2_fixture;
+use crate::constant::ICP_PRINCIPAL;
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::repository::action::v1::ActionState;
+
+#[tokio::test]
+async fn it_should_simulate_create_action_of_funded_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let (test_fixture, create_link_result) =
+            create_tip_linkv2_fixture(ctx, caller, ICP_TOKEN, Nat::from(1_000_000u64)).await;
+        let action_id = create_link_result.action.id.clone();
+
+        // Act
+        let simulate_result = test_fixture.simulate_action_v2(&action_id).await.unwrap();
+
+        // Assert
+        assert_eq!(simulate_result.action_id, action_id);
+        assert!(simulate_result.is_success);
+        assert!(simulate_result.blocking_error.is_none());
+        assert!(simulate_result.errors.is_empty());
+
+        // Assert: the simulation did not change the action
+        let receipt = test_fixture
+            .get_action_receipt_v2(&action_id)
+            .await
+            .unwrap();
+        assert_eq!(receipt.state, ActionState::Created);
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_report_insufficient_balance_when_simulating_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange: the tip is larger than the airdropped balance
+        let caller = TestUser::User1.get_principal();
+        let (test_fixture, create_link_result) =
+            create_tip_linkv2_fixture(ctx, caller, ICP_TOKEN, Nat::from(10_000_000_000u64)).await;
+
+        // Act
+        let simulate_result = test_fixture
+            .simulate_action_v2(&create_link_result.action.id)
+            .await
+            .unwrap();
+
+        // Assert
+        assert!(!simulate_result.is_success);
+        assert_eq!(
+            simulate_result.blocking_error,
+            Some(format!("Insufficient balance for {} asset", ICP_PRINCIPAL))
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_simulate_action_of_other_user_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let caller = TestUser::User1.get_principal();
+        let (_test_fixture, create_link_result) =
+            create_tip_linkv2_fixture(ctx, caller, ICP_TOKEN, Nat::from(1_000_000u64)).await;
+        let (other_fixture, _) = create_tip_linkv2_fixture(
+            ctx,
+            TestUser::User2.get_principal(),
+            ICP_TOKEN,
+            Nat::from(1_000_000u64),
+        )
+        .await;
+
+        // Act
+        let simulate_result = other_fixture
+            .simulate_action_v2(&create_link_result.action.id)
+            .await;
+
+        // Assert
+        assert!(matches!(
+            simulate_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,130 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::cashier_backend::link_v2::fixture::LinkTestFixtureV2;
+use crate::cashier_backend::link_v2::send_tip::fixture::{
+    activate_tip_link_v2_fixture, create_tip_linkv2_fixture,
+};
+use crate::utils::principal::TestUser;
+use crate::utils::with_pocket_ic_context;
+use candid::Nat;
+use cashier_backend_types::constant::ICP_TOKEN;
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::dto::UpdateLinkV2Input;
+use cashier_backend_types::repository::link::v1::LinkState;
+use std::sync::Arc;
+
+#[tokio::test]
+async fn it_should_update_metadata_of_active_tip_linkv2() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (test_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+        let link_id = activate_link_result.link.id.clone();
+        let now = ctx.client.get_time().await.as_nanos_since_unix_epoch();
+        let expires_at = now + 3_600_000_000_000;
+
+        // Act
+        let update_link_result = test_fixture
+            .update_link_v2(UpdateLinkV2Input {
+                link_id: link_id.clone(),
+                title: Some("Fixed title".to_string()),
+                description: Some("A tip for you".to_string()),
+                image_url: Some("https://example.com/tip.png".to_string()),
+                expires_at: Some(expires_at),
+            })
+            .await;
+
+        // Assert
+        let link = update_link_result.unwrap();
+        assert_eq!(link.state, LinkState::Active);
+        assert_eq!(link.title, "Fixed title");
+        assert_eq!(link.description, Some("A tip for you".to_string()));
+        assert_eq!(
+            link.image_url,
+            Some("https://example.com/tip.png".to_string())
+        );
+        assert_eq!(link.expires_at, Some(expires_at));
+        assert_eq!(
+            link.asset_info.len(),
+            activate_link_result.link.asset_info.len()
+        );
+        assert_eq!(
+            link.link_use_action_max_count,
+            activate_link_result.link.link_use_action_max_count
+        );
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_update_tip_linkv2_if_caller_is_not_creator() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let creator = TestUser::User1.get_principal();
+        let tip_amount = Nat::from(1_000_000u64);
+        let (_test_fixture, create_link_result) =
+            create_tip_linkv2_fixture(ctx, creator, ICP_TOKEN, tip_amount).await;
+
+        let caller = TestUser::User2.get_principal();
+        let caller_fixture = LinkTestFixtureV2::new(Arc::new(ctx.clone()), caller).await;
+
+        // Act
+        let update_link_result = caller_fixture
+            .update_link_v2(UpdateLinkV2Input {
+                link_id: create_link_result.link.id.clone(),
+                title: Some("Hijacked".to_string()),
+                description: None,
+                image_url: None,
+                expires_at: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            update_link_result,
+            Err(CanisterError::Unauthorized(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
+
+#[tokio::test]
+async fn it_should_fail_update_tip_linkv2_if_link_disabled() {
+    with_pocket_ic_context::<_, ()>(async move |ctx| {
+        // Arrange
+        let tip_amount = Nat::from(1_000_000u64);
+        let (test_fixture, activate_link_result) =
+            activate_tip_link_v2_fixture(ctx, ICP_TOKEN, tip_amount).await;
+        let link_id = activate_link_result.link.id.clone();
+        test_fixture.disable_link_v2(&link_id).await.unwrap();
+
+        // Act
+        let update_link_result = test_fixture
+            .update_link_v2(UpdateLinkV2Input {
+                link_id,
+                title: Some("Too late".to_string()),
+                description: None,
+                image_url: None,
+                expires_at: None,
+            })
+            .await;
+
+        // Assert
+        assert!(matches!(
+            update_link_result,
+            Err(CanisterError::ValidationErrors(_))
+        ));
+
+        Ok(())
+    })
+    .await
+    .unwrap();
+}
@@ -29,6 +29,10 @@ async fn test_request_lock_for_process_action() {
         let create_action_input = CreateActionInput {
             link_id: dto.link.id.clone(),
             action_type: ActionType::Receive,
+            amount: None,
+            top_up_use_count: None,
+            btc_address: None,
+            destination: None,
         };
         let receive_action = creator_fixture
             .link_fixture
@@ -223,6 +223,9 @@ async fn deploy_template_state(template_dir: &Path) -> SharedPrincipals {
             log_settings: Some(log.clone()),
             owner: TestUser::CashierBackendAdmin.get_principal(),
             token_fee_ttl_ns: Some(168 * 60 * 60 * 1_000_000_000),
+            action_processing_timeout_ns: None,
+            ckbtc_minter: None,
+            link_fee: None,
         }),
     )
     .await;
@@ -242,6 +245,17 @@ async fn deploy_template_state(template_dir: &Path) -> SharedPrincipals {
     )
     .await;
 
+    // Let the cashier backend enforce link gates through the gate service
+    client
+        .update_call(
+            cashier_backend_principal,
+            TestUser::CashierBackendAdmin.get_principal(),
+            "admin_gate_service_set",
+            Encode!(&gate_service_principal).unwrap(),
+        )
+        .await
+        .expect("Should be able to set the gate service");
+
     let icp_ledger_principal = token_icp::deploy_icp_ledger_canister(&client).await;
 
     let mut icrc_token_map = HashMap::new();
@@ -9,6 +9,7 @@ use ic_ledger_types::{AccountIdentifier, DEFAULT_SUBACCOUNT};
 use ic_mple_client::CanisterClient;
 use icrc_ledger_types::icrc1::transfer::TransferArg;
 use icrc_ledger_types::icrc1::{account::Account as IcrcAccount, transfer::TransferError};
+use icrc_ledger_types::icrc2::approve::{ApproveArgs, ApproveError};
 
 #[derive(Debug, Clone)]
 pub struct IcpLedgerClient<C>
@@ -46,6 +47,31 @@ impl<C: CanisterClient> IcpLedgerClient<C> {
             .unwrap()
     }
 
+    // Ledger ICP approve
+    pub async fn approve(
+        &self,
+        spender: IcrcAccount,
+        amount: Nat,
+        expires_at: Option<u64>,
+    ) -> Result<Nat, ApproveError> {
+        let approve_args = ApproveArgs {
+            from_subaccount: None,
+            spender,
+            amount,
+            expected_allowance: None,
+            expires_at,
+            fee: None,
+            memo: None,
+            created_at_time: None,
+        };
+
+        let res: Result<Result<Nat, ApproveError>, ic_mple_client::CanisterClientError> =
+            self.client.update("icrc2_approve", (approve_args,)).await;
+
+        res.map_err(|e| format!("ICRC approve failed: {e:?}"))
+            .unwrap()
+    }
+
     pub async fn balance_of(
         &self,
         account: &IcrcAccount,
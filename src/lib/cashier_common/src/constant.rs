@@ -13,11 +13,37 @@ pub const FEE_TREASURY_PRINCIPAL: Principal = Principal::from_slice(&FEE_TREASUR
 
 pub const ICRC_TRANSACTION_TIME_WINDOW_NANOSECS: u64 = 24 * 3600 * 1_000_000_000; // 24 hours
 
+/// Lifetime in nanoseconds of the ICRC-2 allowances requested from wallets (7 days)
+pub const ICRC2_APPROVE_EXPIRY_NANOSECS: u64 = 7 * 24 * 3600 * 1_000_000_000;
+
+/// Minimum remaining lifetime in nanoseconds of an existing ICRC-2 allowance for it
+/// to be reused instead of requesting a new approve (24 hours)
+pub const ICRC2_ALLOWANCE_MIN_REMAINING_NANOSECS: u64 = 24 * 3600 * 1_000_000_000;
+
 pub const CREATE_LINK_FEE: u64 = 10_000;
 
+/// Maximum number of links created by a single batch, each deposit of the batch
+/// being validated against its ledger when the batch is funded
+pub const MAX_LINK_BATCH_SIZE: u64 = 100;
+
+/// Maximum number of transactions validated against their ledgers at the same time
+pub const MAX_CONCURRENT_TRANSACTION_VALIDATIONS: usize = 100;
+
 /// Default TTL in nanoseconds (168 hours = 7 days)
 pub const DEFAULT_TOKEN_FEE_TTL_NS: u64 = 168 * 60 * 60 * 1_000_000_000;
 
+/// Default time in nanoseconds after which an action left in processing is recovered (10 minutes)
+pub const DEFAULT_ACTION_PROCESSING_TIMEOUT_NS: u64 = 10 * 60 * 1_000_000_000;
+
+/// Number of recovery runs after which an action still left in processing is failed
+pub const MAX_ACTION_RECOVERY_ATTEMPTS: u64 = 6;
+
+/// Number of links whose start and expiry timers are re-armed per timer after an upgrade
+pub const LINK_TIMERS_REARM_CHUNK_SIZE: usize = 500;
+
+/// Time in nanoseconds a random share or NFT token id stays reserved for an unprocessed claim (1 hour)
+pub const CLAIM_RESERVATION_TTL_NS: u64 = 60 * 60 * 1_000_000_000;
+
 #[cfg(test)]
 pub mod dfd {
     use super::*;
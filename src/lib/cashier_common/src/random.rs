@@ -44,3 +44,10 @@ fn custom_getrandom(buf: &mut [u8]) -> Result<(), getrandom::Error> {
         }
     })
 }
+
+/// Returns the next random number drawn from the canister RNG.
+///
+/// Returns `None` until the RNG has been seeded by `init_ic_rand`.
+pub fn next_u64() -> Option<u64> {
+    RNG.with(|rng| rng.borrow_mut().as_mut().map(RngCore::next_u64))
+}
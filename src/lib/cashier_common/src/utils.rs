@@ -53,6 +53,81 @@ pub fn to_memo(id: &str) -> Result<Memo, String> {
     Ok(Memo(ByteBuf::from(memo.to_vec())))
 }
 
+/// The link and transaction a ledger transfer was made for, decoded from its memo
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LinkMemo {
+    /// The memo of an ICRC transfer, built by [`to_link_memo`]
+    Icrc {
+        link_id: String,
+        transaction_id: String,
+    },
+    /// The memo of a legacy ICP transfer, built by [`to_icp_link_memo`].
+    /// The link is the subaccount sending the transfer.
+    Icp { transaction_id_prefix: u64 },
+}
+
+/// Converts a link ID and a transaction ID to a 32-byte Memo format for ICRC transactions
+///
+/// The memo layout is:
+/// - The first 16 bytes contain the transaction UUID bytes, as in [`to_memo`]
+/// - The remaining 16 bytes contain the link UUID bytes
+///
+/// Used on every link transfer so that any ledger transfer can be mapped back
+/// to its link with [`decode_link_memo`].
+pub fn to_link_memo(link_id: &str, transaction_id: &str) -> Result<Memo, String> {
+    let link_uuid = Uuid::parse_str(link_id).map_err(|_| "Invalid UUID format".to_string())?;
+    let transaction_uuid =
+        Uuid::parse_str(transaction_id).map_err(|_| "Invalid UUID format".to_string())?;
+
+    // DO NOT CHANGE THE ORDER OF THE BYTES
+    let mut memo: [u8; 32] = [0; 32];
+    memo[..16].copy_from_slice(transaction_uuid.as_bytes());
+    memo[16..].copy_from_slice(link_uuid.as_bytes());
+
+    Ok(Memo(ByteBuf::from(memo.to_vec())))
+}
+
+/// Converts a transaction ID to the 64-bit memo of a legacy ICP ledger transfer
+///
+/// The legacy `transfer` method only takes a 64-bit memo, which cannot hold [`to_link_memo`].
+/// The memo is the first 8 bytes of the transaction UUID, read as a big-endian integer,
+/// and the link of the transfer is its sending subaccount.
+pub fn to_icp_link_memo(transaction_id: &str) -> Result<u64, String> {
+    let transaction_uuid =
+        Uuid::parse_str(transaction_id).map_err(|_| "Invalid UUID format".to_string())?;
+
+    let (prefix, _) = transaction_uuid.as_u64_pair();
+    Ok(prefix)
+}
+
+/// Decodes a memo built by [`to_link_memo`], or the big-endian bytes of a memo built by [`to_icp_link_memo`]
+/// # Arguments
+/// * `memo` - The raw memo bytes of a ledger transfer
+/// # Returns
+/// * `Option<LinkMemo>` - The link and transaction IDs, or None if the memo was not built for a link
+pub fn decode_link_memo(memo: &[u8]) -> Option<LinkMemo> {
+    if let Ok(icp_memo) = <[u8; 8]>::try_from(memo) {
+        let transaction_id_prefix = u64::from_be_bytes(icp_memo);
+        return (transaction_id_prefix != 0).then_some(LinkMemo::Icp {
+            transaction_id_prefix,
+        });
+    }
+    if memo.len() != 32 {
+        return None;
+    }
+
+    let transaction_uuid = Uuid::from_slice(&memo[..16]).ok()?;
+    let link_uuid = Uuid::from_slice(&memo[16..]).ok()?;
+    if transaction_uuid.is_nil() || link_uuid.is_nil() {
+        return None;
+    }
+
+    Some(LinkMemo::Icrc {
+        link_id: link_uuid.to_string(),
+        transaction_id: transaction_uuid.to_string(),
+    })
+}
+
 /// Converts a Nat value to u64, returning an error if the value is too large
 /// # Arguments
 /// * `nat_value` - The Nat value to convert
@@ -132,3 +207,72 @@ pub fn get_link_ext_account(link_id: &str, canister_id: Principal) -> Result<Acc
         subaccount: Some(subaccount),
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn it_should_decode_link_memo() {
+        // Arrange
+        let link_id = Uuid::new_v4().to_string();
+        let transaction_id = Uuid::new_v4().to_string();
+        let memo = to_link_memo(&link_id, &transaction_id).unwrap();
+
+        // Act
+        let result = decode_link_memo(&memo.0);
+
+        // Assert
+        assert_eq!(
+            result,
+            Some(LinkMemo::Icrc {
+                link_id,
+                transaction_id,
+            })
+        );
+    }
+
+    #[test]
+    fn it_should_decode_icp_link_memo() {
+        // Arrange
+        let transaction_id = Uuid::new_v4().to_string();
+        let memo = to_icp_link_memo(&transaction_id).unwrap();
+
+        // Act
+        let result = decode_link_memo(&memo.to_be_bytes());
+
+        // Assert
+        assert_eq!(
+            result,
+            Some(LinkMemo::Icp {
+                transaction_id_prefix: memo
+            })
+        );
+        assert!(
+            transaction_id
+                .replace('-', "")
+                .starts_with(&format!("{memo:016x}"))
+        );
+    }
+
+    #[test]
+    fn it_should_not_decode_memo_without_link() {
+        // Arrange
+        let memo = to_memo(&Uuid::new_v4().to_string()).unwrap();
+
+        // Act
+        let result = decode_link_memo(&memo.0);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn it_should_not_decode_memo_of_other_length() {
+        // Act
+        let result = decode_link_memo(&[1u8; 16]);
+
+        // Assert
+        assert_eq!(result, None);
+    }
+}
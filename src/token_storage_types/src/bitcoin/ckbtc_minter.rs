@@ -64,6 +64,82 @@ impl Display for UpdateBalanceError {
     }
 }
 
+#[derive(CandidType, Deserialize)]
+pub struct RetrieveBtcWithApprovalArgs {
+    /// The Bitcoin address receiving the withdrawn BTC.
+    pub address: String,
+    /// The amount of ckBTC, in satoshi, burned for the withdrawal.
+    pub amount: u64,
+    /// The subaccount of the caller the ckBTC is burned from.
+    pub from_subaccount: Option<serde_bytes::ByteBuf>,
+}
+
+#[derive(CandidType, Deserialize)]
+pub struct RetrieveBtcOk {
+    /// The ledger index of the block burning the ckBTC.
+    pub block_index: u64,
+}
+
+pub type RetrieveBtcWithApprovalResult = Result<RetrieveBtcOk, RetrieveBtcWithApprovalError>;
+
+/// A withdrawal request of an account, as listed by `retrieve_btc_status_v2_by_account`.
+/// The status of the request is not decoded.
+#[derive(CandidType, Deserialize)]
+pub struct AccountRetrieveBtcRequest {
+    /// The ledger index of the block burning the ckBTC of the request.
+    pub block_index: u64,
+}
+
+#[derive(CandidType, Deserialize)]
+pub enum RetrieveBtcWithApprovalError {
+    /// The minter failed to parse the destination address.
+    MalformedAddress(String),
+    /// A generic error reserved for future extensions.
+    GenericError {
+        error_message: String,
+        error_code: u64,
+    },
+    /// The minter is overloaded, retry the request.
+    TemporarilyUnavailable(String),
+    /// The minter is not allowed to burn enough ckBTC from the caller account.
+    InsufficientAllowance { allowance: u64 },
+    /// The minter is already processing another retrieval request for the same principal.
+    AlreadyProcessing,
+    /// The withdrawal amount is too low, the payload contains the minimal withdrawal amount.
+    AmountTooLow(u64),
+    /// The ckBTC balance of the withdrawal account is too low.
+    InsufficientFunds { balance: u64 },
+}
+
+impl Display for RetrieveBtcWithApprovalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RetrieveBtcWithApprovalError::MalformedAddress(msg) => {
+                write!(f, "MalformedAddress: {}", msg)
+            }
+            RetrieveBtcWithApprovalError::GenericError {
+                error_message,
+                error_code,
+            } => write!(f, "GenericError (code {}): {}", error_code, error_message),
+            RetrieveBtcWithApprovalError::TemporarilyUnavailable(msg) => {
+                write!(f, "TemporarilyUnavailable: {}", msg)
+            }
+            RetrieveBtcWithApprovalError::InsufficientAllowance { allowance } => {
+                write!(f, "InsufficientAllowance: Allowance {}", allowance)
+            }
+            RetrieveBtcWithApprovalError::AlreadyProcessing => {
+                write!(f, "AlreadyProcessing: Another request is in progress")
+            }
+            RetrieveBtcWithApprovalError::AmountTooLow(min_amount) => {
+                write!(f, "AmountTooLow: Minimal amount {}", min_amount)
+            }
+            RetrieveBtcWithApprovalError::InsufficientFunds { balance } => {
+                write!(f, "InsufficientFunds: Balance {}", balance)
+            }
+        }
+    }
+}
+
 #[derive(CandidType, Deserialize)]
 pub enum UtxoStatus {
     /// The minter ignored this UTXO because UTXO's value is too small to pay
@@ -2,6 +2,7 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{CandidType, Deserialize, Int, Nat, Principal};
+use std::fmt::Display;
 
 pub type SubAccount = serde_bytes::ByteBuf;
 
@@ -20,6 +21,57 @@ pub struct NftMetadata {
 
 pub type Icrc7OwnerOfResponse = Vec<Option<Account>>;
 
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub struct Icrc7TransferArg {
+    pub to: Account,
+    pub token_id: Nat,
+    pub memo: Option<serde_bytes::ByteBuf>,
+    pub from_subaccount: Option<SubAccount>,
+    pub created_at_time: Option<u64>,
+}
+
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub enum Icrc7TransferError {
+    GenericError { message: String, error_code: Nat },
+    Duplicate { duplicate_of: Nat },
+    NonExistingTokenId,
+    Unauthorized,
+    CreatedInFuture { ledger_time: Nat },
+    InvalidRecipient,
+    GenericBatchError { message: String, error_code: Nat },
+    TooOld,
+}
+
+impl Display for Icrc7TransferError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Icrc7TransferError::GenericError {
+                message,
+                error_code,
+            }
+            | Icrc7TransferError::GenericBatchError {
+                message,
+                error_code,
+            } => write!(f, "GenericError (code {}): {}", error_code, message),
+            Icrc7TransferError::Duplicate { duplicate_of } => {
+                write!(f, "Duplicate: Duplicate of {}", duplicate_of)
+            }
+            Icrc7TransferError::NonExistingTokenId => write!(f, "NonExistingTokenId"),
+            Icrc7TransferError::Unauthorized => write!(f, "Unauthorized"),
+            Icrc7TransferError::CreatedInFuture { ledger_time } => {
+                write!(f, "CreatedInFuture: Ledger time {}", ledger_time)
+            }
+            Icrc7TransferError::InvalidRecipient => write!(f, "InvalidRecipient"),
+            Icrc7TransferError::TooOld => write!(f, "TooOld"),
+        }
+    }
+}
+
+pub type Icrc7TransferResult = Result<Nat, Icrc7TransferError>;
+
+/// One entry per transfer argument, `None` if the transfer was not processed.
+pub type Icrc7TransferResponse = Vec<Option<Icrc7TransferResult>>;
+
 #[derive(CandidType, Deserialize)]
 #[allow(clippy::large_enum_variant)]
 pub enum CanisterLifecycleArgs {
@@ -5,14 +5,19 @@ use crate::adapter::IntentAdapterTrait;
 use cashier_backend_types::{
     error::CanisterError,
     repository::{
-        intent::v1::{Intent, IntentTask, IntentType, TransferData, TransferFromData},
+        common::Wallet,
+        intent::v1::{
+            Intent, IntentTask, IntentType, NftTransferData, RetrieveBtcData, TransferData,
+            TransferFromData,
+        },
         transaction::v1::{
-            FromCallType, IcTransaction, Icrc1Transfer, Icrc2Approve, Icrc2TransferFrom, Protocol,
-            Transaction, TransactionState,
+            CkBtcRetrieveBtc, FromCallType, IcTransaction, IcpTransfer, Icrc1Transfer,
+            Icrc2Approve, Icrc2TransferFrom, Icrc7Transfer, Protocol, Transaction,
+            TransactionState,
         },
     },
 };
-use cashier_common::utils::to_memo;
+use cashier_common::utils::{to_icp_link_memo, to_link_memo};
 use uuid::Uuid;
 
 #[derive(Clone, Default)]
@@ -22,17 +27,19 @@ impl IcIntentAdapter {
     /// Assembles ICRC1 wallet transfer transactions from the given transfer intent.
     /// # Arguments
     /// * `ts` - The timestamp for the transaction.
+    /// * `link_id` - The ID of the link the intent belongs to.
     /// * `transfer_intent` - The transfer intent containing transfer details.
     /// # Returns
     /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
     fn assemble_icrc1_wallet_transfer(
         &self,
         ts: u64,
+        link_id: &str,
         transfer_intent: TransferData,
     ) -> Result<Vec<Transaction>, CanisterError> {
         let id: Uuid = Uuid::new_v4();
 
-        let memo = to_memo(&id.to_string())?;
+        let memo = to_link_memo(link_id, &id.to_string())?;
 
         let icrc1_transfer = Icrc1Transfer {
             from: transfer_intent.from,
@@ -54,6 +61,7 @@ impl IcIntentAdapter {
             group: 1,
             from_call_type: FromCallType::Wallet,
             start_ts: None,
+            block_index: None,
         };
 
         Ok(vec![transaction])
@@ -62,12 +70,14 @@ impl IcIntentAdapter {
     /// Assembles ICRC2 wallet transfer transactions from the given transfer intent.
     /// # Arguments
     /// * `ts` - The timestamp for the transaction.
+    /// * `link_id` - The ID of the link the intent belongs to.
     /// * `transfer_intent` - The transfer intent containing transfer details.
     /// # Returns
     /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
     fn assemble_icrc2_wallet_transfer(
         &self,
         ts: u64,
+        link_id: &str,
         transfer_intent: TransferFromData,
     ) -> Result<Vec<Transaction>, CanisterError> {
         let approve_id = Uuid::new_v4();
@@ -92,7 +102,7 @@ impl IcIntentAdapter {
             ));
         }
 
-        let memo = to_memo(&approve_id.to_string())?;
+        let memo = to_link_memo(link_id, &approve_id.to_string())?;
 
         let icrc2_approve = Icrc2Approve {
             from: transfer_intent.from.clone(),
@@ -113,10 +123,11 @@ impl IcIntentAdapter {
             group: 1,
             from_call_type: FromCallType::Wallet,
             start_ts: None,
+            block_index: None,
         };
 
         let transfer_id = Uuid::new_v4();
-        let transfer_memo = to_memo(&transfer_id.to_string())?;
+        let transfer_memo = to_link_memo(link_id, &transfer_id.to_string())?;
         let icrc2_transfer_from = Icrc2TransferFrom {
             from: transfer_intent.from,
             to: transfer_intent.to,
@@ -136,6 +147,7 @@ impl IcIntentAdapter {
             group: 1,
             from_call_type: FromCallType::Canister,
             start_ts: None,
+            block_index: None,
         };
 
         Ok(vec![approve_tx, transfer_from_tx])
@@ -144,17 +156,19 @@ impl IcIntentAdapter {
     /// Assembles ICRC1 canister transfer transactions from the given transfer intent.
     /// # Arguments
     /// * `ts` - The timestamp for the transaction.
+    /// * `link_id` - The ID of the link the intent belongs to.
     /// * `transfer_intent` - The transfer intent containing transfer details.
     /// # Returns
     /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
     fn assemble_icrc1_canister_transfer(
         &self,
         ts: u64,
+        link_id: &str,
         transfer_intent: TransferData,
     ) -> Result<Vec<Transaction>, CanisterError> {
         let id: Uuid = Uuid::new_v4();
 
-        let memo = to_memo(&id.to_string())?;
+        let memo = to_link_memo(link_id, &id.to_string())?;
 
         let icrc1_transfer = Icrc1Transfer {
             from: transfer_intent.from,
@@ -175,27 +189,176 @@ impl IcIntentAdapter {
             group: 1,
             from_call_type: FromCallType::Canister,
             start_ts: None,
+            block_index: None,
         };
 
         Ok(vec![transfer_from_tx])
     }
+
+    /// Assembles the legacy ICP ledger transfer paying the account identifier of the given intent.
+    /// # Arguments
+    /// * `ts` - The timestamp for the transaction.
+    /// * `transfer_intent` - The transfer intent containing transfer details.
+    /// # Returns
+    /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
+    fn assemble_icp_canister_transfer(
+        &self,
+        ts: u64,
+        transfer_intent: TransferData,
+    ) -> Result<Vec<Transaction>, CanisterError> {
+        let id: Uuid = Uuid::new_v4();
+
+        let memo = to_icp_link_memo(&id.to_string())?;
+
+        let icp_transfer = IcpTransfer {
+            from: transfer_intent.from,
+            to: transfer_intent.to,
+            asset: transfer_intent.asset,
+            amount: transfer_intent.amount,
+            memo: Some(memo),
+            ts: Some(ts),
+        };
+
+        let transaction = Transaction {
+            id: id.to_string(),
+            created_at: ts,
+            state: TransactionState::Created,
+            dependency: None,
+            protocol: Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)),
+            group: 1,
+            from_call_type: FromCallType::Canister,
+            start_ts: None,
+            block_index: None,
+        };
+
+        Ok(vec![transaction])
+    }
+
+    /// Assembles the ckBTC withdrawal transaction paying BTC to the address of the given intent.
+    /// # Arguments
+    /// * `ts` - The timestamp for the transaction.
+    /// * `link_id` - The ID of the link the intent belongs to.
+    /// * `retrieve_btc_intent` - The withdrawal intent containing the minter and Bitcoin address.
+    /// # Returns
+    /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
+    fn assemble_ckbtc_canister_retrieve_btc(
+        &self,
+        ts: u64,
+        link_id: &str,
+        retrieve_btc_intent: RetrieveBtcData,
+    ) -> Result<Vec<Transaction>, CanisterError> {
+        let id: Uuid = Uuid::new_v4();
+
+        let memo = to_link_memo(link_id, &id.to_string())?;
+
+        let ckbtc_retrieve_btc = CkBtcRetrieveBtc {
+            from: retrieve_btc_intent.from,
+            minter: retrieve_btc_intent.minter,
+            asset: retrieve_btc_intent.asset,
+            amount: retrieve_btc_intent.amount,
+            btc_address: retrieve_btc_intent.btc_address,
+            ts: Some(ts),
+            memo: Some(memo),
+        };
+
+        let ic_transaction = IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc);
+        let retrieve_btc_tx = Transaction {
+            id: id.to_string(),
+            created_at: ts,
+            state: TransactionState::Created,
+            dependency: None,
+            protocol: Protocol::IC(ic_transaction),
+            group: 1,
+            from_call_type: FromCallType::Canister,
+            start_ts: None,
+            block_index: None,
+        };
+
+        Ok(vec![retrieve_btc_tx])
+    }
+
+    /// Assembles the ICRC7 transfer transaction sending the NFT of the given intent.
+    /// # Arguments
+    /// * `ts` - The timestamp for the transaction.
+    /// * `link_id` - The ID of the link the intent belongs to.
+    /// * `nft_transfer_intent` - The NFT transfer intent containing the collection and token id.
+    /// * `from_call_type` - Whether the user wallet or the canister sends the NFT.
+    /// # Returns
+    /// * `Result<Vec<Transaction>, CanisterError>` - A vector of assembled transactions or an error.
+    fn assemble_icrc7_transfer(
+        &self,
+        ts: u64,
+        link_id: &str,
+        nft_transfer_intent: NftTransferData,
+        from_call_type: FromCallType,
+    ) -> Result<Vec<Transaction>, CanisterError> {
+        let id: Uuid = Uuid::new_v4();
+
+        let memo = to_link_memo(link_id, &id.to_string())?;
+
+        let icrc7_transfer = Icrc7Transfer {
+            from: nft_transfer_intent.from,
+            to: nft_transfer_intent.to,
+            asset: nft_transfer_intent.asset,
+            token_id: nft_transfer_intent.token_id,
+            ts: Some(ts),
+            memo: Some(memo),
+        };
+
+        let ic_transaction = IcTransaction::Icrc7Transfer(icrc7_transfer);
+        let transaction = Transaction {
+            id: id.to_string(),
+            created_at: ts,
+            state: TransactionState::Created,
+            dependency: None,
+            protocol: Protocol::IC(ic_transaction),
+            group: 1,
+            from_call_type,
+            start_ts: None,
+            block_index: None,
+        };
+
+        Ok(vec![transaction])
+    }
 }
 
 impl IntentAdapterTrait for IcIntentAdapter {
     fn intent_to_transactions(
         &self,
         ts: u64,
+        link_id: &str,
         intent: &Intent,
     ) -> Result<Vec<Transaction>, CanisterError> {
         match (intent.task.clone(), intent.r#type.clone()) {
             (IntentTask::TransferWalletToLink, IntentType::Transfer(transfer_intent)) => {
-                self.assemble_icrc1_wallet_transfer(ts, transfer_intent)
+                self.assemble_icrc1_wallet_transfer(ts, link_id, transfer_intent)
             }
             (IntentTask::TransferWalletToTreasury, IntentType::TransferFrom(transfer_intent)) => {
-                self.assemble_icrc2_wallet_transfer(ts, transfer_intent)
+                self.assemble_icrc2_wallet_transfer(ts, link_id, transfer_intent)
             }
             (IntentTask::TransferLinkToWallet, IntentType::Transfer(transfer_intent)) => {
-                self.assemble_icrc1_canister_transfer(ts, transfer_intent)
+                match transfer_intent.to {
+                    Wallet::IcpAccountIdentifier { .. } => {
+                        self.assemble_icp_canister_transfer(ts, transfer_intent)
+                    }
+                    Wallet::IC { .. } => {
+                        self.assemble_icrc1_canister_transfer(ts, link_id, transfer_intent)
+                    }
+                }
+            }
+            (IntentTask::TransferLinkToBitcoin, IntentType::RetrieveBtc(retrieve_btc_intent)) => {
+                self.assemble_ckbtc_canister_retrieve_btc(ts, link_id, retrieve_btc_intent)
+            }
+            (IntentTask::TransferWalletToLink, IntentType::NftTransfer(nft_transfer_intent)) => {
+                self.assemble_icrc7_transfer(ts, link_id, nft_transfer_intent, FromCallType::Wallet)
+            }
+            (IntentTask::TransferLinkToWallet, IntentType::NftTransfer(nft_transfer_intent)) => {
+                self.assemble_icrc7_transfer(
+                    ts,
+                    link_id,
+                    nft_transfer_intent,
+                    FromCallType::Canister,
+                )
             }
             _ => Err(CanisterError::InvalidInput(
                 "Unsupported intent task or type".to_string(),
@@ -208,13 +371,11 @@ impl IntentAdapterTrait for IcIntentAdapter {
 mod tests {
     use super::*;
     use candid::Nat;
-    use cashier_backend_types::repository::{
-        common::{Asset, Wallet},
-        intent::v1::IntentState,
-    };
+    use cashier_backend_types::repository::{common::Asset, intent::v1::IntentState};
     use cashier_common::{
         chain::Chain,
         test_utils::{random_id_string, random_principal_id},
+        utils::{LinkMemo, decode_link_memo},
     };
 
     #[test]
@@ -236,7 +397,7 @@ mod tests {
 
         // Act
         let result = adapter
-            .assemble_icrc1_wallet_transfer(ts, transfer_intent.clone())
+            .assemble_icrc1_wallet_transfer(ts, &random_id_string(), transfer_intent.clone())
             .unwrap();
 
         // Assert
@@ -278,7 +439,7 @@ mod tests {
 
         // Act
         let result = adapter
-            .assemble_icrc2_wallet_transfer(ts, transfer_intent.clone())
+            .assemble_icrc2_wallet_transfer(ts, &random_id_string(), transfer_intent.clone())
             .unwrap();
 
         // Assert
@@ -327,7 +488,7 @@ mod tests {
 
         // Act
         let result = adapter
-            .assemble_icrc1_canister_transfer(ts, transfer_intent.clone())
+            .assemble_icrc1_canister_transfer(ts, &random_id_string(), transfer_intent.clone())
             .unwrap();
 
         // Assert
@@ -361,6 +522,7 @@ mod tests {
             amount: amount.clone(),
         };
 
+        let link_id = random_id_string();
         let intent_id = random_id_string();
         let intent = Intent {
             id: intent_id,
@@ -371,10 +533,13 @@ mod tests {
             dependency: vec![],
             chain: Chain::IC,
             label: "Test Intent".to_string(),
+            link_id: None,
         };
 
         // Act
-        let result = adapter.intent_to_transactions(ts, &intent).unwrap();
+        let result = adapter
+            .intent_to_transactions(ts, &link_id, &intent)
+            .unwrap();
 
         // Assert
         assert_eq!(result.len(), 1);
@@ -413,6 +578,7 @@ mod tests {
             actual_amount: Some(actual_amount.clone()),
         };
 
+        let link_id = random_id_string();
         let intent_id = random_id_string();
         let intent = Intent {
             id: intent_id,
@@ -423,10 +589,13 @@ mod tests {
             dependency: vec![],
             chain: Chain::IC,
             label: "Test Intent".to_string(),
+            link_id: None,
         };
 
         // Act
-        let result = adapter.intent_to_transactions(ts, &intent).unwrap();
+        let result = adapter
+            .intent_to_transactions(ts, &link_id, &intent)
+            .unwrap();
 
         // Assert
         assert_eq!(result.len(), 2);
@@ -471,6 +640,7 @@ mod tests {
             asset,
             amount: amount.clone(),
         };
+        let link_id = random_id_string();
         let intent_id = random_id_string();
         let intent = Intent {
             id: intent_id,
@@ -481,10 +651,13 @@ mod tests {
             dependency: vec![],
             chain: Chain::IC,
             label: "Test Intent".to_string(),
+            link_id: None,
         };
 
         // Act
-        let result = adapter.intent_to_transactions(ts, &intent).unwrap();
+        let result = adapter
+            .intent_to_transactions(ts, &link_id, &intent)
+            .unwrap();
 
         // Assert
         assert_eq!(result.len(), 1);
@@ -498,5 +671,168 @@ mod tests {
         assert_eq!(protocol.amount, amount);
         assert_eq!(protocol.from, transfer_intent.from);
         assert_eq!(protocol.to, transfer_intent.to);
+        let link_memo = decode_link_memo(&protocol.memo.as_ref().unwrap().0).unwrap();
+        assert_eq!(
+            link_memo,
+            LinkMemo::Icrc {
+                link_id: link_id.clone(),
+                transaction_id: tx.id.clone(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_intent_to_transactions_transfer_link_to_icp_account_identifier() {
+        // Arrange
+        let adapter = IcIntentAdapter;
+        let ts = 1_632_144_000; // Example timestamp
+        let transfer_intent = TransferData {
+            from: Wallet::new(random_principal_id()),
+            to: Wallet::IcpAccountIdentifier {
+                account_identifier: Wallet::new(random_principal_id())
+                    .get_icp_account_identifier()
+                    .unwrap()
+                    .to_hex(),
+            },
+            asset: Asset::default(),
+            amount: Nat::from(100_000u64),
+        };
+        let intent = Intent {
+            id: random_id_string(),
+            created_at: ts,
+            task: IntentTask::TransferLinkToWallet,
+            r#type: IntentType::Transfer(transfer_intent.clone()),
+            state: IntentState::Created,
+            dependency: vec![],
+            chain: Chain::IC,
+            label: "Test Intent".to_string(),
+            link_id: None,
+        };
+
+        // Act
+        let result = adapter
+            .intent_to_transactions(ts, &random_id_string(), &intent)
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        let tx = &result[0];
+        assert_eq!(tx.from_call_type, FromCallType::Canister);
+        let protocol = match &tx.protocol {
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => icp_transfer,
+            _ => panic!("Expected IcpTransfer"),
+        };
+        assert_eq!(protocol.amount, transfer_intent.amount);
+        assert_eq!(protocol.from, transfer_intent.from);
+        assert_eq!(protocol.to, transfer_intent.to);
+        assert_eq!(protocol.ts, Some(ts));
+        let link_memo = decode_link_memo(&protocol.memo.unwrap().to_be_bytes()).unwrap();
+        assert_eq!(
+            link_memo,
+            LinkMemo::Icp {
+                transaction_id_prefix: to_icp_link_memo(&tx.id).unwrap(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_intent_to_transactions_transfer_link_to_bitcoin() {
+        // Arrange
+        let adapter = IcIntentAdapter;
+        let ts = 1_632_144_000; // Example timestamp
+        let retrieve_btc_intent = RetrieveBtcData {
+            from: Wallet::new(random_principal_id()),
+            minter: Wallet::new(random_principal_id()),
+            asset: Asset::default(),
+            amount: Nat::from(50_000u64),
+            btc_address: "bcrt1qe8tj5cf8m2y8apvqs8s8sw5yrlmzrfxdnfj8d7".to_string(),
+        };
+        let link_id = random_id_string();
+        let intent = Intent {
+            id: random_id_string(),
+            created_at: ts,
+            task: IntentTask::TransferLinkToBitcoin,
+            r#type: IntentType::RetrieveBtc(retrieve_btc_intent.clone()),
+            state: IntentState::Created,
+            dependency: vec![],
+            chain: Chain::IC,
+            label: "Test Intent".to_string(),
+            link_id: None,
+        };
+
+        // Act
+        let result = adapter
+            .intent_to_transactions(ts, &link_id, &intent)
+            .unwrap();
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        let tx = &result[0];
+        assert_eq!(tx.from_call_type, FromCallType::Canister);
+        assert_eq!(tx.state, TransactionState::Created);
+        let protocol = match &tx.protocol {
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(ckbtc_retrieve_btc)) => ckbtc_retrieve_btc,
+            _ => panic!("Expected CkBtcRetrieveBtc"),
+        };
+        assert_eq!(protocol.amount, retrieve_btc_intent.amount);
+        assert_eq!(protocol.from, retrieve_btc_intent.from);
+        assert_eq!(protocol.minter, retrieve_btc_intent.minter);
+        assert_eq!(protocol.btc_address, retrieve_btc_intent.btc_address);
+        let link_memo = decode_link_memo(&protocol.memo.as_ref().unwrap().0).unwrap();
+        assert!(
+            matches!(link_memo, LinkMemo::Icrc { transaction_id, .. } if transaction_id == tx.id)
+        );
+    }
+
+    #[test]
+    fn test_intent_to_transactions_nft_transfer() {
+        // Arrange
+        let adapter = IcIntentAdapter;
+        let ts = 1_632_144_000; // Example timestamp
+        let nft_transfer_intent = NftTransferData {
+            from: Wallet::new(random_principal_id()),
+            to: Wallet::new(random_principal_id()),
+            asset: Asset::IcNft {
+                address: random_principal_id(),
+            },
+            token_id: Nat::from(7u64),
+        };
+        let link_id = random_id_string();
+        let intent = |task: IntentTask| Intent {
+            id: random_id_string(),
+            created_at: ts,
+            task,
+            r#type: IntentType::NftTransfer(nft_transfer_intent.clone()),
+            state: IntentState::Created,
+            dependency: vec![],
+            chain: Chain::IC,
+            label: "Test Intent".to_string(),
+            link_id: None,
+        };
+
+        // Act
+        let deposit_txs = adapter
+            .intent_to_transactions(ts, &link_id, &intent(IntentTask::TransferWalletToLink))
+            .unwrap();
+        let claim_txs = adapter
+            .intent_to_transactions(ts, &link_id, &intent(IntentTask::TransferLinkToWallet))
+            .unwrap();
+
+        // Assert
+        assert_eq!(deposit_txs.len(), 1);
+        assert_eq!(deposit_txs[0].from_call_type, FromCallType::Wallet);
+        assert_eq!(claim_txs.len(), 1);
+        assert_eq!(claim_txs[0].from_call_type, FromCallType::Canister);
+        let protocol = match &claim_txs[0].protocol {
+            Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => icrc7_transfer,
+            _ => panic!("Expected Icrc7Transfer"),
+        };
+        assert_eq!(protocol.token_id, nft_transfer_intent.token_id);
+        assert_eq!(protocol.from, nft_transfer_intent.from);
+        assert_eq!(protocol.to, nft_transfer_intent.to);
+        let link_memo = decode_link_memo(&protocol.memo.as_ref().unwrap().0).unwrap();
+        assert!(
+            matches!(link_memo, LinkMemo::Icrc { transaction_id, .. } if transaction_id == claim_txs[0].id)
+        );
     }
 }
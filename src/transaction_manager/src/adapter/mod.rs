@@ -12,12 +12,14 @@ pub trait IntentAdapterTrait {
     /// Converts an intent into a list of transactions based on its type.
     /// # Arguments
     /// * `ts` - The timestamp for the transactions.
+    /// * `link_id` - The ID of the link the intent belongs to, encoded in the transaction memos.
     /// * `intent` - The intent to be converted.
     /// # Returns
     /// * `Result<Vec<Transaction>, CanisterError>` - A vector of transactions or an error.
     fn intent_to_transactions(
         &self,
         ts: u64,
+        link_id: &str,
         intent: &Intent,
     ) -> Result<Vec<Transaction>, CanisterError>;
 }
@@ -0,0 +1,53 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::icrc_token::types::Account;
+use candid::Principal;
+use cashier_backend_types::error::CanisterError;
+use ic_cdk::call::{Call, CandidDecodeFailed};
+use token_storage_types::bitcoin::ckbtc_minter::{
+    AccountRetrieveBtcRequest, RetrieveBtcWithApprovalArgs, RetrieveBtcWithApprovalResult,
+};
+
+pub struct CkBtcMinterService(pub Principal);
+
+impl CkBtcMinterService {
+    pub fn new(principal: Principal) -> Self {
+        CkBtcMinterService(principal)
+    }
+
+    /// Calls the retrieve_btc_with_approval method on the ckBTC minter canister.
+    /// The minter burns the ckBTC it was approved to spend and sends the BTC to the given address.
+    /// # Arguments
+    /// * `arg0` - The retrieve BTC arguments
+    /// # Returns
+    /// * `Result<RetrieveBtcWithApprovalResult, CanisterError>` - The resulting retrieve BTC result or an error if the call fails
+    pub async fn retrieve_btc_with_approval(
+        &self,
+        arg0: &RetrieveBtcWithApprovalArgs,
+    ) -> Result<RetrieveBtcWithApprovalResult, CanisterError> {
+        let res = Call::bounded_wait(self.0, "retrieve_btc_with_approval")
+            .with_arg(arg0)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<RetrieveBtcWithApprovalResult, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
+    /// Calls the retrieve_btc_status_v2_by_account method on the ckBTC minter canister.
+    /// # Arguments
+    /// * `account` - The account whose ckBTC was burned for the withdrawals
+    /// # Returns
+    /// * `Result<Vec<AccountRetrieveBtcRequest>, CanisterError>` - The withdrawal requests of the account or an error if the call fails
+    pub async fn retrieve_btc_status_v2_by_account(
+        &self,
+        account: &Account,
+    ) -> Result<Vec<AccountRetrieveBtcRequest>, CanisterError> {
+        let res = Call::bounded_wait(self.0, "retrieve_btc_status_v2_by_account")
+            .with_arg(Some(account))
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Vec<AccountRetrieveBtcRequest>, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+}
@@ -2,7 +2,7 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::adapter::IntentAdapterTrait;
-use crate::icrc112::create_icrc_112_requests;
+use crate::icrc112::{create_icrc_112_requests, skip_covered_approves};
 use crate::{
     adapter::ic::intent::IcIntentAdapter,
     traits::TransactionManager,
@@ -12,16 +12,20 @@ use crate::{
     },
     transaction::{
         ic_transaction_executor::IcTransactionExecutor,
-        ic_transaction_validator::IcTransactionValidator,
+        ic_transaction_validator::{IcTransactionValidator, TransferValidationMode},
+        simulator::TransactionSimulator,
     },
 };
 use cashier_backend_types::{
     error::CanisterError,
-    link_v2::action_result::{CreateActionResult, ProcessActionResult},
-    repository::{action::v1::Action, intent::v1::Intent, transaction::v1::Transaction},
+    link_v2::action_result::{CreateActionResult, ProcessActionResult, SimulateActionResult},
+    repository::{
+        action::v1::{Action, ActionState},
+        intent::v1::Intent,
+        transaction::v1::Transaction,
+    },
 };
 use cashier_common::runtime::IcEnvironment;
-use cashier_common::utils::get_link_account;
 use std::{
     collections::{HashMap, HashSet},
     future::Future,
@@ -52,9 +56,12 @@ impl<E: IcEnvironment> IcTransactionManager<E> {
 impl<E: IcEnvironment> TransactionManager for IcTransactionManager<E> {
     /// Create action by generating transactions from intents,
     /// analyzing dependencies, and creating ICRC-112 requests.
+    /// Approves of a new action already covered by an allowance on the ledger
+    /// are left out of the ICRC-112 requests.
     /// # Arguments
     /// * `action` - The action to be created
     /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - An optional mapping of intent IDs to their associated transactions
     /// # Returns
     /// * `Result<CreateActionResult, CanisterError>` - The result of creating the action
     fn create_action(
@@ -62,61 +69,74 @@ impl<E: IcEnvironment> TransactionManager for IcTransactionManager<E> {
         action: Action,
         intents: Vec<Intent>,
         intent_txs_map: Option<HashMap<String, Vec<Transaction>>>,
-    ) -> Result<CreateActionResult, CanisterError> {
+    ) -> Pin<Box<dyn Future<Output = Result<CreateActionResult, CanisterError>>>> {
         let current_ts = self.ic_env.time();
+        let canister_id = self.ic_env.id();
 
-        // assemble intent transactions
-        let mut transactions = Vec::<Transaction>::new();
-        let mut intent_txs_map = if let Some(map) = intent_txs_map {
-            map
-        } else {
-            let mut intent_txs_map = HashMap::<String, Vec<Transaction>>::new();
+        // only newly assembled approves are checked against the existing allowances,
+        // stored transactions already carry the outcome of that check
+        let is_new_action = intent_txs_map.is_none();
 
-            for intent in intents.iter() {
-                let intent_transactions = self
-                    .intent_adapter
-                    .intent_to_transactions(current_ts, intent)?;
-                transactions.extend(intent_transactions.clone());
-                intent_txs_map.insert(intent.id.clone(), intent_transactions);
-            }
-            intent_txs_map
+        // assemble intent transactions
+        let intent_txs_map = match intent_txs_map {
+            Some(map) => Ok(map),
+            None => intents
+                .iter()
+                .map(|intent| {
+                    self.intent_adapter
+                        .intent_to_transactions(
+                            current_ts,
+                            intent.link_id.as_ref().unwrap_or(&action.link_id),
+                            intent,
+                        )
+                        .map(|txs| (intent.id.clone(), txs))
+                })
+                .collect::<Result<HashMap<String, Vec<Transaction>>, CanisterError>>(),
         };
 
         // transaction with dependencies filled
-        let mut transactions = self
-            .dependency_analyzer
-            .analyze_and_fill_transaction_dependencies(&intents, &intent_txs_map)?;
+        let transactions = intent_txs_map.and_then(|intent_txs_map| {
+            self.dependency_analyzer
+                .analyze_and_fill_transaction_dependencies(&intents, &intent_txs_map)
+                .map(|transactions| (intent_txs_map, transactions))
+        });
 
-        // update intent_txs_map with updated transactions
-        for intent in intents.iter() {
-            let tx_ids = intent_txs_map
-                .get(&intent.id)
-                .unwrap()
-                .iter()
-                .map(|tx| tx.id.clone())
-                .collect::<HashSet<String>>();
+        Box::pin(async move {
+            let (mut intent_txs_map, mut transactions) = transactions?;
 
-            let updated_txs = transactions
-                .iter()
-                .filter(|tx| tx_ids.contains(&tx.id))
-                .cloned()
-                .collect::<Vec<Transaction>>();
+            // leave out the approves already covered by an allowance
+            if is_new_action {
+                skip_covered_approves(&mut transactions, current_ts).await;
+            }
 
-            intent_txs_map.insert(intent.id.clone(), updated_txs);
-        }
+            // update intent_txs_map with updated transactions
+            for intent in intents.iter() {
+                let tx_ids = intent_txs_map
+                    .get(&intent.id)
+                    .unwrap()
+                    .iter()
+                    .map(|tx| tx.id.clone())
+                    .collect::<HashSet<String>>();
 
-        // create ICRC112 requests from transactions
-        let canister_id = self.ic_env.id();
-        let link_account = get_link_account(&action.link_id, canister_id)?;
+                let updated_txs = transactions
+                    .iter()
+                    .filter(|tx| tx_ids.contains(&tx.id))
+                    .cloned()
+                    .collect::<Vec<Transaction>>();
 
-        let icrc112_requests =
-            create_icrc_112_requests(&mut transactions, link_account, canister_id, current_ts)?;
+                intent_txs_map.insert(intent.id.clone(), updated_txs);
+            }
 
-        Ok(CreateActionResult {
-            action,
-            intents,
-            intent_txs_map,
-            icrc112_requests: Some(icrc112_requests),
+            // create ICRC112 requests from transactions
+            let icrc112_requests =
+                create_icrc_112_requests(&mut transactions, canister_id, current_ts)?;
+
+            Ok(CreateActionResult {
+                action,
+                intents,
+                intent_txs_map,
+                icrc112_requests: Some(icrc112_requests),
+            })
         })
     }
 
@@ -149,8 +169,9 @@ impl<E: IcEnvironment> TransactionManager for IcTransactionManager<E> {
 
         // create ICRC112 requests from transactions
         let canister_id = self.ic_env.id();
-        let link_id = action.link_id.clone();
-        let validator_service = ValidatorService::new(Rc::new(IcTransactionValidator));
+        let validator_service = ValidatorService::new(Rc::new(IcTransactionValidator::new(
+            TransferValidationMode::BlockLookup,
+        )));
         let executor_service = ExecutorService::new(Rc::new(IcTransactionExecutor));
 
         Box::pin(async move {
@@ -176,13 +197,8 @@ impl<E: IcEnvironment> TransactionManager for IcTransactionManager<E> {
                 processed_transactions.extend(validate_transactions_result.canister_transactions);
             }
 
-            let link_account = get_link_account(&link_id, canister_id)?;
-            let icrc112_requests = create_icrc_112_requests(
-                &mut processed_transactions,
-                link_account,
-                canister_id,
-                current_ts,
-            )?;
+            let icrc112_requests =
+                create_icrc_112_requests(&mut processed_transactions, canister_id, current_ts)?;
 
             // update intent_txs_map with processed transactions
             let mut updated_intent_txs_map = HashMap::<String, Vec<Transaction>>::new();
@@ -220,6 +236,119 @@ impl<E: IcEnvironment> TransactionManager for IcTransactionManager<E> {
             })
         })
     }
+
+    /// Validate action by checking its wallet transactions against the ledgers
+    /// and rolling up the action and intents states. Nothing is executed.
+    /// # Arguments
+    /// * `action` - The action to be validated
+    /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - A mapping of intent IDs to their associated transactions
+    /// # Returns
+    /// * `Result<ProcessActionResult, CanisterError>` - The result of validating the action
+    fn validate_action(
+        &self,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<ProcessActionResult, CanisterError>>>> {
+        let transactions = intents
+            .iter()
+            .filter_map(|intent| intent_txs_map.get(&intent.id))
+            .flatten()
+            .cloned()
+            .collect::<Vec<Transaction>>();
+        let validator_service = ValidatorService::new(Rc::new(IcTransactionValidator::new(
+            TransferValidationMode::BlockLookup,
+        )));
+
+        Box::pin(async move {
+            let validate_transactions_result = validator_service
+                .validate_action_transactions(&transactions)
+                .await?;
+
+            // canister transactions are left as they are
+            let validated_txs = validate_transactions_result
+                .wallet_transactions
+                .into_iter()
+                .map(|tx| (tx.id.clone(), tx))
+                .collect::<HashMap<String, Transaction>>();
+            let updated_intent_txs_map = intent_txs_map
+                .into_iter()
+                .map(|(intent_id, txs)| {
+                    let txs = txs
+                        .into_iter()
+                        .map(|tx| validated_txs.get(&tx.id).cloned().unwrap_or(tx))
+                        .collect::<Vec<Transaction>>();
+                    (intent_id, txs)
+                })
+                .collect::<HashMap<String, Vec<Transaction>>>();
+
+            let rollup_action_state_result =
+                validator_service.rollup_action_state(action, &intents, updated_intent_txs_map)?;
+
+            Ok(ProcessActionResult {
+                is_success: rollup_action_state_result.action.state == ActionState::Success,
+                action: rollup_action_state_result.action,
+                intents: rollup_action_state_result.intents,
+                intent_txs_map: rollup_action_state_result.intent_txs_map,
+                icrc112_requests: None,
+                errors: validate_transactions_result.errors,
+            })
+        })
+    }
+
+    /// Simulate action by assembling its transactions, checking their dependencies
+    /// and checking the paying accounts' balances and allowances on the ledgers.
+    /// # Arguments
+    /// * `action` - The action to be simulated
+    /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - An optional mapping of intent IDs to their associated transactions
+    /// # Returns
+    /// * `Result<SimulateActionResult, CanisterError>` - The predicted outcome of processing the action
+    fn simulate_action(
+        &self,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: Option<HashMap<String, Vec<Transaction>>>,
+    ) -> Pin<Box<dyn Future<Output = Result<SimulateActionResult, CanisterError>>>> {
+        let current_ts = self.ic_env.time();
+
+        // assemble intent transactions if the action has none yet
+        let intent_txs_map = match intent_txs_map {
+            Some(map) => Ok(map),
+            None => intents
+                .iter()
+                .map(|intent| {
+                    self.intent_adapter
+                        .intent_to_transactions(
+                            current_ts,
+                            intent.link_id.as_ref().unwrap_or(&action.link_id),
+                            intent,
+                        )
+                        .map(|txs| (intent.id.clone(), txs))
+                })
+                .collect::<Result<HashMap<String, Vec<Transaction>>, CanisterError>>(),
+        };
+
+        // a dependency cycle would block processing before any ledger call
+        let transactions = intent_txs_map.and_then(|intent_txs_map| {
+            self.dependency_analyzer
+                .analyze_and_fill_transaction_dependencies(&intents, &intent_txs_map)
+        });
+
+        Box::pin(async move {
+            let errors = match transactions {
+                Ok(transactions) => TransactionSimulator::simulate(&transactions).await,
+                Err(err) => vec![err.to_string()],
+            };
+
+            Ok(SimulateActionResult {
+                action,
+                is_success: errors.is_empty(),
+                errors,
+            })
+        })
+    }
 }
 
 #[cfg(test)]
@@ -249,6 +378,7 @@ mod tests {
             state: ActionState::Created,
             creator: Principal::anonymous(),
             link_id: "11111111-1111-1111-1111-111111111111".to_string(),
+            top_up_count: None,
         };
 
         // prepare an intent that will be converted to an ICRC1 wallet transfer
@@ -263,7 +393,9 @@ mod tests {
         });
 
         // Act
-        let res = manager.create_action(action.clone(), vec![intent.clone()], None);
+        let res = manager
+            .create_action(action.clone(), vec![intent.clone()], None)
+            .await;
         println!("Create action result: {:?}", res);
 
         // Assert
@@ -1,39 +1,45 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use crate::icrc_token::{
+    service::IcrcService,
+    types::{Allowance, AllowanceArgs},
+};
 use crate::utils::topological_sort::kahn_topological_sort;
-use candid::Principal;
+use candid::{Nat, Principal};
 use cashier_backend_types::{
     dto::action::{Icrc112Request, Icrc112Requests},
     error::CanisterError,
     link_v2::graph::Graph,
-    repository::{
-        common::Asset,
-        transaction::v1::{FromCallType, IcTransaction, Protocol, Transaction, TransactionState},
+    repository::transaction::v1::{
+        FromCallType, IcTransaction, Icrc2Approve, Protocol, Transaction, TransactionState,
     },
 };
-use cashier_common::constant::ICRC_TRANSACTION_TIME_WINDOW_NANOSECS;
+use cashier_common::constant::{
+    ICRC_TRANSACTION_TIME_WINDOW_NANOSECS, ICRC2_ALLOWANCE_MIN_REMAINING_NANOSECS,
+    ICRC2_APPROVE_EXPIRY_NANOSECS,
+};
 use cashier_common::utils::nonce_from_tx_id;
 use icrc_112_utils::build_canister_call;
 use icrc_ledger_types::{
     icrc1::{account::Account, transfer::TransferArg},
     icrc2::approve::ApproveArgs,
 };
+use serde_bytes::ByteBuf;
 use std::collections::HashMap;
+use token_storage_types::icrc7::{self, Icrc7TransferArg};
 
 /// Creates ICRC-112 requests from a list of transactions
 /// The input transactions are topologically sorted based on their dependencies,
 /// then converted to ICRC-112 requests.
 /// # Arguments
 /// * `transactions` - A reference to a vector of Transactions
-/// * `link_account` - The account to which the tokens will be transferred
 /// * `canister_id` - The canister ID of the token contract
 /// * `current_ts` - The current timestamp to be used for created_at_time fields
 /// # Returns
 /// * `Result<Icrc112Requests, CanisterError>` - The resulting Icrc112Requests or an error
 pub fn create_icrc_112_requests(
     transactions: &mut [Transaction],
-    link_account: Account,
     canister_id: Principal,
     current_ts: u64,
 ) -> Result<Icrc112Requests, CanisterError> {
@@ -63,8 +69,7 @@ pub fn create_icrc_112_requests(
         let mut group_requests = Vec::<Icrc112Request>::new();
         for tx_id in tx_group.iter() {
             if let Some(tx) = tx_map.get_mut(tx_id) {
-                let icrc_112_request =
-                    convert_tx_to_icrc_112_request(tx, link_account, canister_id, current_ts)?;
+                let icrc_112_request = convert_tx_to_icrc_112_request(tx, canister_id, current_ts)?;
                 group_requests.push(icrc_112_request);
             }
         }
@@ -83,17 +88,71 @@ pub fn create_icrc_112_requests(
     Ok(icrc_112_requests)
 }
 
-/// Converts a Transaction to an Icrc112Request for ICRC-1 or ICRC-2 token transfers.
+/// Marks the wallet ICRC-2 approves already covered by an allowance on the ledger as succeeded,
+/// so that they are left out of the ICRC-112 requests. The amount of an approve includes the allowance
+/// reserved by the other pending actions of its account, so that allowance is never counted twice.
+/// Approves whose allowance cannot be queried are kept.
+/// # Arguments
+/// * `transactions` - A mutable reference to the transactions of the action
+/// * `current_ts` - The current timestamp, used to check the allowance expiration
+pub async fn skip_covered_approves(transactions: &mut [Transaction], current_ts: u64) {
+    for tx in transactions.iter_mut() {
+        if tx.from_call_type != FromCallType::Wallet || tx.state != TransactionState::Created {
+            continue;
+        }
+        let Protocol::IC(IcTransaction::Icrc2Approve(approve)) = &tx.protocol else {
+            continue;
+        };
+
+        if let Ok(allowance) = get_ledger_allowance(approve).await
+            && is_allowance_reusable(&allowance, &approve.amount, current_ts)
+        {
+            tx.state = TransactionState::Success;
+        }
+    }
+}
+
+/// Checks whether an existing allowance covers the amount of an approve
+/// and will not expire before the action is processed.
+/// # Arguments
+/// * `allowance` - The allowance currently granted on the ledger
+/// * `amount` - The amount the approve would grant
+/// * `current_ts` - The current timestamp
+/// # Returns
+/// * `bool` - True if the allowance can be used instead of a new approve
+pub fn is_allowance_reusable(allowance: &Allowance, amount: &Nat, current_ts: u64) -> bool {
+    let min_expires_at = current_ts.saturating_add(ICRC2_ALLOWANCE_MIN_REMAINING_NANOSECS);
+    allowance.allowance >= *amount
+        && allowance
+            .expires_at
+            .is_none_or(|expires_at| expires_at > min_expires_at)
+}
+
+/// Queries the allowance granted on the ledger by the account of an approve to its spender
+/// # Arguments
+/// * `approve` - The approve to be covered
+/// # Returns
+/// * `Result<Allowance, CanisterError>` - The allowance or an error if the call fails
+async fn get_ledger_allowance(approve: &Icrc2Approve) -> Result<Allowance, CanisterError> {
+    let allowance_args = AllowanceArgs {
+        account: approve.from.clone().try_into()?,
+        spender: approve.spender.clone().try_into()?,
+    };
+    IcrcService::new(approve.asset.address())
+        .icrc_2_allowance(&allowance_args)
+        .await
+}
+
+/// Converts a Transaction to an Icrc112Request for ICRC-1 or ICRC-2 token transfers
+/// and ICRC-7 NFT transfers.
 /// # Arguments
 /// * `tx` - The transaction to convert.
-/// * `link_account` - The account to which the tokens will be transferred.
 /// * `canister_id` - The canister ID of the token contract.
 /// * `current_ts` - The current timestamp to be used for the created_at_time field.
 /// # Returns
 /// * `Result<Icrc112Request, CanisterError>` - The resulting Icrc112Request or an error if the conversion fails.
 pub fn convert_tx_to_icrc_112_request(
     tx: &mut Transaction,
-    link_account: Account,
     canister_id: Principal,
     current_ts: u64,
 ) -> Result<Icrc112Request, CanisterError> {
@@ -117,8 +176,15 @@ pub fn convert_tx_to_icrc_112_request(
                 tx_transfer.ts = Some(created_at_time);
             }
 
+            // each deposit pays the link it funds, which is not always the link of the action
+            let to = tx_transfer.to.get_account().ok_or_else(|| {
+                CanisterError::InvalidDataError(
+                    "Transaction destination should be an ICRC account".to_string(),
+                )
+            })?;
+
             let arg = TransferArg {
-                to: link_account,
+                to,
                 amount: tx_transfer.amount.clone(),
                 from_subaccount: None,
                 fee: None,
@@ -126,9 +192,7 @@ pub fn convert_tx_to_icrc_112_request(
                 memo: Some(memo),
             };
 
-            let canister_id = match tx_transfer.asset {
-                Asset::IC { address } => address,
-            };
+            let canister_id = tx_transfer.asset.address();
 
             let canister_call = build_canister_call(&canister_id, "icrc1_transfer", &arg);
             let nonce = nonce_from_tx_id(&tx.id)?;
@@ -168,15 +232,13 @@ pub fn convert_tx_to_icrc_112_request(
                 spender,
                 amount: tx_approve.amount.clone(),
                 expected_allowance: None,
-                expires_at: None,
+                expires_at: Some(current_ts.saturating_add(ICRC2_APPROVE_EXPIRY_NANOSECS)),
                 fee: None,
                 memo: Some(memo),
                 created_at_time: Some(created_at_time),
             };
 
-            let canister_id = match tx_approve.asset {
-                Asset::IC { address } => address,
-            };
+            let canister_id = tx_approve.asset.address();
 
             let canister_call = build_canister_call(&canister_id, "icrc2_approve", &arg);
             let nonce = nonce_from_tx_id(&tx.id)?;
@@ -188,6 +250,56 @@ pub fn convert_tx_to_icrc_112_request(
                 nonce: Some(nonce),
             })
         }
+        Protocol::IC(IcTransaction::Icrc7Transfer(tx_transfer)) => {
+            let memo = tx_transfer.clone().memo.ok_or_else(|| {
+                CanisterError::InvalidDataError("Transaction memo should not be empty".to_string())
+            })?;
+
+            // update the created_at_time to current_ts if the tx created_at_time is outdated
+            let mut created_at_time = tx_transfer.clone().ts.ok_or_else(|| {
+                CanisterError::InvalidDataError(
+                    "Transaction timestamp should not be empty".to_string(),
+                )
+            })?;
+
+            if (current_ts as i64 - created_at_time as i64)
+                > ICRC_TRANSACTION_TIME_WINDOW_NANOSECS as i64
+            {
+                created_at_time = current_ts;
+                tx_transfer.ts = Some(created_at_time);
+            }
+
+            let to = tx_transfer.to.get_account().ok_or_else(|| {
+                CanisterError::InvalidDataError(
+                    "Transaction destination should be an ICRC account".to_string(),
+                )
+            })?;
+
+            let args = vec![Icrc7TransferArg {
+                to: icrc7::Account {
+                    owner: to.owner,
+                    subaccount: to
+                        .subaccount
+                        .map(|subaccount| ByteBuf::from(subaccount.to_vec())),
+                },
+                token_id: tx_transfer.token_id.clone(),
+                memo: Some(memo.0),
+                from_subaccount: None,
+                created_at_time: Some(created_at_time),
+            }];
+
+            let canister_id = tx_transfer.asset.address();
+
+            let canister_call = build_canister_call(&canister_id, "icrc7_transfer", &args);
+            let nonce = nonce_from_tx_id(&tx.id)?;
+
+            Ok(Icrc112Request {
+                canister_id: canister_call.canister_id,
+                method: canister_call.method,
+                arg: canister_call.arg,
+                nonce: Some(nonce),
+            })
+        }
         _ => Err(CanisterError::HandleLogicError(
             "Unsupported transaction protocol for ICRC-112 request conversion".to_string(),
         )),
@@ -199,8 +311,8 @@ mod tests {
     use super::*;
     use candid::Nat;
     use cashier_backend_types::repository::{
-        common::Wallet,
-        transaction::v1::{Icrc1Transfer, Icrc2Approve},
+        common::{Asset, Wallet},
+        transaction::v1::{Icrc1Transfer, Icrc2Approve, Icrc7Transfer},
     };
     use cashier_common::test_utils::{random_id_string, random_principal_id};
     use icrc_ledger_types::icrc1::transfer::Memo;
@@ -222,7 +334,7 @@ mod tests {
             state: TransactionState::Created,
             protocol: Protocol::IC(IcTransaction::Icrc1Transfer(Icrc1Transfer {
                 from,
-                to,
+                to: to.clone(),
                 asset,
                 amount: amount.clone(),
                 memo,
@@ -231,32 +343,29 @@ mod tests {
             dependency: None,
             created_at,
             start_ts: Some(start_ts),
+            block_index: None,
             group: 1u16,
         };
 
-        let link_account = Account {
-            owner: random_principal_id(),
-            subaccount: None,
-        };
         let canister_id = random_principal_id();
         let current_ts = 1_632_192_100_000_000_000;
 
         // Act
         let icrc_112_request =
-            convert_tx_to_icrc_112_request(&mut tx, link_account, canister_id, current_ts).unwrap();
+            convert_tx_to_icrc_112_request(&mut tx, canister_id, current_ts).unwrap();
 
         // Assert
         assert_eq!(
             icrc_112_request.canister_id,
             match &tx.protocol {
                 Protocol::IC(IcTransaction::Icrc1Transfer(tx_transfer)) =>
-                    match tx_transfer.asset {
-                        Asset::IC { address } => address,
-                    },
+                    tx_transfer.asset.address(),
                 _ => panic!("Unexpected protocol type"),
             }
         );
         assert_eq!(icrc_112_request.method, "icrc1_transfer");
+        let arg = candid::decode_one::<TransferArg>(&icrc_112_request.arg).unwrap();
+        assert_eq!(Some(arg.to), to.get_account());
     }
 
     #[test]
@@ -285,33 +394,64 @@ mod tests {
             dependency: None,
             created_at,
             start_ts: Some(start_ts),
+            block_index: None,
             group: 1u16,
         };
 
-        let link_account = Account {
-            owner: random_principal_id(),
-            subaccount: None,
-        };
         let canister_id = random_principal_id();
         let current_ts = 1_632_192_100_000_000_000;
 
         // Act
         let icrc_112_request =
-            convert_tx_to_icrc_112_request(&mut tx, link_account, canister_id, current_ts).unwrap();
+            convert_tx_to_icrc_112_request(&mut tx, canister_id, current_ts).unwrap();
 
         // Assert
         assert_eq!(
             icrc_112_request.canister_id,
             match &tx.protocol {
-                Protocol::IC(IcTransaction::Icrc2Approve(tx_approve)) => match tx_approve.asset {
-                    Asset::IC { address } => address,
-                },
+                Protocol::IC(IcTransaction::Icrc2Approve(tx_approve)) => tx_approve.asset.address(),
                 _ => panic!("Unexpected protocol type"),
             }
         );
         assert_eq!(icrc_112_request.method, "icrc2_approve");
     }
 
+    #[test]
+    fn test_convert_tx_to_icrc_112_request_for_icrc7_transfer() {
+        // Arrange
+        let start_ts = 1_632_192_100_000_000_000u64; // Current timestamp
+        let collection = random_principal_id();
+
+        let mut tx = Transaction {
+            id: random_id_string(),
+            from_call_type: FromCallType::Wallet,
+            state: TransactionState::Created,
+            protocol: Protocol::IC(IcTransaction::Icrc7Transfer(Icrc7Transfer {
+                from: Wallet::new(random_principal_id()),
+                to: Wallet::new(random_principal_id()),
+                asset: Asset::IcNft {
+                    address: collection,
+                },
+                token_id: Nat::from(7u64),
+                memo: Some(Memo::default()),
+                ts: Some(start_ts),
+            })),
+            dependency: None,
+            created_at: start_ts,
+            start_ts: Some(start_ts),
+            block_index: None,
+            group: 1u16,
+        };
+
+        // Act
+        let icrc_112_request =
+            convert_tx_to_icrc_112_request(&mut tx, random_principal_id(), start_ts).unwrap();
+
+        // Assert
+        assert_eq!(icrc_112_request.canister_id, collection);
+        assert_eq!(icrc_112_request.method, "icrc7_transfer");
+    }
+
     #[test]
     fn test_create_icrc112_requests_from_icrc1_transfer_and_icrc2_approve() {
         // Arrange
@@ -341,6 +481,7 @@ mod tests {
             dependency: None,
             created_at,
             start_ts: Some(start_ts),
+            block_index: None,
             group: 1u16,
         };
 
@@ -359,25 +500,83 @@ mod tests {
             dependency: None,
             created_at,
             start_ts: Some(start_ts),
+            block_index: None,
             group: 1u16,
         };
 
         let mut transactions = vec![tx1.clone(), tx2.clone()];
 
-        let link_account = Account {
-            owner: random_principal_id(),
-            subaccount: None,
-        };
         let canister_id = random_principal_id();
         let current_ts = 1_632_192_100_000_000_000;
 
         // Act
         let icrc_112_requests =
-            create_icrc_112_requests(&mut transactions, link_account, canister_id, current_ts)
-                .unwrap();
+            create_icrc_112_requests(&mut transactions, canister_id, current_ts).unwrap();
 
         // Assert
         assert_eq!(icrc_112_requests.len(), 1);
         assert_eq!(icrc_112_requests[0].len(), 2);
     }
+
+    #[test]
+    fn it_should_reuse_allowance_without_expiration() {
+        // Arrange
+        let allowance = Allowance {
+            allowance: Nat::from(20_000u64),
+            expires_at: None,
+        };
+
+        // Act
+        let result = is_allowance_reusable(&allowance, &Nat::from(20_000u64), 1_000);
+
+        // Assert
+        assert!(result);
+    }
+
+    #[test]
+    fn it_should_not_reuse_insufficient_allowance() {
+        // Arrange
+        let allowance = Allowance {
+            allowance: Nat::from(19_999u64),
+            expires_at: None,
+        };
+
+        // Act
+        let result = is_allowance_reusable(&allowance, &Nat::from(20_000u64), 1_000);
+
+        // Assert
+        assert!(!result);
+    }
+
+    #[test]
+    fn it_should_not_reuse_allowance_expiring_soon() {
+        // Arrange
+        let current_ts = 1_632_192_100_000_000_000u64;
+        let allowance = Allowance {
+            allowance: Nat::from(50_000u64),
+            expires_at: Some(current_ts + ICRC2_ALLOWANCE_MIN_REMAINING_NANOSECS),
+        };
+
+        // Act
+        let result = is_allowance_reusable(&allowance, &Nat::from(20_000u64), current_ts);
+
+        // Assert
+        assert!(!result);
+    }
+
+    #[test]
+    fn it_should_reuse_allowance_expiring_later() {
+        // Arrange
+        let current_ts = 1_632_192_100_000_000_000u64;
+        let allowance = Allowance {
+            allowance: Nat::from(50_000u64),
+            expires_at: Some(current_ts + ICRC2_APPROVE_EXPIRY_NANOSECS),
+        };
+
+        // Act
+        let result = is_allowance_reusable(&allowance, &Nat::from(20_000u64), current_ts);
+
+        // Assert
+        assert!(result);
+    }
 }
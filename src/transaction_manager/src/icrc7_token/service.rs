@@ -0,0 +1,49 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_backend_types::error::CanisterError;
+use ic_cdk::call::{Call, CandidDecodeFailed};
+use token_storage_types::icrc7::{Icrc7OwnerOfResponse, Icrc7TransferArg, Icrc7TransferResponse};
+
+pub struct Icrc7Service(pub Principal);
+
+impl Icrc7Service {
+    pub fn new(principal: Principal) -> Self {
+        Icrc7Service(principal)
+    }
+
+    /// Calls the icrc7_owner_of method on the ICRC-7 collection canister
+    /// # Arguments
+    /// * `token_ids` - The ids of the tokens to look up
+    /// # Returns
+    /// * `Result<Icrc7OwnerOfResponse, CanisterError>` - The owner of each token, in the order of `token_ids`, or an error if the call fails
+    pub async fn icrc7_owner_of(
+        &self,
+        token_ids: &[Nat],
+    ) -> Result<Icrc7OwnerOfResponse, CanisterError> {
+        let res = Call::bounded_wait(self.0, "icrc7_owner_of")
+            .with_arg(token_ids)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Icrc7OwnerOfResponse, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
+    /// Calls the icrc7_transfer method on the ICRC-7 collection canister
+    /// # Arguments
+    /// * `args` - The transfer arguments, one per token
+    /// # Returns
+    /// * `Result<Icrc7TransferResponse, CanisterError>` - The result of each transfer, in the order of `args`, or an error if the call fails
+    pub async fn icrc7_transfer(
+        &self,
+        args: &[Icrc7TransferArg],
+    ) -> Result<Icrc7TransferResponse, CanisterError> {
+        let res = Call::bounded_wait(self.0, "icrc7_transfer")
+            .with_arg(args)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Icrc7TransferResponse, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+}
@@ -2,12 +2,14 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::icrc_token::types::{
-    Account, Allowance, AllowanceArgs, Icrc1Tokens, Icrc1TransferResult, TransferArg,
-    TransferFromArgs, TransferFromResult,
+    Account, Allowance, AllowanceArgs, ApproveArgs, ApproveResult, Icrc1Tokens,
+    Icrc1TransferResult, StandardRecord, TransferArg, TransferFromArgs, TransferFromResult,
 };
 use candid::{self, Principal};
 use cashier_backend_types::error::CanisterError;
 use ic_cdk::call::{Call, CandidDecodeFailed};
+use ic_ledger_types::{TransferArgs as IcpTransferArgs, TransferResult as IcpTransferResult};
+use icrc_ledger_types::icrc3::blocks::{ArchivedBlocks, GetBlocksRequest, GetBlocksResult};
 
 pub struct IcrcService(pub Principal);
 
@@ -42,6 +44,17 @@ impl IcrcService {
         parsed_res.map_err(CanisterError::from)
     }
 
+    /// Calls the icrc1_supported_standards method on the ICRC canister
+    /// # Returns
+    /// * `Result<Vec<StandardRecord>, CanisterError>` - The standards supported by the ledger or an error if the call fails
+    pub async fn icrc_1_supported_standards(&self) -> Result<Vec<StandardRecord>, CanisterError> {
+        let res = Call::bounded_wait(self.0, "icrc1_supported_standards")
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<Vec<StandardRecord>, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
     /// Calls the icrc1_transfer method on the ICRC canister
     /// # Arguments
     /// * `arg0` - The transfer arguments
@@ -90,4 +103,69 @@ impl IcrcService {
         let parsed_res: Result<TransferFromResult, CandidDecodeFailed> = res.candid();
         parsed_res.map_err(CanisterError::from)
     }
+
+    /// Calls the icrc2_approve method on the ICRC canister
+    /// # Arguments
+    /// * `arg0` - The approve arguments
+    /// # Returns
+    /// * `Result<ApproveResult, CanisterError>` - The resulting approve result or an error if the call fails
+    pub async fn icrc_2_approve(&self, arg0: &ApproveArgs) -> Result<ApproveResult, CanisterError> {
+        let res = Call::bounded_wait(self.0, "icrc2_approve")
+            .with_arg(arg0)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<ApproveResult, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
+    /// Calls the icrc3_get_blocks method on the ICRC canister
+    /// # Arguments
+    /// * `arg0` - The block ranges to fetch
+    /// # Returns
+    /// * `Result<GetBlocksResult, CanisterError>` - The blocks held by the ledger or an error if the call fails
+    pub async fn icrc_3_get_blocks(
+        &self,
+        arg0: &[GetBlocksRequest],
+    ) -> Result<GetBlocksResult, CanisterError> {
+        let res = Call::bounded_wait(self.0, "icrc3_get_blocks")
+            .with_arg(arg0)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<GetBlocksResult, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
+    /// Calls the callback returned by icrc3_get_blocks for the blocks moved to an archive canister
+    /// # Arguments
+    /// * `archived_blocks` - The archived block ranges and the callback serving them
+    /// # Returns
+    /// * `Result<GetBlocksResult, CanisterError>` - The archived blocks or an error if the call fails
+    pub async fn icrc_3_get_archived_blocks(
+        archived_blocks: &ArchivedBlocks,
+    ) -> Result<GetBlocksResult, CanisterError> {
+        let callback = &archived_blocks.callback;
+        let res = Call::bounded_wait(callback.canister_id, &callback.method)
+            .with_arg(&archived_blocks.args)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<GetBlocksResult, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
+
+    /// Calls the legacy transfer method on the ICP ledger, which pays an account identifier
+    /// # Arguments
+    /// * `arg0` - The transfer arguments
+    /// # Returns
+    /// * `Result<IcpTransferResult, CanisterError>` - The resulting transfer result or an error if the call fails
+    pub async fn icp_transfer(
+        &self,
+        arg0: &IcpTransferArgs,
+    ) -> Result<IcpTransferResult, CanisterError> {
+        let res = Call::bounded_wait(self.0, "transfer")
+            .with_arg(arg0)
+            .await
+            .map_err(CanisterError::from)?;
+        let parsed_res: Result<IcpTransferResult, CandidDecodeFailed> = res.candid();
+        parsed_res.map_err(CanisterError::from)
+    }
 }
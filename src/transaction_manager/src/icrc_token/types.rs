@@ -2,7 +2,7 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{self, CandidType, Deserialize, Principal};
-use cashier_backend_types::repository::common::Wallet;
+use cashier_backend_types::{error::CanisterError, repository::common::Wallet};
 
 pub type SubAccount = serde_bytes::ByteBuf;
 
@@ -21,16 +21,24 @@ impl From<Principal> for Account {
     }
 }
 
-impl From<Wallet> for Account {
-    fn from(wallet: Wallet) -> Self {
+impl TryFrom<Wallet> for Account {
+    type Error = CanisterError;
+
+    fn try_from(wallet: Wallet) -> Result<Self, Self::Error> {
         match wallet {
             Wallet::IC {
                 address,
                 subaccount,
-            } => Account {
+            } => Ok(Account {
                 owner: address,
                 subaccount: subaccount.map(|s| serde_bytes::ByteBuf::from(s.to_vec())),
-            },
+            }),
+            Wallet::IcpAccountIdentifier { account_identifier } => {
+                Err(CanisterError::InvalidInput(format!(
+                    "ICP account identifier {} is not an ICRC account",
+                    account_identifier
+                )))
+            }
         }
     }
 }
@@ -77,6 +85,12 @@ pub enum Icrc1TransferError {
 }
 pub type Icrc1TransferResult = std::result::Result<Icrc1BlockIndex, Icrc1TransferError>;
 
+#[derive(CandidType, Deserialize, Debug, Clone)]
+pub struct StandardRecord {
+    pub name: String,
+    pub url: String,
+}
+
 #[derive(CandidType, Deserialize, Debug)]
 pub struct AllowanceArgs {
     pub account: Account,
@@ -126,3 +140,43 @@ pub enum TransferFromError {
     },
 }
 pub type TransferFromResult = std::result::Result<Icrc1BlockIndex, TransferFromError>;
+
+#[derive(CandidType, Deserialize, Debug)]
+pub struct ApproveArgs {
+    pub fee: Option<Icrc1Tokens>,
+    pub memo: Option<serde_bytes::ByteBuf>,
+    pub from_subaccount: Option<SubAccount>,
+    pub created_at_time: Option<Icrc1Timestamp>,
+    pub amount: Icrc1Tokens,
+    pub expected_allowance: Option<Icrc1Tokens>,
+    pub expires_at: Option<Icrc1Timestamp>,
+    pub spender: Account,
+}
+#[derive(CandidType, Deserialize, Debug)]
+pub enum ApproveError {
+    GenericError {
+        message: String,
+        error_code: candid::Nat,
+    },
+    TemporarilyUnavailable,
+    Duplicate {
+        duplicate_of: Icrc1BlockIndex,
+    },
+    BadFee {
+        expected_fee: Icrc1Tokens,
+    },
+    AllowanceChanged {
+        current_allowance: Icrc1Tokens,
+    },
+    CreatedInFuture {
+        ledger_time: Icrc1Timestamp,
+    },
+    TooOld,
+    Expired {
+        ledger_time: Icrc1Timestamp,
+    },
+    InsufficientFunds {
+        balance: Icrc1Tokens,
+    },
+}
+pub type ApproveResult = std::result::Result<Icrc1BlockIndex, ApproveError>;
@@ -1,6 +1,8 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+pub mod transfer_link_to_bitcoin;
 pub mod transfer_link_to_wallet;
+pub mod transfer_nft;
 pub mod transfer_wallet_to_link;
 pub mod transfer_wallet_to_treasury;
@@ -0,0 +1,129 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    repository::{
+        common::{Asset, Chain, Wallet},
+        intent::v1::{Intent, IntentState, IntentTask, IntentType, RetrieveBtcData},
+    },
+};
+use icrc_ledger_types::icrc1::account::Account;
+use uuid::Uuid;
+
+pub struct TransferLinkToBitcoinIntent {
+    pub intent: Intent,
+}
+
+impl TransferLinkToBitcoinIntent {
+    pub fn new(intent: Intent) -> Self {
+        Self { intent }
+    }
+
+    /// Creates a new TransferLinkToBitcoinIntent, withdrawing ckBTC from the link as native BTC
+    /// # Arguments
+    /// * `label` - A label for the intent
+    /// * `asset` - The ckBTC asset to be withdrawn
+    /// * `sending_amount` - The amount to be withdrawn
+    /// * `btc_address` - The Bitcoin address receiving the BTC
+    /// * `minter_id` - The principal of the ckBTC minter burning the amount
+    /// * `link_account` - The account associated with the link
+    /// * `created_at_ts` - The timestamp when the intent is created
+    /// # Returns
+    /// * `Result<TransferLinkToBitcoinIntent, CanisterError>` - The resulting TransferLinkToBitcoinIntent or an error
+    pub fn create(
+        label: String,
+        asset: Asset,
+        sending_amount: Nat,
+        btc_address: String,
+        minter_id: Principal,
+        link_account: Account,
+        created_at_ts: u64,
+    ) -> Result<Self, CanisterError> {
+        if btc_address.trim().is_empty() {
+            return Err(CanisterError::InvalidInput(
+                "Bitcoin address must not be empty".to_string(),
+            ));
+        }
+
+        let intent = Intent {
+            id: Uuid::new_v4().to_string(),
+            label,
+            state: IntentState::Created,
+            created_at: created_at_ts,
+            dependency: vec![],
+            chain: Chain::IC,
+            task: IntentTask::TransferLinkToBitcoin,
+            r#type: IntentType::RetrieveBtc(RetrieveBtcData {
+                from: link_account.into(),
+                minter: Wallet::new(minter_id),
+                asset,
+                amount: sending_amount,
+                btc_address: btc_address.trim().to_string(),
+            }),
+            link_id: None,
+        };
+
+        Ok(Self::new(intent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_common::test_utils::random_principal_id;
+
+    #[test]
+    fn test_create_transfer_link_to_bitcoin_intent() {
+        // Arrange
+        let minter_id = random_principal_id();
+        let link_account = Account {
+            owner: random_principal_id(),
+            subaccount: Some([1u8; 32]),
+        };
+
+        // Act
+        let intent = TransferLinkToBitcoinIntent::create(
+            "Test Intent".to_string(),
+            Asset::default(),
+            Nat::from(100u64),
+            "bcrt1qe8tj5cf8m2y8apvqs8s8sw5yrlmzrfxdnfj8d7".to_string(),
+            minter_id,
+            link_account,
+            0,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(intent.intent.task, IntentTask::TransferLinkToBitcoin);
+        let retrieve_btc_data = intent.intent.r#type.as_retrieve_btc().unwrap();
+        assert_eq!(retrieve_btc_data.amount, Nat::from(100u64));
+        assert_eq!(retrieve_btc_data.minter, Wallet::new(minter_id));
+        assert_eq!(retrieve_btc_data.from, link_account.into());
+        assert_eq!(
+            retrieve_btc_data.btc_address,
+            "bcrt1qe8tj5cf8m2y8apvqs8s8sw5yrlmzrfxdnfj8d7"
+        );
+    }
+
+    #[test]
+    fn test_create_transfer_link_to_bitcoin_intent_rejects_empty_address() {
+        // Act
+        let result = TransferLinkToBitcoinIntent::create(
+            "Test Intent".to_string(),
+            Asset::default(),
+            Nat::from(100u64),
+            "  ".to_string(),
+            random_principal_id(),
+            Account {
+                owner: random_principal_id(),
+                subaccount: None,
+            },
+            0,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+    }
+}
@@ -38,6 +38,34 @@ impl TransferLinkToWalletIntent {
         receiver_id: Principal,
         link_account: Account,
         created_at_ts: u64,
+    ) -> Result<Self, CanisterError> {
+        Self::create_to_wallet(
+            label,
+            asset,
+            sending_amount,
+            Wallet::new(receiver_id),
+            link_account,
+            created_at_ts,
+        )
+    }
+
+    /// Creates a new TransferLinkToWalletIntent to the given wallet
+    /// # Arguments
+    /// * `label` - A label for the intent
+    /// * `asset` - The asset to be transferred
+    /// * `sending_amount` - The amount to be sent
+    /// * `to_wallet` - The wallet receiving the amount, optionally with a subaccount
+    /// * `link_account` - The account associated with the link
+    /// * `created_at_ts` - The timestamp when the intent is created
+    /// # Returns
+    /// * `Result<TransferLinkToWalletIntent, CanisterError>` - The resulting TransferLinkToWalletIntent or an error
+    pub fn create_to_wallet(
+        label: String,
+        asset: Asset,
+        sending_amount: Nat,
+        to_wallet: Wallet,
+        link_account: Account,
+        created_at_ts: u64,
     ) -> Result<Self, CanisterError> {
         let mut intent = Intent {
             id: Uuid::new_v4().to_string(),
@@ -48,10 +76,10 @@ impl TransferLinkToWalletIntent {
             chain: Chain::IC,
             task: IntentTask::TransferLinkToWallet,
             r#type: IntentType::default_transfer(),
+            link_id: None,
         };
 
         // enrich the intent with asset info
-        let to_wallet = Wallet::new(receiver_id);
         let from_wallet: Wallet = link_account.into();
 
         let mut transfer_data = intent.r#type.as_transfer().ok_or_else(|| {
@@ -113,4 +141,33 @@ mod tests {
         assert_eq!(intent_type.to, Wallet::new(receiver_id));
         assert_eq!(intent_type.from, link_account.into());
     }
+
+    #[test]
+    fn test_create_transfer_link_to_wallet_intent_with_subaccount() {
+        // Arrange
+        let to_wallet = Wallet::new_with_subaccount(random_principal_id(), Some([1u8; 32]));
+        let link_account = Account {
+            owner: random_principal_id(),
+            subaccount: None,
+        };
+
+        // Act
+        let intent = TransferLinkToWalletIntent::create_to_wallet(
+            "Test Intent".to_string(),
+            Asset::default(),
+            Nat::from(100u64),
+            to_wallet.clone(),
+            link_account,
+            0,
+        )
+        .unwrap();
+
+        // Assert
+        let intent_type = match intent.intent.r#type {
+            IntentType::Transfer(transfer_intent) => transfer_intent,
+            _ => panic!("Expected Transfer intent type"),
+        };
+        assert_eq!(intent_type.to, to_wallet);
+        assert_eq!(intent_type.from, link_account.into());
+    }
 }
@@ -0,0 +1,178 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use candid::{Nat, Principal};
+use cashier_backend_types::{
+    error::CanisterError,
+    repository::{
+        common::{Asset, Chain, Wallet},
+        intent::v1::{Intent, IntentState, IntentTask, IntentType, NftTransferData},
+    },
+};
+use icrc_ledger_types::icrc1::account::Account;
+use uuid::Uuid;
+
+pub struct TransferNftIntent {
+    pub intent: Intent,
+}
+
+impl TransferNftIntent {
+    pub fn new(intent: Intent) -> Self {
+        Self { intent }
+    }
+
+    /// Creates a new TransferNftIntent depositing an NFT from the creator's wallet to the link
+    /// # Arguments
+    /// * `label` - A label for the intent
+    /// * `asset` - The NFT collection of the token
+    /// * `token_id` - The id of the token to be transferred
+    /// * `from_user_id` - The principal of the wallet sending the token
+    /// * `link_account` - The account associated with the link
+    /// * `created_at_ts` - The timestamp when the intent is created
+    /// # Returns
+    /// * `Result<TransferNftIntent, CanisterError>` - The resulting TransferNftIntent or an error
+    pub fn create_wallet_to_link(
+        label: String,
+        asset: Asset,
+        token_id: Nat,
+        from_user_id: Principal,
+        link_account: Account,
+        created_at_ts: u64,
+    ) -> Result<Self, CanisterError> {
+        Self::create(
+            label,
+            IntentTask::TransferWalletToLink,
+            NftTransferData {
+                from: Wallet::new(from_user_id),
+                to: link_account.into(),
+                asset,
+                token_id,
+            },
+            created_at_ts,
+        )
+    }
+
+    /// Creates a new TransferNftIntent sending an NFT held by the link to the receiver's wallet
+    /// # Arguments
+    /// * `label` - A label for the intent
+    /// * `asset` - The NFT collection of the token
+    /// * `token_id` - The id of the token to be transferred
+    /// * `to_wallet` - The wallet receiving the token, optionally with a subaccount
+    /// * `link_account` - The account associated with the link
+    /// * `created_at_ts` - The timestamp when the intent is created
+    /// # Returns
+    /// * `Result<TransferNftIntent, CanisterError>` - The resulting TransferNftIntent or an error
+    pub fn create_link_to_wallet(
+        label: String,
+        asset: Asset,
+        token_id: Nat,
+        to_wallet: Wallet,
+        link_account: Account,
+        created_at_ts: u64,
+    ) -> Result<Self, CanisterError> {
+        Self::create(
+            label,
+            IntentTask::TransferLinkToWallet,
+            NftTransferData {
+                from: link_account.into(),
+                to: to_wallet,
+                asset,
+                token_id,
+            },
+            created_at_ts,
+        )
+    }
+
+    fn create(
+        label: String,
+        task: IntentTask,
+        nft_transfer_data: NftTransferData,
+        created_at_ts: u64,
+    ) -> Result<Self, CanisterError> {
+        if !nft_transfer_data.asset.is_nft() {
+            return Err(CanisterError::InvalidInput(
+                "Only NFT assets can be sent with an NFT transfer".to_string(),
+            ));
+        }
+
+        if let Wallet::IcpAccountIdentifier { .. } = nft_transfer_data.to {
+            return Err(CanisterError::InvalidInput(
+                "NFTs can only be sent to an ICRC account".to_string(),
+            ));
+        }
+
+        let intent = Intent {
+            id: Uuid::new_v4().to_string(),
+            label,
+            state: IntentState::Created,
+            created_at: created_at_ts,
+            dependency: vec![],
+            chain: Chain::IC,
+            task,
+            r#type: IntentType::NftTransfer(nft_transfer_data),
+            link_id: None,
+        };
+
+        Ok(Self::new(intent))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_common::test_utils::random_principal_id;
+
+    #[test]
+    fn test_create_transfer_nft_link_to_wallet_intent() {
+        // Arrange
+        let asset = Asset::IcNft {
+            address: random_principal_id(),
+        };
+        let receiver_id = random_principal_id();
+        let link_account = Account {
+            owner: random_principal_id(),
+            subaccount: None,
+        };
+
+        // Act
+        let intent = TransferNftIntent::create_link_to_wallet(
+            "Test Intent".to_string(),
+            asset.clone(),
+            Nat::from(7u64),
+            Wallet::new(receiver_id),
+            link_account,
+            0,
+        )
+        .unwrap();
+
+        // Assert
+        assert_eq!(intent.intent.task, IntentTask::TransferLinkToWallet);
+        let nft_transfer = intent.intent.r#type.as_nft_transfer().unwrap();
+        assert_eq!(nft_transfer.asset, asset);
+        assert_eq!(nft_transfer.token_id, Nat::from(7u64));
+        assert_eq!(nft_transfer.from, link_account.into());
+        assert_eq!(nft_transfer.to, Wallet::new(receiver_id));
+    }
+
+    #[test]
+    fn test_create_transfer_nft_intent_rejects_fungible_asset() {
+        // Arrange
+        let link_account = Account {
+            owner: random_principal_id(),
+            subaccount: None,
+        };
+
+        // Act
+        let result = TransferNftIntent::create_wallet_to_link(
+            "Test Intent".to_string(),
+            Asset::default(),
+            Nat::from(7u64),
+            random_principal_id(),
+            link_account,
+            0,
+        );
+
+        // Assert
+        assert!(matches!(result, Err(CanisterError::InvalidInput(_))));
+    }
+}
@@ -48,6 +48,7 @@ impl TransferWalletToLinkIntent {
             chain: Chain::IC,
             task: IntentTask::TransferWalletToLink,
             r#type: IntentType::default_transfer(),
+            link_id: None,
         };
 
         // enrich the intent with asset info
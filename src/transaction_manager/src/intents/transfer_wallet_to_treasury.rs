@@ -9,7 +9,6 @@ use cashier_backend_types::{
         intent::v1::{Intent, IntentState, IntentTask, IntentType},
     },
 };
-use cashier_common::constant::FEE_TREASURY_PRINCIPAL;
 use icrc_ledger_types::icrc1::account::Account;
 use uuid::Uuid;
 
@@ -30,9 +29,11 @@ impl TransferWalletToTreasuryIntent {
     /// * `approval_amount` - The amount to be approved for transfer.
     /// * `sender_id` - The Principal ID of the sender's wallet.
     /// * `spender_account` - The account which is approved to spend the tokens.
+    /// * `treasury_account` - The account receiving the fee.
     /// * `created_at_ts` - The timestamp when the intent is created.
     /// # Returns
     /// * `Result<TransferWalletToTreasuryIntent, CanisterError>` - The resulting intent or an error if the creation fails.
+    #[allow(clippy::too_many_arguments)]
     pub fn create(
         label: String,
         asset: Asset,
@@ -40,6 +41,7 @@ impl TransferWalletToTreasuryIntent {
         approval_amount: u64,
         sender_id: Principal,
         spender_account: Account,
+        treasury_account: Account,
         created_at_ts: u64,
     ) -> Result<Self, CanisterError> {
         let mut intent = Intent {
@@ -51,15 +53,12 @@ impl TransferWalletToTreasuryIntent {
             chain: Chain::IC,
             task: IntentTask::TransferWalletToTreasury,
             r#type: IntentType::default_transfer_from(),
+            link_id: None,
         };
 
         // enrich the intent with asset info
         let from_wallet = Wallet::new(sender_id);
-        let to_wallet: Wallet = Account {
-            owner: FEE_TREASURY_PRINCIPAL,
-            subaccount: None,
-        }
-        .into();
+        let to_wallet: Wallet = treasury_account.into();
         let spender_wallet: Wallet = spender_account.into();
 
         // TransferFrom case
@@ -96,6 +95,10 @@ mod tests {
             owner: random_principal_id(),
             subaccount: None,
         };
+        let treasury_account = Account {
+            owner: random_principal_id(),
+            subaccount: Some([1u8; 32]),
+        };
         let created_at_ts = 0;
 
         // Act
@@ -106,6 +109,7 @@ mod tests {
             approval_amount,
             sender_id,
             spender_account,
+            treasury_account,
             created_at_ts,
         );
 
@@ -125,5 +129,6 @@ mod tests {
         assert_eq!(intent_type.actual_amount, Some(Nat::from(actual_amount)));
         assert_eq!(intent_type.asset, asset);
         assert_eq!(intent_type.from, Wallet::new(sender_id));
+        assert_eq!(intent_type.to, treasury_account.into());
     }
 }
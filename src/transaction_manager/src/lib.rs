@@ -2,8 +2,10 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 pub mod adapter;
+pub mod ckbtc_minter;
 pub mod ic_transaction_manager;
 pub mod icrc112;
+pub mod icrc7_token;
 pub mod icrc_token;
 pub mod intents;
 pub mod traits;
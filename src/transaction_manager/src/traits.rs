@@ -3,7 +3,7 @@
 
 use cashier_backend_types::{
     error::CanisterError,
-    link_v2::action_result::{CreateActionResult, ProcessActionResult},
+    link_v2::action_result::{CreateActionResult, ProcessActionResult, SimulateActionResult},
     repository::{action::v1::Action, intent::v1::Intent, transaction::v1::Transaction},
 };
 use std::{collections::HashMap, future::Future, pin::Pin};
@@ -23,7 +23,7 @@ pub trait TransactionManager {
         action: Action,
         intents: Vec<Intent>,
         intent_txs_map: Option<HashMap<String, Vec<Transaction>>>,
-    ) -> Result<CreateActionResult, CanisterError>;
+    ) -> Pin<Box<dyn Future<Output = Result<CreateActionResult, CanisterError>>>>;
 
     /// Process the given action by executing the associated transactions
     /// # Arguments
@@ -40,4 +40,38 @@ pub trait TransactionManager {
         intents: Vec<Intent>,
         intent_txs_map: HashMap<String, Vec<Transaction>>,
     ) -> Pin<Box<dyn Future<Output = Result<ProcessActionResult, CanisterError>>>>;
+
+    /// Validate the wallet transactions of the given action against the ledgers and roll up its state,
+    /// without executing any transaction. Canister transactions keep their state.
+    /// # Arguments
+    /// * `action` - The action to be validated
+    /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - A mapping of intent IDs to their corresponding transactions
+    /// # Returns
+    /// * `ProcessActionResult` - The result containing the rolled up action, intents, and transactions
+    /// # Errors
+    /// * `CanisterError` - If the transactions cannot be ordered by their dependencies
+    fn validate_action(
+        &self,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: HashMap<String, Vec<Transaction>>,
+    ) -> Pin<Box<dyn Future<Output = Result<ProcessActionResult, CanisterError>>>>;
+
+    /// Simulate processing the given action against the current ledger state, without making any transfer
+    /// # Arguments
+    /// * `action` - The action to be simulated
+    /// * `intents` - The intents associated with the action
+    /// * `intent_txs_map` - An optional mapping of intent IDs to their corresponding transactions,
+    ///   assembled from the intents if not given
+    /// # Returns
+    /// * `SimulateActionResult` - The predicted outcome and the errors that would block processing
+    /// # Errors
+    /// * `CanisterError` - If the transactions cannot be assembled from the intents
+    fn simulate_action(
+        &self,
+        action: Action,
+        intents: Vec<Intent>,
+        intent_txs_map: Option<HashMap<String, Vec<Transaction>>>,
+    ) -> Pin<Box<dyn Future<Output = Result<SimulateActionResult, CanisterError>>>>;
 }
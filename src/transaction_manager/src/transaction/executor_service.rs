@@ -1,13 +1,16 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
-use crate::transaction::traits::TransactionExecutor;
+use crate::{
+    transaction::traits::TransactionExecutor, utils::topological_sort::kahn_topological_sort,
+};
 use cashier_backend_types::{
     error::CanisterError,
-    link_v2::transaction_manager::ExecuteTransactionsResult,
+    link_v2::{graph::Graph, transaction_manager::ExecuteTransactionsResult},
     repository::transaction::v1::{Transaction, TransactionState},
 };
-use std::rc::Rc;
+use futures::future;
+use std::{collections::HashMap, rc::Rc};
 
 pub struct ExecutorService<E: TransactionExecutor> {
     executor: Rc<E>,
@@ -19,40 +22,102 @@ impl<E: TransactionExecutor> ExecutorService<E> {
     }
 
     /// Executes a list of transactions using the underlying executor.
+    /// Transactions are executed level by level in dependency order, and the transactions
+    /// of a level are executed concurrently. A transaction whose dependency failed is not
+    /// executed and fails as well, and a transaction already executed is kept as is.
     /// # Arguments
     /// * `transactions` - A slice of transactions to be executed
     /// # Returns
-    /// * `Result<ExecuteTransactionsResult, CanisterError>` - The result of executing the transactions
+    /// * `Result<ExecuteTransactionsResult, CanisterError>` - The result of executing the transactions,
+    ///   in the order they were given
     pub async fn execute_transactions(
         &self,
         transactions: &[Transaction],
     ) -> Result<ExecuteTransactionsResult, CanisterError> {
-        let mut executed_transactions = Vec::<Transaction>::new();
+        let mut executed_txs_map = HashMap::<String, Transaction>::new();
         let mut errors = Vec::<String>::new();
-        let mut is_success = true;
-        for transaction in transactions.iter() {
-            match self.executor.execute(transaction.clone()).await {
-                Ok(_executed_tx) => {
-                    let executed_tx = Transaction {
-                        state: TransactionState::Success,
-                        ..transaction.clone()
-                    };
-                    executed_transactions.push(executed_tx);
+
+        let txs_map: HashMap<&str, &Transaction> =
+            transactions.iter().map(|tx| (tx.id.as_str(), tx)).collect();
+
+        // dependencies outside of the given transactions are part of the levels too,
+        // they were settled before execution and are skipped
+        let graph: Graph = transactions.to_vec().into();
+        let levels = kahn_topological_sort(&graph)?;
+
+        for level in levels.iter() {
+            let mut level_transactions = Vec::<&Transaction>::new();
+            for tx_id in level.iter() {
+                let Some(tx) = txs_map.get(tx_id.as_str()) else {
+                    continue;
+                };
+
+                // a transaction already executed is not sent again
+                if tx.state == TransactionState::Success {
+                    executed_txs_map.insert(tx.id.clone(), (*tx).clone());
+                    continue;
                 }
-                Err(err) => {
-                    let failed_tx = Transaction {
-                        state: TransactionState::Fail,
-                        ..transaction.clone()
-                    };
-                    executed_transactions.push(failed_tx);
+
+                let dependency_failed = tx.dependency.iter().flatten().any(|dep| {
+                    executed_txs_map
+                        .get(dep)
+                        .is_some_and(|dep_tx| dep_tx.state == TransactionState::Fail)
+                });
+                if dependency_failed {
+                    executed_txs_map.insert(
+                        tx.id.clone(),
+                        Transaction {
+                            state: TransactionState::Fail,
+                            ..(*tx).clone()
+                        },
+                    );
                     errors.push(format!(
-                        "Transaction {} failed to execute: {}",
-                        transaction.id, err
+                        "Transaction {} was not executed: a dependency failed",
+                        tx.id
                     ));
-                    is_success = false;
+                    continue;
                 }
+
+                level_transactions.push(tx);
+            }
+
+            let results = future::join_all(
+                level_transactions
+                    .iter()
+                    .map(|tx| self.executor.execute((*tx).clone())),
+            )
+            .await;
+
+            for (transaction, result) in level_transactions.into_iter().zip(results) {
+                let executed_tx = match result {
+                    Ok(block_index) => Transaction {
+                        state: TransactionState::Success,
+                        block_index: Some(block_index),
+                        ..transaction.clone()
+                    },
+                    Err(err) => {
+                        errors.push(format!(
+                            "Transaction {} failed to execute: {}",
+                            transaction.id, err
+                        ));
+                        Transaction {
+                            state: TransactionState::Fail,
+                            ..transaction.clone()
+                        }
+                    }
+                };
+                executed_txs_map.insert(transaction.id.clone(), executed_tx);
             }
         }
+
+        let executed_transactions: Vec<Transaction> = transactions
+            .iter()
+            .filter_map(|tx| executed_txs_map.remove(&tx.id))
+            .collect();
+        let is_success = executed_transactions
+            .iter()
+            .all(|tx| tx.state == TransactionState::Success);
+
         Ok(ExecuteTransactionsResult {
             transactions: executed_transactions,
             is_success,
@@ -60,3 +125,135 @@ impl<E: TransactionExecutor> ExecutorService<E> {
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::test_utils::generate_mock_transaction;
+    use candid::Nat;
+    use std::cell::RefCell;
+    use std::future::Future;
+    use std::pin::Pin;
+
+    // Mock TransactionExecutor, failing the configured transactions
+    struct MockExecutor {
+        failing_tx_ids: Vec<String>,
+        executed_tx_ids: RefCell<Vec<String>>,
+    }
+
+    impl MockExecutor {
+        fn new(failing_tx_ids: &[&str]) -> Self {
+            Self {
+                failing_tx_ids: failing_tx_ids.iter().map(ToString::to_string).collect(),
+                executed_tx_ids: RefCell::new(vec![]),
+            }
+        }
+    }
+
+    impl TransactionExecutor for MockExecutor {
+        fn execute(
+            &self,
+            transaction: Transaction,
+        ) -> Pin<Box<dyn Future<Output = Result<Nat, CanisterError>>>> {
+            self.executed_tx_ids
+                .borrow_mut()
+                .push(transaction.id.clone());
+            let fail = self.failing_tx_ids.contains(&transaction.id);
+            Box::pin(async move {
+                if fail {
+                    Err(CanisterError::from("mock fail"))
+                } else {
+                    Ok(Nat::from(1u64))
+                }
+            })
+        }
+    }
+
+    #[tokio::test]
+    async fn it_should_execute_all_transactions_of_a_level() {
+        // Arrange
+        let executor = Rc::new(MockExecutor::new(&["tx1"]));
+        let service = ExecutorService::new(executor.clone());
+        let txs = vec![
+            generate_mock_transaction("tx1", vec![]),
+            generate_mock_transaction("tx2", vec![]),
+            generate_mock_transaction("tx3", vec![]),
+        ];
+
+        // Act
+        let result = service.execute_transactions(&txs).await.unwrap();
+
+        // Assert
+        assert!(!result.is_success);
+        assert_eq!(result.errors.len(), 1);
+        assert_eq!(executor.executed_tx_ids.borrow().len(), 3);
+        let states: Vec<(String, TransactionState)> = result
+            .transactions
+            .iter()
+            .map(|tx| (tx.id.clone(), tx.state.clone()))
+            .collect();
+        assert_eq!(
+            states,
+            vec![
+                ("tx1".to_string(), TransactionState::Fail),
+                ("tx2".to_string(), TransactionState::Success),
+                ("tx3".to_string(), TransactionState::Success),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn it_should_not_execute_transaction_with_failed_dependency() {
+        // Arrange
+        let executor = Rc::new(MockExecutor::new(&["tx1"]));
+        let service = ExecutorService::new(executor.clone());
+        let txs = vec![
+            generate_mock_transaction("tx2", vec!["tx1"]),
+            generate_mock_transaction("tx1", vec![]),
+        ];
+
+        // Act
+        let result = service.execute_transactions(&txs).await.unwrap();
+
+        // Assert
+        assert!(!result.is_success);
+        assert_eq!(result.errors.len(), 2);
+        assert_eq!(*executor.executed_tx_ids.borrow(), vec!["tx1".to_string()]);
+        assert_eq!(result.transactions[0].id, "tx2");
+        assert_eq!(result.transactions[0].state, TransactionState::Fail);
+    }
+
+    #[tokio::test]
+    async fn it_should_not_execute_a_transaction_already_executed() {
+        // Arrange
+        let executor = Rc::new(MockExecutor::new(&[]));
+        let service = ExecutorService::new(executor.clone());
+        let mut executed_tx = generate_mock_transaction("tx1", vec![]);
+        executed_tx.state = TransactionState::Success;
+        let txs = vec![executed_tx, generate_mock_transaction("tx2", vec!["tx1"])];
+
+        // Act
+        let result = service.execute_transactions(&txs).await.unwrap();
+
+        // Assert
+        assert!(result.is_success);
+        assert_eq!(*executor.executed_tx_ids.borrow(), vec!["tx2".to_string()]);
+        assert_eq!(result.transactions[0].state, TransactionState::Success);
+    }
+
+    #[tokio::test]
+    async fn it_should_execute_transaction_with_settled_external_dependency() {
+        // Arrange
+        let executor = Rc::new(MockExecutor::new(&[]));
+        let service = ExecutorService::new(executor.clone());
+        let txs = vec![generate_mock_transaction("tx2", vec!["wallet_tx"])];
+
+        // Act
+        let result = service.execute_transactions(&txs).await.unwrap();
+
+        // Assert
+        assert!(result.is_success);
+        assert_eq!(result.transactions[0].state, TransactionState::Success);
+        assert_eq!(result.transactions[0].block_index, Some(Nat::from(1u64)));
+    }
+}
@@ -1,20 +1,35 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use crate::ckbtc_minter::service::CkBtcMinterService;
 use crate::icrc_token::{
     service::IcrcService,
-    types::{Account, TransferArg, TransferFromArgs},
+    types::{
+        Account, AllowanceArgs, ApproveArgs, ApproveError, Icrc1TransferError, TransferArg,
+        TransferFromArgs,
+    },
 };
+use crate::icrc7_token::service::Icrc7Service;
 use crate::transaction::traits::TransactionExecutor;
-use cashier_backend_types::repository::common::Asset;
+use candid::Nat;
 use cashier_backend_types::repository::transaction::v1::{
-    FromCallType, IcTransaction, Icrc1Transfer, Protocol,
+    CkBtcRetrieveBtc, FromCallType, IcTransaction, IcpTransfer, Icrc1Transfer, Icrc7Transfer,
+    Protocol,
 };
 use cashier_backend_types::{
     error::CanisterError,
     repository::transaction::v1::{Icrc2TransferFrom, Transaction},
 };
+use cashier_common::utils::convert_nat_to_u64;
+use ic_ledger_types::{
+    Memo as IcpMemo, Subaccount as IcpSubaccount, Timestamp, Tokens,
+    TransferArgs as IcpTransferArgs, TransferError as IcpTransferError,
+};
 use std::pin::Pin;
+use token_storage_types::{
+    bitcoin::ckbtc_minter::RetrieveBtcWithApprovalArgs,
+    icrc7::{self, Icrc7TransferArg, Icrc7TransferError},
+};
 
 pub struct IcTransactionExecutor;
 
@@ -23,15 +38,13 @@ impl IcTransactionExecutor {
     /// # Arguments
     /// * `transaction` - The ICRC-2 TransferFrom transaction to be executed
     /// # Returns
-    /// * `Result<(), CanisterError>` - Ok if successful, Err otherwise
+    /// * `Result<Nat, CanisterError>` - The ledger block index if successful, Err otherwise
     async fn execute_icrc2_transfer_from(
         transaction: Icrc2TransferFrom,
-    ) -> Result<(), CanisterError> {
-        let address = match transaction.asset {
-            Asset::IC { address, .. } => address,
-        };
-        let from_account: Account = transaction.from.into();
-        let to_account: Account = transaction.to.into();
+    ) -> Result<Nat, CanisterError> {
+        let address = transaction.asset.address();
+        let from_account: Account = transaction.from.try_into()?;
+        let to_account: Account = transaction.to.try_into()?;
 
         let transfer_arg = TransferFromArgs {
             from: from_account,
@@ -39,55 +52,250 @@ impl IcTransactionExecutor {
             amount: transaction.amount,
             fee: None,
             spender_subaccount: None,
-            memo: None, // TODO
+            memo: transaction.memo.map(|memo| memo.0),
             created_at_time: None,
         };
 
         let icrc_service = IcrcService::new(address);
         let result = icrc_service.icrc_2_transfer_from(&transfer_arg).await?;
 
-        let _block_id = result.map_err(|e| {
+        result.map_err(|e| {
             CanisterError::CallCanisterFailed(format!(
                 "Failed to transfer fee from link creator to treasury: {:?}",
                 e
             ))
-        })?;
-
-        Ok(())
+        })
     }
 
     /// Execute ICRC-1 Transfer transaction
     /// # Arguments
     /// * `transaction` - The ICRC-1 Transfer transaction to be executed
     /// # Returns
-    /// * `Result<(), CanisterError>` - Ok if successful, Err otherwise
-    async fn execute_icrc1_transfer(transaction: Icrc1Transfer) -> Result<(), CanisterError> {
-        let address = match transaction.asset {
-            Asset::IC { address, .. } => address,
-        };
-        let from_account: Account = transaction.from.into();
-        let to_account: Account = transaction.to.into();
+    /// * `Result<Nat, CanisterError>` - The ledger block index if successful, Err otherwise
+    async fn execute_icrc1_transfer(transaction: Icrc1Transfer) -> Result<Nat, CanisterError> {
+        let address = transaction.asset.address();
+        let from_account: Account = transaction.from.try_into()?;
+        let to_account: Account = transaction.to.try_into()?;
 
         let transfer_arg = TransferArg {
             from_subaccount: from_account.subaccount,
             to: to_account,
             amount: transaction.amount,
             fee: None,
-            memo: None, // TODO
+            memo: transaction.memo.map(|memo| memo.0),
             created_at_time: transaction.ts,
         };
 
         let icrc_service = IcrcService::new(address);
         let result = icrc_service.icrc_1_transfer(&transfer_arg).await?;
 
-        let _block_id = result.map_err(|e| {
-            CanisterError::CallCanisterFailed(format!(
+        match result {
+            Ok(block_index) => Ok(block_index),
+            // the same transfer was already executed by an earlier, interrupted call
+            Err(Icrc1TransferError::Duplicate { duplicate_of }) => Ok(duplicate_of),
+            Err(e) => Err(CanisterError::CallCanisterFailed(format!(
                 "Failed to transfer fee from link to wallet: {:?}",
                 e
-            ))
-        })?;
+            ))),
+        }
+    }
+
+    /// Execute a legacy ICP ledger transfer to an account identifier.
+    /// The legacy method requires the exact ledger fee, so it is fetched first.
+    /// # Arguments
+    /// * `transaction` - The ICP transfer transaction to be executed
+    /// # Returns
+    /// * `Result<Nat, CanisterError>` - The ledger block index if successful, Err otherwise
+    async fn execute_icp_transfer(transaction: IcpTransfer) -> Result<Nat, CanisterError> {
+        let address = transaction.asset.address();
+        let from_account: Account = transaction.from.try_into()?;
+        let to = transaction
+            .to
+            .get_icp_account_identifier()
+            .map_err(CanisterError::InvalidInput)?;
+
+        let icrc_service = IcrcService::new(address);
+        let fee = icrc_service.icrc_1_fee().await?;
+
+        let from_subaccount = from_account
+            .subaccount
+            .map(|subaccount| {
+                <[u8; 32]>::try_from(subaccount.as_slice())
+                    .map(IcpSubaccount)
+                    .map_err(|_| CanisterError::InvalidInput("Invalid subaccount".to_string()))
+            })
+            .transpose()?;
+
+        let transfer_args = IcpTransferArgs {
+            memo: IcpMemo(transaction.memo.unwrap_or(0)),
+            amount: Tokens::from_e8s(convert_nat_to_u64(&transaction.amount)?),
+            fee: Tokens::from_e8s(convert_nat_to_u64(&fee)?),
+            from_subaccount,
+            to,
+            created_at_time: transaction
+                .ts
+                .map(|timestamp_nanos| Timestamp { timestamp_nanos }),
+        };
+
+        let result = icrc_service.icp_transfer(&transfer_args).await?;
+
+        match result {
+            Ok(block_index) => Ok(Nat::from(block_index)),
+            // the same transfer was already executed by an earlier, interrupted call
+            Err(IcpTransferError::TxDuplicate { duplicate_of }) => Ok(Nat::from(duplicate_of)),
+            Err(e) => Err(CanisterError::CallCanisterFailed(format!(
+                "Failed to transfer ICP from link to account identifier: {}",
+                e
+            ))),
+        }
+    }
+
+    /// Execute a ckBTC withdrawal to a Bitcoin address.
+    /// The `from` account approves the minter for the amount, then the minter burns it
+    /// and sends the equivalent BTC to the destination address.
+    /// When an earlier, interrupted call already gave the approval and the minter spent it,
+    /// the withdrawal it requested is returned instead of requesting a new one.
+    /// # Arguments
+    /// * `transaction` - The ckBTC withdrawal transaction to be executed
+    /// # Returns
+    /// * `Result<Nat, CanisterError>` - The index of the ledger block burning the ckBTC if successful, Err otherwise
+    async fn execute_ckbtc_retrieve_btc(
+        transaction: CkBtcRetrieveBtc,
+    ) -> Result<Nat, CanisterError> {
+        let address = transaction.asset.address();
+        let from_account: Account = transaction.from.try_into()?;
+        let minter_account: Account = transaction.minter.try_into()?;
+        let amount = convert_nat_to_u64(&transaction.amount)?;
+        let minter_service = CkBtcMinterService::new(minter_account.owner);
+
+        let approve_arg = ApproveArgs {
+            from_subaccount: from_account.subaccount.clone(),
+            spender: minter_account.clone(),
+            amount: transaction.amount.clone(),
+            expected_allowance: None,
+            expires_at: None,
+            fee: None,
+            memo: transaction.memo.map(|memo| memo.0),
+            created_at_time: transaction.ts,
+        };
+
+        let icrc_service = IcrcService::new(address);
+        match icrc_service.icrc_2_approve(&approve_arg).await? {
+            Ok(_) => {}
+            // a duplicate means the approval was already given by an earlier, interrupted call
+            Err(ApproveError::Duplicate { duplicate_of }) => {
+                let allowance = icrc_service
+                    .icrc_2_allowance(&AllowanceArgs {
+                        account: from_account.clone(),
+                        spender: minter_account.clone(),
+                    })
+                    .await?;
+
+                // the minter spent the approval, so that call already requested the withdrawal
+                if allowance.allowance < transaction.amount {
+                    return Self::find_ckbtc_retrieval(
+                        &minter_service,
+                        &from_account,
+                        duplicate_of,
+                    )
+                    .await;
+                }
+            }
+            Err(e) => {
+                return Err(CanisterError::CallCanisterFailed(format!(
+                    "Failed to approve the ckBTC minter: {:?}",
+                    e
+                )));
+            }
+        }
+
+        let retrieve_arg = RetrieveBtcWithApprovalArgs {
+            address: transaction.btc_address,
+            amount,
+            from_subaccount: from_account.subaccount,
+        };
+
+        let result = minter_service
+            .retrieve_btc_with_approval(&retrieve_arg)
+            .await?;
+
+        result
+            .map(|retrieve_btc_ok| Nat::from(retrieve_btc_ok.block_index))
+            .map_err(|e| {
+                CanisterError::CallCanisterFailed(format!(
+                    "Failed to retrieve BTC from the ckBTC minter: {}",
+                    e
+                ))
+            })
+    }
+
+    /// Finds the withdrawal an earlier call requested with the approval given at the given block.
+    /// The minter burns the ckBTC after the approval, so it is the first withdrawal of the
+    /// account burned after it.
+    /// # Arguments
+    /// * `minter_service` - The ckBTC minter
+    /// * `from_account` - The account whose ckBTC was burned
+    /// * `approve_block_index` - The index of the ledger block approving the minter
+    /// # Returns
+    /// * `Result<Nat, CanisterError>` - The index of the ledger block burning the ckBTC if found, Err otherwise
+    async fn find_ckbtc_retrieval(
+        minter_service: &CkBtcMinterService,
+        from_account: &Account,
+        approve_block_index: Nat,
+    ) -> Result<Nat, CanisterError> {
+        let requests = minter_service
+            .retrieve_btc_status_v2_by_account(from_account)
+            .await?;
+
+        requests
+            .into_iter()
+            .map(|request| Nat::from(request.block_index))
+            .filter(|block_index| *block_index > approve_block_index)
+            .min()
+            .ok_or_else(|| {
+                CanisterError::CallCanisterFailed(
+                    "The ckBTC minter approval was spent without a BTC withdrawal".to_string(),
+                )
+            })
+    }
+
+    /// Execute ICRC-7 Transfer transaction
+    /// # Arguments
+    /// * `transaction` - The ICRC-7 Transfer transaction to be executed
+    /// # Returns
+    /// * `Result<Nat, CanisterError>` - The collection transaction index if successful, Err otherwise
+    async fn execute_icrc7_transfer(transaction: Icrc7Transfer) -> Result<Nat, CanisterError> {
+        let address = transaction.asset.address();
+        let from_account: Account = transaction.from.try_into()?;
+        let to_account: Account = transaction.to.try_into()?;
 
-        Ok(())
+        let transfer_arg = Icrc7TransferArg {
+            to: icrc7::Account {
+                owner: to_account.owner,
+                subaccount: to_account.subaccount,
+            },
+            token_id: transaction.token_id,
+            memo: transaction.memo.map(|memo| memo.0),
+            from_subaccount: from_account.subaccount,
+            created_at_time: transaction.ts,
+        };
+
+        let icrc7_service = Icrc7Service::new(address);
+        let mut results = icrc7_service.icrc7_transfer(&[transfer_arg]).await?;
+
+        match results.pop().flatten() {
+            Some(Ok(tx_index)) => Ok(tx_index),
+            // the same transfer was already executed by an earlier, interrupted call
+            Some(Err(Icrc7TransferError::Duplicate { duplicate_of })) => Ok(duplicate_of),
+            Some(Err(e)) => Err(CanisterError::CallCanisterFailed(format!(
+                "Failed to transfer NFT from link to wallet: {}",
+                e
+            ))),
+            None => Err(CanisterError::CallCanisterFailed(
+                "Failed to transfer NFT from link to wallet: transfer was not processed"
+                    .to_string(),
+            )),
+        }
     }
 }
 
@@ -95,7 +303,7 @@ impl TransactionExecutor for IcTransactionExecutor {
     fn execute(
         &self,
         transaction: Transaction,
-    ) -> Pin<Box<dyn Future<Output = Result<(), CanisterError>>>> {
+    ) -> Pin<Box<dyn Future<Output = Result<Nat, CanisterError>>>> {
         Box::pin(async move {
             match transaction.protocol {
                 Protocol::IC(IcTransaction::Icrc2TransferFrom(tx)) => {
@@ -109,6 +317,28 @@ impl TransactionExecutor for IcTransactionExecutor {
                         )),
                     }
                 }
+                Protocol::IC(IcTransaction::CkBtcRetrieveBtc(tx)) => {
+                    match transaction.from_call_type {
+                        FromCallType::Canister => Self::execute_ckbtc_retrieve_btc(tx).await,
+                        FromCallType::Wallet => Err(CanisterError::from(
+                            "ckBTC withdrawal from wallet is not supported",
+                        )),
+                    }
+                }
+                Protocol::IC(IcTransaction::IcpTransfer(tx)) => match transaction.from_call_type {
+                    FromCallType::Canister => Self::execute_icp_transfer(tx).await,
+                    FromCallType::Wallet => Err(CanisterError::from(
+                        "ICP transfer from wallet is not supported",
+                    )),
+                },
+                Protocol::IC(IcTransaction::Icrc7Transfer(tx)) => {
+                    match transaction.from_call_type {
+                        FromCallType::Canister => Self::execute_icrc7_transfer(tx).await,
+                        FromCallType::Wallet => Err(CanisterError::from(
+                            "ICRC-7 transfer from wallet is not supported",
+                        )),
+                    }
+                }
                 _ => Err(CanisterError::from(
                     "Unsupported protocol for IC Transaction Executor",
                 )),
@@ -2,53 +2,251 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use crate::icrc_token::{service::IcrcService, types::AllowanceArgs};
-use crate::transaction::traits::TransactionValidator;
-use cashier_backend_types::repository::{
-    common::Asset,
-    transaction::v1::{
-        FromCallType, IcTransaction, Icrc1Transfer, Icrc2Approve, Protocol, Transaction,
-    },
+use crate::icrc7_token::service::Icrc7Service;
+use crate::transaction::traits::{TransactionValidationError, TransactionValidator};
+use candid::{Nat, Principal};
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::repository::transaction::v1::{
+    FromCallType, IcTransaction, Icrc1Transfer, Icrc2Approve, Icrc7Transfer, Protocol, Transaction,
+};
+use icrc_ledger_types::{
+    icrc::generic_value::ICRC3Value,
+    icrc1::account::Account,
+    icrc3::blocks::{BlockWithId, GetBlocksRequest},
 };
 use std::pin::Pin;
+use token_storage_types::icrc7;
+
+/// Name of the block log standard in `icrc1_supported_standards`
+const ICRC3_STANDARD_NAME: &str = "ICRC-3";
+/// Number of blocks fetched per `icrc3_get_blocks` call
+const BLOCK_PAGE_SIZE: u64 = 100;
+/// Maximum number of ledger and archive calls made to look up the block of a transfer
+const MAX_BLOCK_LOOKUP_CALLS: u64 = 20;
+/// Time in nanoseconds a transfer may be created ahead of the ledger time (ICRC-1 permitted drift)
+const LEDGER_PERMITTED_DRIFT_NANOSECS: u64 = 60 * 1_000_000_000;
 
-pub struct IcTransactionValidator;
+/// How wallet ICRC-1 transfers are proven to have happened
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransferValidationMode {
+    /// The destination balance covers the transfer amount
+    BalanceCheck,
+    /// A ledger block records the transfer, falling back to
+    /// `BalanceCheck` for ledgers without ICRC-3 and transfers without a creation time
+    BlockLookup,
+}
+
+pub struct IcTransactionValidator {
+    pub mode: TransferValidationMode,
+}
 
 impl IcTransactionValidator {
+    pub fn new(mode: TransferValidationMode) -> Self {
+        Self { mode }
+    }
+
     /// Validate ICRC-1 Transfer transaction
     /// # Arguments
     /// * `transaction` - The ICRC-1 Transfer transaction to be validated
+    /// * `block_index` - The index of the block recording the transfer, if it was found before
+    /// * `mode` - How the transfer is proven to have happened
     /// # Returns
-    /// * `Result<(), String>` - Ok if valid, Err with message otherwise
-    pub async fn validate_icrc1_transfer(transaction: Icrc1Transfer) -> Result<(), String> {
-        let address = match transaction.asset {
-            Asset::IC { address, .. } => address,
-        };
+    /// * `Result<Option<Nat>, TransactionValidationError>` - The index of the ledger block recording the transfer,
+    ///   if it was looked up, Err with the reason otherwise
+    pub async fn validate_icrc1_transfer(
+        transaction: Icrc1Transfer,
+        block_index: Option<Nat>,
+        mode: TransferValidationMode,
+    ) -> Result<Option<Nat>, TransactionValidationError> {
+        let address = transaction.asset.address();
 
-        let account = transaction.to.into();
+        if mode == TransferValidationMode::BlockLookup
+            && let Some(created_at_time) = transaction.ts
+            && Self::supports_icrc3(address).await
+        {
+            return Self::find_icrc1_transfer_block(
+                address,
+                &transaction,
+                block_index,
+                created_at_time,
+            )
+            .await
+            .map(Some);
+        }
+
+        Self::validate_icrc1_transfer_balance(transaction)
+            .await
+            .map(|_| None)
+    }
+
+    /// Validate ICRC-1 Transfer transaction against the destination balance
+    /// # Arguments
+    /// * `transaction` - The ICRC-1 Transfer transaction to be validated
+    /// # Returns
+    /// * `Result<(), TransactionValidationError>` - Ok if valid, Err with the reason otherwise
+    pub async fn validate_icrc1_transfer_balance(
+        transaction: Icrc1Transfer,
+    ) -> Result<(), TransactionValidationError> {
+        let address = transaction.asset.address();
+
+        let account = transaction
+            .to
+            .try_into()
+            .map_err(|e: CanisterError| TransactionValidationError::Failed(e.to_string()))?;
         let icrc_service = IcrcService::new(address);
         let balance_res = icrc_service
             .icrc_1_balance_of(&account)
             .await
-            .map_err(|e| format!("Query icrc1 balance failed for canister {}: {}", address, e))?;
+            .map_err(|e| {
+                TransactionValidationError::Unavailable(format!(
+                    "Query icrc1 balance failed for canister {}: {}",
+                    address, e
+                ))
+            })?;
 
         if balance_res < transaction.amount {
-            return Err(format!("Insufficient balance for {} asset", address));
+            return Err(TransactionValidationError::Failed(format!(
+                "Insufficient balance for {} asset",
+                address
+            )));
         }
 
         Ok(())
     }
 
+    /// Returns true if the ledger advertises the ICRC-3 block log
+    /// # Arguments
+    /// * `address` - The ledger canister
+    /// # Returns
+    /// * `bool` - Whether `icrc3_get_blocks` can be used on the ledger
+    async fn supports_icrc3(address: Principal) -> bool {
+        IcrcService::new(address)
+            .icrc_1_supported_standards()
+            .await
+            .is_ok_and(|standards| {
+                standards
+                    .iter()
+                    .any(|standard| standard.name == ICRC3_STANDARD_NAME)
+            })
+    }
+
+    /// Searches the ledger blocks for the block recording the transfer, from the most recent one
+    /// back to the creation time of the transfer, including the blocks moved to archive canisters.
+    /// The block found before is checked first, and the search is given up as unavailable once
+    /// `MAX_BLOCK_LOOKUP_CALLS` calls were made.
+    /// # Arguments
+    /// * `address` - The ledger canister
+    /// * `transaction` - The ICRC-1 Transfer transaction to look up
+    /// * `block_index` - The index of the block recording the transfer, if it was found before
+    /// * `created_at_time` - The creation time of the transfer
+    /// # Returns
+    /// * `Result<Nat, TransactionValidationError>` - The index of the matching block, Err with the reason otherwise
+    pub async fn find_icrc1_transfer_block(
+        address: Principal,
+        transaction: &Icrc1Transfer,
+        block_index: Option<Nat>,
+        created_at_time: u64,
+    ) -> Result<Nat, TransactionValidationError> {
+        let icrc_service = IcrcService::new(address);
+        let mut budget = BlockLookupBudget::new(address, MAX_BLOCK_LOOKUP_CALLS);
+
+        if let Some(block_index) = block_index {
+            let blocks = Self::get_blocks(&icrc_service, &mut budget, block_index, 1).await?;
+            if let Some(block) = find_transfer_block(&blocks, transaction) {
+                return Ok(block.id.clone());
+            }
+        }
+
+        // an empty request returns the current log length
+        budget.spend()?;
+        let log_length = icrc_service
+            .icrc_3_get_blocks(&[])
+            .await
+            .map_err(|e| budget.query_err(e))?
+            .log_length;
+        let log_length = u64::try_from(log_length.0).map_err(|_| {
+            TransactionValidationError::Unavailable(format!(
+                "Invalid icrc3 log length for canister {}",
+                address
+            ))
+        })?;
+
+        // the ledger rejects transfers created later than its time plus the permitted drift,
+        // so no older block can record the transfer
+        let min_block_ts = created_at_time.saturating_sub(LEDGER_PERMITTED_DRIFT_NANOSECS);
+        let mut end = log_length;
+        while end > 0 {
+            let start = end.saturating_sub(BLOCK_PAGE_SIZE);
+            let blocks =
+                Self::get_blocks(&icrc_service, &mut budget, Nat::from(start), end - start).await?;
+
+            if let Some(block) = find_transfer_block(&blocks, transaction) {
+                return Ok(block.id.clone());
+            }
+            if oldest_block_timestamp(&blocks).is_some_and(|ts| ts < min_block_ts) {
+                break;
+            }
+            end = start;
+        }
+
+        Err(TransactionValidationError::Failed(format!(
+            "No ledger block found for the transfer of {} asset",
+            address
+        )))
+    }
+
+    /// Fetches a range of ledger blocks, including the blocks moved to archive canisters
+    /// # Arguments
+    /// * `icrc_service` - The ledger service
+    /// * `budget` - The calls left for the lookup
+    /// * `start` - The index of the first block
+    /// * `length` - The number of blocks
+    /// # Returns
+    /// * `Result<Vec<BlockWithId>, TransactionValidationError>` - The blocks, Err if a call failed or the budget ran out
+    async fn get_blocks(
+        icrc_service: &IcrcService,
+        budget: &mut BlockLookupBudget,
+        start: Nat,
+        length: u64,
+    ) -> Result<Vec<BlockWithId>, TransactionValidationError> {
+        budget.spend()?;
+        let result = icrc_service
+            .icrc_3_get_blocks(&[GetBlocksRequest {
+                start,
+                length: Nat::from(length),
+            }])
+            .await
+            .map_err(|e| budget.query_err(e))?;
+
+        let mut blocks = result.blocks;
+        for archived_blocks in result.archived_blocks.iter() {
+            budget.spend()?;
+            let archived = IcrcService::icrc_3_get_archived_blocks(archived_blocks)
+                .await
+                .map_err(|e| budget.query_err(e))?;
+            blocks.extend(archived.blocks);
+        }
+
+        Ok(blocks)
+    }
+
     /// Validate ICRC-2 Approve transaction
     /// # Arguments
     /// * `transaction` - The ICRC-2 Approve transaction to be validated
     /// # Returns
-    /// * `Result<(), String>` - Ok if valid, Err with message otherwise
-    pub async fn validate_icrc2_approve(transaction: Icrc2Approve) -> Result<(), String> {
-        let address = match transaction.asset {
-            Asset::IC { address, .. } => address,
-        };
-        let from_account = transaction.from.into();
-        let spender_account = transaction.spender.into();
+    /// * `Result<(), TransactionValidationError>` - Ok if valid, Err with the reason otherwise
+    pub async fn validate_icrc2_approve(
+        transaction: Icrc2Approve,
+    ) -> Result<(), TransactionValidationError> {
+        let address = transaction.asset.address();
+        let from_account = transaction
+            .from
+            .try_into()
+            .map_err(|e: CanisterError| TransactionValidationError::Failed(e.to_string()))?;
+        let spender_account = transaction
+            .spender
+            .try_into()
+            .map_err(|e: CanisterError| TransactionValidationError::Failed(e.to_string()))?;
         let icrc_service = IcrcService::new(address);
         let allowance_args = AllowanceArgs {
             account: from_account,
@@ -58,14 +256,53 @@ impl IcTransactionValidator {
             .icrc_2_allowance(&allowance_args)
             .await
             .map_err(|e| {
-                format!(
+                TransactionValidationError::Unavailable(format!(
                     "Query icrc2 allowance failed for canister {}: {}",
                     address, e
-                )
+                ))
             })?;
 
         if allowance_res.allowance < transaction.amount {
-            return Err(format!("Insufficient allowance for {} asset", address));
+            return Err(TransactionValidationError::Failed(format!(
+                "Insufficient allowance for {} asset",
+                address
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Validate ICRC-7 Transfer transaction against the current owner of the token
+    /// # Arguments
+    /// * `transaction` - The ICRC-7 Transfer transaction to be validated
+    /// # Returns
+    /// * `Result<(), TransactionValidationError>` - Ok if the destination owns the token, Err with the reason otherwise
+    pub async fn validate_icrc7_transfer(
+        transaction: Icrc7Transfer,
+    ) -> Result<(), TransactionValidationError> {
+        let address = transaction.asset.address();
+
+        let icrc7_service = Icrc7Service::new(address);
+        let owners = icrc7_service
+            .icrc7_owner_of(std::slice::from_ref(&transaction.token_id))
+            .await
+            .map_err(|e| {
+                TransactionValidationError::Unavailable(format!(
+                    "Query icrc7 owner failed for canister {}: {}",
+                    address, e
+                ))
+            })?;
+
+        let owner = owners.into_iter().next().flatten();
+        let is_owned = transaction
+            .to
+            .get_account()
+            .is_some_and(|account| is_nft_owned_by(owner.as_ref(), account));
+        if !is_owned {
+            return Err(TransactionValidationError::Failed(format!(
+                "Token {} of {} collection is not owned by the receiver",
+                transaction.token_id, address
+            )));
         }
 
         Ok(())
@@ -76,29 +313,389 @@ impl TransactionValidator for IcTransactionValidator {
     fn validate_success(
         &self,
         transaction: Transaction,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>>>> {
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>> {
+        let mode = self.mode;
+
         Box::pin(async move {
             match transaction.protocol {
                 Protocol::IC(IcTransaction::Icrc1Transfer(icrc1_transfer)) => {
                     match transaction.from_call_type {
-                        FromCallType::Wallet => Self::validate_icrc1_transfer(icrc1_transfer).await,
-                        FromCallType::Canister => {
-                            Err("ICRC-1 transfer from canister not supported for validation"
-                                .to_string())
+                        FromCallType::Wallet => {
+                            Self::validate_icrc1_transfer(
+                                icrc1_transfer,
+                                transaction.block_index,
+                                mode,
+                            )
+                            .await
                         }
+                        FromCallType::Canister => Err(TransactionValidationError::Failed(
+                            "ICRC-1 transfer from canister not supported for validation"
+                                .to_string(),
+                        )),
                     }
                 }
                 Protocol::IC(IcTransaction::Icrc2Approve(icrc2_approve)) => {
                     match transaction.from_call_type {
-                        FromCallType::Wallet => Self::validate_icrc2_approve(icrc2_approve).await,
-                        FromCallType::Canister => {
-                            Err("ICRC-2 approve from canister not supported for validation"
-                                .to_string())
-                        }
+                        FromCallType::Wallet => Self::validate_icrc2_approve(icrc2_approve)
+                            .await
+                            .map(|_| None),
+                        FromCallType::Canister => Err(TransactionValidationError::Failed(
+                            "ICRC-2 approve from canister not supported for validation".to_string(),
+                        )),
+                    }
+                }
+                Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => {
+                    match transaction.from_call_type {
+                        FromCallType::Wallet => Self::validate_icrc7_transfer(icrc7_transfer)
+                            .await
+                            .map(|_| None),
+                        FromCallType::Canister => Err(TransactionValidationError::Failed(
+                            "ICRC-7 transfer from canister not supported for validation"
+                                .to_string(),
+                        )),
                     }
                 }
-                _ => Err("Unsupported transaction protocol for validation".to_string()),
+                _ => Err(TransactionValidationError::Failed(
+                    "Unsupported transaction protocol for validation".to_string(),
+                )),
             }
         })
     }
 }
+
+/// The ledger and archive calls a block lookup may still make
+pub struct BlockLookupBudget {
+    address: Principal,
+    calls_left: u64,
+}
+
+impl BlockLookupBudget {
+    pub fn new(address: Principal, calls: u64) -> Self {
+        Self {
+            address,
+            calls_left: calls,
+        }
+    }
+
+    /// Takes one call from the budget
+    /// # Returns
+    /// * `Result<(), TransactionValidationError>` - Unavailable once no call is left
+    pub fn spend(&mut self) -> Result<(), TransactionValidationError> {
+        if self.calls_left == 0 {
+            return Err(TransactionValidationError::Unavailable(format!(
+                "Block lookup budget exhausted for canister {}",
+                self.address
+            )));
+        }
+        self.calls_left -= 1;
+        Ok(())
+    }
+
+    fn query_err(&self, e: CanisterError) -> TransactionValidationError {
+        TransactionValidationError::Unavailable(format!(
+            "Query icrc3 blocks failed for canister {}: {}",
+            self.address, e
+        ))
+    }
+}
+
+/// Finds the ledger block recording the given ICRC-1 transfer.
+/// A block matches when its `from`, `to`, amount and memo equal the transfer's.
+/// # Arguments
+/// * `blocks` - The ledger blocks to search
+/// * `transaction` - The ICRC-1 Transfer transaction to look up
+/// # Returns
+/// * `Option<&BlockWithId>` - The matching block, if any
+pub fn find_transfer_block<'a>(
+    blocks: &'a [BlockWithId],
+    transaction: &Icrc1Transfer,
+) -> Option<&'a BlockWithId> {
+    let (Some(from), Some(to)) = (transaction.from.get_account(), transaction.to.get_account())
+    else {
+        return None;
+    };
+    let from = normalize_account(from);
+    let to = normalize_account(to);
+    let memo = transaction.memo.as_ref().map(|memo| memo.0.as_slice());
+
+    blocks.iter().find(|block| {
+        let ICRC3Value::Map(block_map) = &block.block else {
+            return false;
+        };
+        let Some(ICRC3Value::Map(tx)) = block_map.get("tx") else {
+            return false;
+        };
+
+        let is_transfer = match (block_map.get("btype"), tx.get("op")) {
+            (Some(ICRC3Value::Text(btype)), _) => btype == "1xfer",
+            (None, Some(ICRC3Value::Text(op))) => op == "xfer",
+            _ => false,
+        };
+        let block_memo = match tx.get("memo") {
+            Some(ICRC3Value::Blob(block_memo)) => Some(block_memo.as_slice()),
+            _ => None,
+        };
+
+        is_transfer
+            && tx.get("from").and_then(parse_account).as_ref() == Some(&from)
+            && tx.get("to").and_then(parse_account).as_ref() == Some(&to)
+            && matches!(tx.get("amt"), Some(ICRC3Value::Nat(amount)) if *amount == transaction.amount)
+            && block_memo == memo
+    })
+}
+
+/// Returns the timestamp of the oldest of the given ledger blocks
+/// # Arguments
+/// * `blocks` - The ledger blocks
+/// # Returns
+/// * `Option<u64>` - The oldest block timestamp, None if no block has one
+pub fn oldest_block_timestamp(blocks: &[BlockWithId]) -> Option<u64> {
+    blocks
+        .iter()
+        .filter_map(|block| match &block.block {
+            ICRC3Value::Map(block_map) => match block_map.get("ts") {
+                Some(ICRC3Value::Nat(ts)) => u64::try_from(ts.0.clone()).ok(),
+                _ => None,
+            },
+            _ => None,
+        })
+        .min()
+}
+
+/// Parses an ICRC-3 encoded account, `[owner]` or `[owner, subaccount]`.
+/// The default subaccount is normalized to `None`.
+fn parse_account(value: &ICRC3Value) -> Option<Account> {
+    let ICRC3Value::Array(parts) = value else {
+        return None;
+    };
+    let owner = match parts.first() {
+        Some(ICRC3Value::Blob(owner)) => Principal::try_from_slice(owner).ok()?,
+        _ => return None,
+    };
+    let subaccount = match parts.get(1) {
+        Some(ICRC3Value::Blob(subaccount)) => {
+            Some(<[u8; 32]>::try_from(subaccount.as_slice()).ok()?)
+        }
+        None => None,
+        _ => return None,
+    };
+
+    Some(normalize_account(Account { owner, subaccount }))
+}
+
+/// Returns true if the ICRC-7 owner of a token is the given account.
+/// # Arguments
+/// * `owner` - The owner returned by `icrc7_owner_of`, `None` for unknown tokens
+/// * `account` - The account expected to own the token
+/// # Returns
+/// * `bool` - Whether both accounts are the same, the default subaccount being normalized
+pub fn is_nft_owned_by(owner: Option<&icrc7::Account>, account: Account) -> bool {
+    let Some(owner) = owner else {
+        return false;
+    };
+    let owner_subaccount = match &owner.subaccount {
+        Some(subaccount) => match <[u8; 32]>::try_from(subaccount.as_slice()) {
+            Ok(subaccount) => Some(subaccount),
+            Err(_) => return false,
+        },
+        None => None,
+    };
+
+    normalize_account(Account {
+        owner: owner.owner,
+        subaccount: owner_subaccount,
+    }) == normalize_account(account)
+}
+
+fn normalize_account(account: Account) -> Account {
+    Account {
+        owner: account.owner,
+        subaccount: account
+            .subaccount
+            .filter(|subaccount| *subaccount != [0u8; 32]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use cashier_backend_types::repository::common::{Asset, Wallet};
+    use cashier_common::test_utils::random_principal_id;
+    use icrc_ledger_types::icrc1::transfer::Memo;
+    use serde_bytes::ByteBuf;
+    use std::collections::BTreeMap;
+
+    fn encode_account(account: &Account) -> ICRC3Value {
+        let mut parts = vec![ICRC3Value::Blob(ByteBuf::from(
+            account.owner.as_slice().to_vec(),
+        ))];
+        if let Some(subaccount) = account.subaccount {
+            parts.push(ICRC3Value::Blob(ByteBuf::from(subaccount.to_vec())));
+        }
+        ICRC3Value::Array(parts)
+    }
+
+    fn transfer_block(id: u64, transfer: &Icrc1Transfer, amount: Nat) -> BlockWithId {
+        let mut tx = BTreeMap::new();
+        tx.insert("op".to_string(), ICRC3Value::Text("xfer".to_string()));
+        tx.insert(
+            "from".to_string(),
+            encode_account(&transfer.from.get_account().unwrap()),
+        );
+        tx.insert(
+            "to".to_string(),
+            encode_account(&transfer.to.get_account().unwrap()),
+        );
+        tx.insert("amt".to_string(), ICRC3Value::Nat(amount));
+        if let Some(memo) = &transfer.memo {
+            tx.insert("memo".to_string(), ICRC3Value::Blob(memo.0.clone()));
+        }
+
+        let mut block = BTreeMap::new();
+        block.insert("tx".to_string(), ICRC3Value::Map(tx));
+        BlockWithId {
+            id: Nat::from(id),
+            block: ICRC3Value::Map(block),
+        }
+    }
+
+    fn wallet_to_link_transfer() -> Icrc1Transfer {
+        Icrc1Transfer {
+            from: Wallet::IC {
+                address: random_principal_id(),
+                subaccount: None,
+            },
+            to: Wallet::IC {
+                address: random_principal_id(),
+                subaccount: Some([7u8; 32]),
+            },
+            asset: Asset::IC {
+                address: random_principal_id(),
+            },
+            amount: Nat::from(1_000u64),
+            memo: Some(Memo(ByteBuf::from(vec![1u8; 32]))),
+            ts: None,
+        }
+    }
+
+    #[test]
+    fn it_should_find_block_matching_transfer() {
+        // Arrange
+        let transfer = wallet_to_link_transfer();
+        let blocks = vec![
+            transfer_block(10, &transfer, Nat::from(999u64)),
+            transfer_block(11, &transfer, transfer.amount.clone()),
+        ];
+
+        // Act
+        let result = find_transfer_block(&blocks, &transfer);
+
+        // Assert
+        assert_eq!(result.map(|block| block.id.clone()), Some(Nat::from(11u64)));
+    }
+
+    #[test]
+    fn it_should_not_match_block_with_other_memo() {
+        // Arrange
+        let transfer = wallet_to_link_transfer();
+        let mut other_transfer = transfer.clone();
+        other_transfer.memo = Some(Memo(ByteBuf::from(vec![2u8; 32])));
+        let blocks = vec![transfer_block(10, &other_transfer, transfer.amount.clone())];
+
+        // Act
+        let result = find_transfer_block(&blocks, &transfer);
+
+        // Assert
+        assert!(result.is_none());
+    }
+
+    #[test]
+    fn it_should_treat_default_subaccount_as_none() {
+        // Arrange
+        let transfer = wallet_to_link_transfer();
+        let mut block_transfer = transfer.clone();
+        block_transfer.from = Wallet::IC {
+            address: transfer.from.get_account().unwrap().owner,
+            subaccount: Some([0u8; 32]),
+        };
+        let blocks = vec![transfer_block(3, &block_transfer, transfer.amount.clone())];
+
+        // Act
+        let result = find_transfer_block(&blocks, &transfer);
+
+        // Assert
+        assert!(result.is_some());
+    }
+
+    #[test]
+    fn it_should_match_nft_owner_with_link_subaccount() {
+        // Arrange
+        let owner = random_principal_id();
+        let subaccount = [3u8; 32];
+        let nft_owner = icrc7::Account {
+            owner,
+            subaccount: Some(ByteBuf::from(subaccount.to_vec())),
+        };
+
+        // Act
+        let owned = is_nft_owned_by(
+            Some(&nft_owner),
+            Account {
+                owner,
+                subaccount: Some(subaccount),
+            },
+        );
+        let not_owned = is_nft_owned_by(
+            Some(&nft_owner),
+            Account {
+                owner,
+                subaccount: None,
+            },
+        );
+
+        // Assert
+        assert!(owned);
+        assert!(!not_owned);
+        assert!(!is_nft_owned_by(None, Account::from(owner)));
+    }
+
+    #[test]
+    fn it_should_give_up_a_block_lookup_once_its_budget_is_spent() {
+        // Arrange
+        let mut budget = BlockLookupBudget::new(random_principal_id(), 2);
+
+        // Act
+        let spent = [budget.spend(), budget.spend(), budget.spend()];
+
+        // Assert
+        assert!(spent[0].is_ok());
+        assert!(spent[1].is_ok());
+        assert!(matches!(
+            spent[2],
+            Err(TransactionValidationError::Unavailable(_))
+        ));
+    }
+
+    #[test]
+    fn it_should_return_the_oldest_block_timestamp() {
+        // Arrange
+        let transfer = wallet_to_link_transfer();
+        let mut blocks = vec![
+            transfer_block(10, &transfer, Nat::from(1_000u64)),
+            transfer_block(11, &transfer, Nat::from(1_000u64)),
+            transfer_block(12, &transfer, Nat::from(1_000u64)),
+        ];
+        for (block, ts) in blocks.iter_mut().zip([2_000u64, 1_000, 3_000]) {
+            if let ICRC3Value::Map(block_map) = &mut block.block {
+                block_map.insert("ts".to_string(), ICRC3Value::Nat(Nat::from(ts)));
+            }
+        }
+
+        // Act
+        let oldest = oldest_block_timestamp(&blocks);
+
+        // Assert
+        assert_eq!(oldest, Some(1_000));
+        assert_eq!(oldest_block_timestamp(&[]), None);
+    }
+}
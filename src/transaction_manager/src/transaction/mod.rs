@@ -5,5 +5,6 @@ pub mod dependency_analyzer;
 pub mod executor_service;
 pub mod ic_transaction_executor;
 pub mod ic_transaction_validator;
+pub mod simulator;
 pub mod traits;
 pub mod validator_service;
@@ -0,0 +1,260 @@
+// Copyright (c) 2025 Cashier Protocol Labs
+// Licensed under the MIT License (see LICENSE file in the project root)
+
+use crate::icrc_token::{service::IcrcService, types::AllowanceArgs};
+use crate::icrc7_token::service::Icrc7Service;
+use crate::transaction::ic_transaction_validator::is_nft_owned_by;
+use crate::utils::{
+    calculator::calculate_required_balance_map, topological_sort::kahn_topological_sort_flat,
+};
+use candid::{Nat, Principal};
+use cashier_backend_types::error::CanisterError;
+use cashier_backend_types::link_v2::graph::Graph;
+use cashier_backend_types::repository::transaction::v1::{
+    FromCallType, IcTransaction, Icrc2TransferFrom, Protocol, Transaction, TransactionState,
+};
+use futures::future;
+use std::collections::{BTreeSet, HashMap};
+
+pub struct TransactionSimulator;
+
+impl TransactionSimulator {
+    /// Simulates the execution of the given transactions against the current ledger
+    /// balances and allowances, without making any transfer.
+    /// Transactions that already succeeded are ignored.
+    /// # Arguments
+    /// * `transactions` - The transactions of an action, with their dependencies filled
+    /// # Returns
+    /// * `Vec<String>` - The errors that would block the execution, the most blocking first
+    pub async fn simulate(transactions: &[Transaction]) -> Vec<String> {
+        let mut errors = Vec::<String>::new();
+        let pending_transactions: Vec<Transaction> = transactions
+            .iter()
+            .filter(|tx| tx.state != TransactionState::Success)
+            .cloned()
+            .collect();
+
+        let fee_map = match Self::get_fee_map(&pending_transactions).await {
+            Ok(fee_map) => fee_map,
+            Err(err) => return vec![err],
+        };
+
+        // every paying account must hold the amounts it sends, ledger fees included,
+        // less the amounts it receives first from the same action
+        let ordered_transactions = match Self::sort_in_execution_order(&pending_transactions) {
+            Ok(ordered_transactions) => ordered_transactions,
+            Err(err) => return vec![err.to_string()],
+        };
+        let required_balance_map = calculate_required_balance_map(&ordered_transactions, &fee_map);
+        let balance_results = future::join_all(required_balance_map.keys().map(
+            |(wallet, asset)| async move {
+                let address = asset.address();
+                let account = wallet.clone().try_into().map_err(|e| {
+                    format!("Query icrc1 balance failed for canister {}: {}", address, e)
+                })?;
+                IcrcService::new(address)
+                    .icrc_1_balance_of(&account)
+                    .await
+                    .map_err(|e| {
+                        format!("Query icrc1 balance failed for canister {}: {}", address, e)
+                    })
+            },
+        ))
+        .await;
+
+        for (((_wallet, asset), required_balance), balance_result) in
+            required_balance_map.iter().zip(balance_results)
+        {
+            let address = asset.address();
+            match balance_result {
+                Ok(balance) if balance < *required_balance => {
+                    errors.push(format!("Insufficient balance for {} asset", address));
+                }
+                Ok(_) => {}
+                Err(err) => errors.push(err),
+            }
+        }
+
+        // every transfer_from must be covered by an allowance, granted by a pending
+        // approval of the same action or already on the ledger
+        for transfer_from in pending_transactions
+            .iter()
+            .filter_map(|tx| match &tx.protocol {
+                Protocol::IC(IcTransaction::Icrc2TransferFrom(transfer_from)) => {
+                    Some(transfer_from)
+                }
+                _ => None,
+            })
+        {
+            let address = transfer_from.asset.address();
+            let fee = fee_map.get(&address).cloned().unwrap_or(Nat::from(0u64));
+            let required_allowance = transfer_from.amount.clone() + fee;
+
+            let allowance =
+                match Self::find_pending_approve_amount(&pending_transactions, transfer_from) {
+                    Some(amount) => Ok(amount),
+                    None => Self::get_ledger_allowance(address, transfer_from)
+                        .await
+                        .map_err(|e| {
+                            format!(
+                                "Query icrc2 allowance failed for canister {}: {}",
+                                address, e
+                            )
+                        }),
+                };
+
+            match allowance {
+                Ok(allowance) if allowance < required_allowance => {
+                    errors.push(format!("Insufficient allowance for {} asset", address));
+                }
+                Ok(_) => {}
+                Err(err) => errors.push(err),
+            }
+        }
+
+        // every NFT must still be owned by the account sending it
+        for icrc7_transfer in pending_transactions
+            .iter()
+            .filter_map(|tx| match &tx.protocol {
+                Protocol::IC(IcTransaction::Icrc7Transfer(icrc7_transfer)) => Some(icrc7_transfer),
+                _ => None,
+            })
+        {
+            let address = icrc7_transfer.asset.address();
+            let owner_result = Icrc7Service::new(address)
+                .icrc7_owner_of(std::slice::from_ref(&icrc7_transfer.token_id))
+                .await;
+
+            match owner_result {
+                Ok(owners) => {
+                    let owner = owners.into_iter().next().flatten();
+                    let is_owned = icrc7_transfer
+                        .from
+                        .get_account()
+                        .is_some_and(|account| is_nft_owned_by(owner.as_ref(), account));
+                    if !is_owned {
+                        errors.push(format!(
+                            "Token {} of {} collection is not owned by the sender",
+                            icrc7_transfer.token_id, address
+                        ));
+                    }
+                }
+                Err(e) => errors.push(format!(
+                    "Query icrc7 owner failed for canister {}: {}",
+                    address, e
+                )),
+            }
+        }
+
+        errors
+    }
+
+    /// Orders the transactions as they are processed: wallet transactions are validated
+    /// before canister transactions are executed, each in dependency order
+    /// # Arguments
+    /// * `transactions` - The transactions to be simulated
+    /// # Returns
+    /// * `Result<Vec<Transaction>, CanisterError>` - The ordered transactions, Err if their dependencies have a cycle
+    fn sort_in_execution_order(
+        transactions: &[Transaction],
+    ) -> Result<Vec<Transaction>, CanisterError> {
+        let graph: Graph = transactions.to_vec().into();
+        let sorted_ids = kahn_topological_sort_flat(&graph)?;
+
+        let txs_map: HashMap<&str, &Transaction> =
+            transactions.iter().map(|tx| (tx.id.as_str(), tx)).collect();
+        let mut sorted_transactions: Vec<Transaction> = sorted_ids
+            .iter()
+            .filter_map(|tx_id| txs_map.get(tx_id.as_str()).map(|tx| (*tx).clone()))
+            .collect();
+        sorted_transactions.sort_by_key(|tx| tx.from_call_type == FromCallType::Canister);
+
+        Ok(sorted_transactions)
+    }
+
+    /// Fetches the ledger fee of every fungible asset used by the transactions
+    /// # Arguments
+    /// * `transactions` - The transactions to be simulated
+    /// # Returns
+    /// * `Result<HashMap<Principal, Nat>, String>` - A map of token principal to its fee, Err with message otherwise
+    async fn get_fee_map(transactions: &[Transaction]) -> Result<HashMap<Principal, Nat>, String> {
+        let addresses: BTreeSet<Principal> = transactions
+            .iter()
+            .filter_map(|tx| {
+                let asset = match &tx.protocol {
+                    Protocol::IC(IcTransaction::Icrc1Transfer(transfer)) => &transfer.asset,
+                    Protocol::IC(IcTransaction::Icrc2Approve(approve)) => &approve.asset,
+                    Protocol::IC(IcTransaction::Icrc2TransferFrom(transfer_from)) => {
+                        &transfer_from.asset
+                    }
+                    Protocol::IC(IcTransaction::CkBtcRetrieveBtc(retrieve_btc)) => {
+                        &retrieve_btc.asset
+                    }
+                    Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => &icp_transfer.asset,
+                    // NFT collections charge no transfer fee
+                    Protocol::IC(IcTransaction::Icrc7Transfer(_)) => return None,
+                };
+                Some(asset.address())
+            })
+            .collect();
+
+        let fee_results = future::join_all(
+            addresses
+                .iter()
+                .map(|address| async move { IcrcService::new(*address).icrc_1_fee().await }),
+        )
+        .await;
+
+        addresses
+            .into_iter()
+            .zip(fee_results)
+            .map(|(address, fee_result)| {
+                fee_result
+                    .map(|fee| (address, fee))
+                    .map_err(|e| format!("Query icrc1 fee failed for canister {}: {}", address, e))
+            })
+            .collect()
+    }
+
+    /// Queries the allowance granted on the ledger to the spender of a transfer_from
+    /// # Arguments
+    /// * `address` - The ledger canister
+    /// * `transfer_from` - The transfer_from to be covered
+    /// # Returns
+    /// * `Result<Nat, CanisterError>` - The allowance or an error if the call fails
+    async fn get_ledger_allowance(
+        address: Principal,
+        transfer_from: &Icrc2TransferFrom,
+    ) -> Result<Nat, CanisterError> {
+        let allowance_args = AllowanceArgs {
+            account: transfer_from.from.clone().try_into()?,
+            spender: transfer_from.spender.clone().try_into()?,
+        };
+        IcrcService::new(address)
+            .icrc_2_allowance(&allowance_args)
+            .await
+            .map(|allowance| allowance.allowance)
+    }
+
+    /// Finds the amount approved for a transfer_from by a pending approval of the same action
+    /// # Arguments
+    /// * `transactions` - The pending transactions of the action
+    /// * `transfer_from` - The transfer_from to be covered
+    /// # Returns
+    /// * `Option<Nat>` - The approved amount, if the action approves the spender
+    fn find_pending_approve_amount(
+        transactions: &[Transaction],
+        transfer_from: &Icrc2TransferFrom,
+    ) -> Option<Nat> {
+        transactions.iter().find_map(|tx| match &tx.protocol {
+            Protocol::IC(IcTransaction::Icrc2Approve(approve))
+                if approve.from == transfer_from.from
+                    && approve.spender == transfer_from.spender
+                    && approve.asset == transfer_from.asset =>
+            {
+                Some(approve.amount.clone())
+            }
+            _ => None,
+        })
+    }
+}
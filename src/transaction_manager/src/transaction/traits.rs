@@ -1,19 +1,37 @@
 // Copyright (c) 2025 Cashier Protocol Labs
 // Licensed under the MIT License (see LICENSE file in the project root)
 
+use candid::Nat;
 use cashier_backend_types::{error::CanisterError, repository::transaction::v1::Transaction};
-use std::pin::Pin;
+use std::{fmt, pin::Pin};
+
+/// The reason a transaction could not be validated
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TransactionValidationError {
+    /// The ledger shows that the transaction did not happen
+    Failed(String),
+    /// The ledger could not be queried, the transaction may still have happened
+    Unavailable(String),
+}
+
+impl fmt::Display for TransactionValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Failed(message) | Self::Unavailable(message) => f.write_str(message),
+        }
+    }
+}
 
 pub trait TransactionValidator {
     /// Validate the transaction success
     /// # Arguments
     /// * `transaction` - The transaction to be validated
     /// # Returns
-    /// * `Pin<Box<dyn Future<Output = Result<(), CanisterError>>>` - A future that resolves to Ok(()) if the transaction is succeeded, or a CanisterError if error occurs
+    /// * `Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>>` - A future that resolves to the ledger block index of the transaction, if known, when the transaction is succeeded, or the reason it could not be validated otherwise
     fn validate_success(
         &self,
         transaction: Transaction,
-    ) -> Pin<Box<dyn Future<Output = Result<(), String>>>>;
+    ) -> Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>>;
 }
 
 pub trait TransactionExecutor {
@@ -21,9 +39,9 @@ pub trait TransactionExecutor {
     /// # Arguments
     /// * `transaction` - The transaction to be executed
     /// # Returns
-    /// * `Result<(), CanisterError>` - Ok(()) if processing is successful, or a CanisterError if error occurs
+    /// * `Result<Nat, CanisterError>` - The ledger block index of the transaction if processing is successful, or a CanisterError if error occurs
     fn execute(
         &self,
         transaction: Transaction,
-    ) -> Pin<Box<dyn Future<Output = Result<(), CanisterError>>>>;
+    ) -> Pin<Box<dyn Future<Output = Result<Nat, CanisterError>>>>;
 }
@@ -1,5 +1,6 @@
 use crate::{
-    transaction::traits::TransactionValidator, utils::topological_sort::kahn_topological_sort_flat,
+    transaction::traits::{TransactionValidationError, TransactionValidator},
+    utils::topological_sort::kahn_topological_sort_flat,
 };
 use cashier_backend_types::{
     error::CanisterError,
@@ -13,6 +14,8 @@ use cashier_backend_types::{
         transaction::v1::{FromCallType, Transaction, TransactionState},
     },
 };
+use cashier_common::constant::MAX_CONCURRENT_TRANSACTION_VALIDATIONS;
+use futures::future;
 use std::{collections::HashMap, rc::Rc};
 
 pub struct ValidatorService<V: TransactionValidator> {
@@ -24,7 +27,11 @@ impl<V: TransactionValidator> ValidatorService<V> {
         Self { validator }
     }
 
-    /// Validate a list of transactions and update their states accordingly
+    /// Validate a list of transactions and update their states accordingly.
+    /// Wallet transactions are validated concurrently, at most
+    /// `MAX_CONCURRENT_TRANSACTION_VALIDATIONS` at a time, and the ones already
+    /// successful are kept without being validated again.
+    /// A transaction whose ledger cannot be queried keeps its state.
     /// # Arguments
     /// * `transactions` - A slice of transactions to be validated
     /// # Returns
@@ -46,28 +53,64 @@ impl<V: TransactionValidator> ValidatorService<V> {
         let graph: Graph = transactions.to_vec().into();
         let sorted_transactions = kahn_topological_sort_flat(&graph)?;
 
-        // validate transactions in topological order and update their status
-        let mut is_success = true;
+        let mut pending_transactions = Vec::<Transaction>::new();
         for tx_id in sorted_transactions.iter() {
-            if let Some(tx) = txs_map.get_mut(tx_id) {
+            if let Some(tx) = txs_map.remove(tx_id) {
                 if tx.from_call_type == FromCallType::Canister {
                     // Skip validation for canister-initiated transactions
-                    canister_transactions.push(tx.clone());
+                    canister_transactions.push(tx);
                     continue;
                 }
+                pending_transactions.push(tx);
+            }
+        }
+
+        // validate wallet transactions concurrently and update their status,
+        // a transaction already validated is not looked up again
+        let transactions_to_validate: Vec<&Transaction> = pending_transactions
+            .iter()
+            .filter(|tx| tx.state != TransactionState::Success)
+            .collect();
+        let mut validated_txs_map = HashMap::<String, Transaction>::new();
+        let mut is_success = true;
+        for chunk in transactions_to_validate.chunks(MAX_CONCURRENT_TRANSACTION_VALIDATIONS) {
+            let results = future::join_all(
+                chunk
+                    .iter()
+                    .map(|tx| self.validator.validate_success((*tx).clone())),
+            )
+            .await;
 
-                match self.validator.validate_success(tx.clone()).await {
-                    Ok(_) => tx.state = TransactionState::Success,
-                    Err(e) => {
+            for (tx, result) in chunk.iter().zip(results) {
+                let mut tx = (*tx).clone();
+                match result {
+                    Ok(block_index) => {
+                        tx.state = TransactionState::Success;
+                        tx.block_index = block_index;
+                    }
+                    // the ledger could not be queried, the transaction keeps its state
+                    // until it can be validated
+                    Err(TransactionValidationError::Unavailable(e)) => {
+                        errors.push(e);
+                        is_success = false;
+                    }
+                    Err(TransactionValidationError::Failed(e)) => {
                         tx.state = TransactionState::Fail;
                         errors.push(e);
                         is_success = false;
                     }
                 }
-                wallet_transactions.push(tx.clone());
+                validated_txs_map.insert(tx.id.clone(), tx);
             }
         }
 
+        // keep the topological order of the wallet transactions
+        wallet_transactions.extend(
+            pending_transactions
+                .into_iter()
+                .map(|tx| validated_txs_map.remove(&tx.id).unwrap_or(tx)),
+        );
+
         Ok(ValidateActionTransactionsResult {
             wallet_transactions,
             canister_transactions,
@@ -133,11 +176,13 @@ impl<V: TransactionValidator> ValidatorService<V> {
 mod tests {
     use super::*;
     use crate::utils::test_utils::{generate_mock_intent, generate_mock_transaction};
+    use candid::Nat;
     use cashier_backend_types::repository::action::v1::{Action, ActionState, ActionType};
     use cashier_backend_types::repository::intent::v1::IntentState;
     use cashier_backend_types::repository::transaction::v1::{
         FromCallType, Transaction, TransactionState,
     };
+    use cashier_common::constant::MAX_LINK_BATCH_SIZE;
     use std::cell::RefCell;
     use std::collections::HashMap;
     use std::future::Future;
@@ -161,18 +206,63 @@ mod tests {
         fn validate_success(
             &self,
             _tx: Transaction,
-        ) -> Pin<Box<dyn Future<Output = Result<(), String>>>> {
+        ) -> Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>>
+        {
             let fail = *self.should_fail.borrow();
             Box::pin(async move {
                 if fail {
-                    Err("mock fail".to_string())
+                    Err(TransactionValidationError::Failed("mock fail".to_string()))
                 } else {
-                    Ok(())
+                    Ok(None)
                 }
             })
         }
     }
 
+    // Mock TransactionValidator whose ledger cannot be queried
+    struct UnavailableValidator;
+
+    impl TransactionValidator for UnavailableValidator {
+        fn validate_success(
+            &self,
+            _tx: Transaction,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>>
+        {
+            Box::pin(async move {
+                Err(TransactionValidationError::Unavailable(
+                    "mock unavailable".to_string(),
+                ))
+            })
+        }
+    }
+
+    // Mock TransactionValidator tracking how many validations are in flight at once
+    #[derive(Default)]
+    struct ConcurrencyValidator {
+        in_flight: Rc<RefCell<usize>>,
+        max_in_flight: Rc<RefCell<usize>>,
+    }
+
+    impl TransactionValidator for ConcurrencyValidator {
+        fn validate_success(
+            &self,
+            _tx: Transaction,
+        ) -> Pin<Box<dyn Future<Output = Result<Option<Nat>, TransactionValidationError>>>>
+        {
+            let in_flight = self.in_flight.clone();
+            let max_in_flight = self.max_in_flight.clone();
+            Box::pin(async move {
+                *in_flight.borrow_mut() += 1;
+                let current = *in_flight.borrow();
+                let max = (*max_in_flight.borrow()).max(current);
+                *max_in_flight.borrow_mut() = max;
+                tokio::task::yield_now().await;
+                *in_flight.borrow_mut() -= 1;
+                Ok(None)
+            })
+        }
+    }
+
     fn make_action(state: ActionState) -> Action {
         Action {
             id: "action1".to_string(),
@@ -180,6 +270,7 @@ mod tests {
             r#type: ActionType::CreateLink,
             creator: candid::Principal::anonymous(),
             link_id: "mock_link_id".to_string(),
+            top_up_count: None,
         }
     }
 
@@ -239,6 +330,85 @@ mod tests {
         assert_eq!(result_fail.errors, vec!["mock fail".to_string()]);
     }
 
+    #[tokio::test]
+    async fn test_validate_action_transactions_of_a_full_link_batch() {
+        // Arrange
+        let validator = Rc::new(ConcurrencyValidator::default());
+        let service = ValidatorService::new(validator.clone());
+        let tx_count = 2 * MAX_LINK_BATCH_SIZE as usize;
+        let txs: Vec<Transaction> = (0..tx_count)
+            .map(|i| {
+                let mut tx = generate_mock_transaction(&format!("tx{i}"), vec![]);
+                tx.from_call_type = FromCallType::Wallet;
+                tx
+            })
+            .collect();
+
+        // Act
+        let result = service.validate_action_transactions(&txs).await.unwrap();
+
+        // Assert
+        assert!(result.is_success);
+        assert_eq!(result.wallet_transactions.len(), tx_count);
+        assert!(
+            result
+                .wallet_transactions
+                .iter()
+                .all(|tx| tx.state == TransactionState::Success)
+        );
+        assert_eq!(
+            *validator.max_in_flight.borrow(),
+            MAX_CONCURRENT_TRANSACTION_VALIDATIONS
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_action_transactions_skips_successful_ones() {
+        // Arrange
+        let validator = Rc::new(ConcurrencyValidator::default());
+        let service = ValidatorService::new(validator.clone());
+        let mut tx1 = generate_mock_transaction("tx1", vec![]);
+        tx1.from_call_type = FromCallType::Wallet;
+        tx1.state = TransactionState::Success;
+        tx1.block_index = Some(Nat::from(42u64));
+
+        // Act
+        let result = service.validate_action_transactions(&[tx1]).await.unwrap();
+
+        // Assert
+        assert!(result.is_success);
+        assert_eq!(*validator.max_in_flight.borrow(), 0);
+        assert_eq!(
+            result.wallet_transactions[0].block_index,
+            Some(Nat::from(42u64))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_validate_action_transactions_unavailable() {
+        // Arrange
+        let service = ValidatorService::new(Rc::new(UnavailableValidator));
+        let mut tx1 = generate_mock_transaction("tx1", vec![]);
+        tx1.from_call_type = FromCallType::Wallet;
+        tx1.state = TransactionState::Processing;
+
+        // Act
+        let result = service
+            .validate_action_transactions(&[tx1.clone()])
+            .await
+            .unwrap();
+
+        // Assert
+        let tx1_result = result
+            .wallet_transactions
+            .iter()
+            .find(|tx| tx.id == "tx1")
+            .unwrap();
+        assert_eq!(tx1_result.state, TransactionState::Processing);
+        assert!(!result.is_success);
+        assert_eq!(result.errors, vec!["mock unavailable".to_string()]);
+    }
+
     #[test]
     fn test_rollup_action_state_success() {
         // Arrange
@@ -2,9 +2,13 @@
 // Licensed under the MIT License (see LICENSE file in the project root)
 
 use candid::{Nat, Principal};
-use cashier_backend_types::repository::{asset_info::AssetInfo, common::Asset};
-use cashier_common::constant::{CREATE_LINK_FEE, ICP_CANISTER_PRINCIPAL};
-use std::collections::HashMap;
+use cashier_backend_types::init::LinkCreationFee;
+use cashier_backend_types::repository::{
+    asset_info::AssetInfo,
+    common::{Asset, Wallet},
+    transaction::v1::{IcTransaction, Protocol, Transaction},
+};
+use std::collections::{BTreeMap, HashMap};
 
 /// Calculate the token balance required for the link
 /// # Arguments
@@ -20,18 +24,16 @@ pub fn calculate_link_balance_map(
 ) -> HashMap<Principal, Nat> {
     let mut balance_map: HashMap<Principal, Nat> = HashMap::new();
     asset_info.iter().for_each(|info| {
-        let address = match &info.asset {
-            Asset::IC { address } => address,
-        };
+        let address = info.asset.address();
 
         let default_fee = Nat::from(0u64);
-        let fee_in_nat = fee_map.get(address).unwrap_or(&default_fee);
+        let fee_in_nat = fee_map.get(&address).unwrap_or(&default_fee);
         let fee_amount = fee_in_nat.clone();
 
         let sending_amount =
             (info.amount_per_link_use_action.clone() + fee_amount) * Nat::from(max_use_count);
 
-        balance_map.insert(*address, sending_amount);
+        balance_map.insert(address, sending_amount);
     });
 
     balance_map
@@ -39,22 +41,248 @@ pub fn calculate_link_balance_map(
 
 /// Calculate the total fee required to create a link
 /// # Arguments
+/// * `link_fee` - The link creation fee to be charged
 /// * `fee_map` - A map of token principal to its corresponding fee
 /// # Returns
 /// * `(actual_amount: Nat, approved_amount: Nat)` - A tuple containing the actual fee amount and the approved fee amount
-pub fn calculate_create_link_fee(fee_map: &HashMap<Principal, Nat>) -> (Nat, Nat) {
-    let create_link_fee = Nat::from(CREATE_LINK_FEE);
+pub fn calculate_create_link_fee(
+    link_fee: &LinkCreationFee,
+    fee_map: &HashMap<Principal, Nat>,
+) -> (Nat, Nat) {
+    let create_link_fee = Nat::from(link_fee.amount);
     let default_fee = Nat::from(0u64);
-    let fee_in_nat = fee_map.get(&ICP_CANISTER_PRINCIPAL).unwrap_or(&default_fee);
+    let fee_in_nat = fee_map.get(&link_fee.token_id).unwrap_or(&default_fee);
     (
         create_link_fee.clone(),
         create_link_fee + fee_in_nat.clone(),
     )
 }
 
+/// The cost of creating a link in one token
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CreateLinkCost {
+    /// The amount shared with the link users
+    pub funding_amount: Nat,
+    /// The ledger fees of the deposit, the claims and the link creation fee payment
+    pub ledger_fee_amount: Nat,
+    /// The link creation fee
+    pub service_fee_amount: Nat,
+    /// The allowance requested to pay the link creation fee
+    pub approve_amount: Option<Nat>,
+}
+
+impl CreateLinkCost {
+    /// The total amount debited from the creator wallet
+    pub fn total_amount(&self) -> Nat {
+        self.funding_amount.clone()
+            + self.ledger_fee_amount.clone()
+            + self.service_fee_amount.clone()
+    }
+}
+
+/// Calculate the cost of creating a link, per token
+/// # Arguments
+/// * `asset_info` - The assets deposited into the link when it is created, empty for receive links
+/// * `link_fee` - The link creation fee, None if the link is created for free
+/// * `fee_map` - A map of token principal to its corresponding fee
+/// * `max_use_count` - The maximum number of times the link can be used
+/// # Returns
+/// * `BTreeMap<Principal, CreateLinkCost>` - A map of token principal to its cost.
+///   NFT deposits move no fungible balance and are left out.
+pub fn calculate_create_link_cost(
+    asset_info: &[AssetInfo],
+    link_fee: Option<&LinkCreationFee>,
+    fee_map: &HashMap<Principal, Nat>,
+    max_use_count: u64,
+) -> BTreeMap<Principal, CreateLinkCost> {
+    let mut cost_map = BTreeMap::<Principal, CreateLinkCost>::new();
+    let ledger_fee_of = |address: &Principal| fee_map.get(address).cloned().unwrap_or_default();
+
+    let fungible_asset_info: Vec<AssetInfo> = asset_info
+        .iter()
+        .filter(|info| !info.asset.is_nft())
+        .cloned()
+        .collect();
+    let balance_map = calculate_link_balance_map(&fungible_asset_info, fee_map, max_use_count);
+    for (address, deposit_amount) in balance_map {
+        // the deposit covers the ledger fee of every claim
+        let claims_ledger_fee = ledger_fee_of(&address) * max_use_count;
+        let cost = cost_map.entry(address).or_default();
+        cost.funding_amount = deposit_amount - claims_ledger_fee.clone();
+        cost.ledger_fee_amount = claims_ledger_fee + ledger_fee_of(&address);
+    }
+
+    if let Some(link_fee) = link_fee {
+        let (actual_amount, approval_amount) = calculate_create_link_fee(link_fee, fee_map);
+        let cost = cost_map.entry(link_fee.token_id).or_default();
+        // the approve and the transfer_from both pay a ledger fee
+        cost.ledger_fee_amount += ledger_fee_of(&link_fee.token_id) * 2u64;
+        cost.service_fee_amount = actual_amount;
+        cost.approve_amount = Some(approval_amount);
+    }
+
+    cost_map
+}
+
+/// Calculate the balance each paying account needs to cover the given transactions
+/// # Arguments
+/// * `transactions` - The transactions still to be executed, in execution order
+/// * `fee_map` - A map of token principal to its corresponding fee
+/// # Returns
+/// * `BTreeMap<(Wallet, Asset), Nat>` - A map of paying account and asset to the required balance.
+///   Transfers cost their amount plus the ledger fee, approvals cost the ledger fee only.
+///   BTC withdrawals cost their amount plus the fee of the approval given to the minter.
+///   NFT transfers move no fungible balance and are left out.
+///   The amounts an account receives from earlier transactions are spent before its balance,
+///   accounts whose costs they fully cover are left out.
+pub fn calculate_required_balance_map(
+    transactions: &[Transaction],
+    fee_map: &HashMap<Principal, Nat>,
+) -> BTreeMap<(Wallet, Asset), Nat> {
+    let mut balance_map = BTreeMap::<(Wallet, Asset), Nat>::new();
+    let mut received_map = BTreeMap::<(Wallet, Asset), Nat>::new();
+    transactions.iter().for_each(|tx| {
+        let (from, to, asset, amount) = match &tx.protocol {
+            Protocol::IC(IcTransaction::Icrc1Transfer(transfer)) => (
+                &transfer.from,
+                Some(&transfer.to),
+                &transfer.asset,
+                transfer.amount.clone(),
+            ),
+            Protocol::IC(IcTransaction::Icrc2Approve(approve)) => {
+                (&approve.from, None, &approve.asset, Nat::from(0u64))
+            }
+            Protocol::IC(IcTransaction::Icrc2TransferFrom(transfer_from)) => (
+                &transfer_from.from,
+                Some(&transfer_from.to),
+                &transfer_from.asset,
+                transfer_from.amount.clone(),
+            ),
+            Protocol::IC(IcTransaction::CkBtcRetrieveBtc(retrieve_btc)) => (
+                &retrieve_btc.from,
+                None,
+                &retrieve_btc.asset,
+                retrieve_btc.amount.clone(),
+            ),
+            Protocol::IC(IcTransaction::IcpTransfer(icp_transfer)) => (
+                &icp_transfer.from,
+                Some(&icp_transfer.to),
+                &icp_transfer.asset,
+                icp_transfer.amount.clone(),
+            ),
+            Protocol::IC(IcTransaction::Icrc7Transfer(_)) => return,
+        };
+
+        let default_fee = Nat::from(0u64);
+        let fee_in_nat = fee_map.get(&asset.address()).unwrap_or(&default_fee);
+        let cost = amount.clone() + fee_in_nat.clone();
+
+        // the amount received from earlier transactions is spent first
+        let received = received_map
+            .entry((from.clone(), asset.clone()))
+            .or_insert(Nat::from(0u64));
+        if *received >= cost {
+            *received -= cost;
+        } else {
+            let uncovered = cost - received.clone();
+            *received = Nat::from(0u64);
+            *balance_map
+                .entry((from.clone(), asset.clone()))
+                .or_insert(Nat::from(0u64)) += uncovered;
+        }
+
+        if let Some(to) = to {
+            *received_map
+                .entry((to.clone(), asset.clone()))
+                .or_insert(Nat::from(0u64)) += amount;
+        }
+    });
+
+    balance_map
+}
+
+/// Calculate the share of a random-amount link paid to the next claimer
+/// # Arguments
+/// * `remaining_amount` - The amount of the pot not yet shared
+/// * `remaining_claims` - The number of claims left, including this one
+/// * `random` - A random number used to draw the share
+/// # Returns
+/// * `Nat` - The share, between 1 and twice the average of the remaining pot.
+///   At least 1 is left for each of the other claims and the last claim takes the remainder,
+///   so the shares never add up to more than the pot.
+pub fn calculate_random_share(remaining_amount: &Nat, remaining_claims: u64, random: u64) -> Nat {
+    if remaining_claims <= 1 {
+        return remaining_amount.clone();
+    }
+
+    let other_claims = Nat::from(remaining_claims - 1);
+    if *remaining_amount <= other_claims {
+        return Nat::from(0u64);
+    }
+
+    let max_share = remaining_amount.clone() - other_claims;
+    let double_average = remaining_amount.clone() * 2u64 / remaining_claims;
+    let upper_bound = if double_average < max_share {
+        double_average
+    } else {
+        max_share
+    };
+
+    Nat::from(1u64) + Nat::from(random) % upper_bound
+}
+
+/// Split an amount by basis-point weights
+/// # Arguments
+/// * `amount` - The amount to be split
+/// * `weights_bps` - The weight of each share in basis points, expected to add up to 10_000
+/// # Returns
+/// * `Vec<Nat>` - The shares in the order of `weights_bps`.
+///   Rounding leftovers go to the last share, so the shares always add up to `amount`.
+pub fn calculate_split_amounts(amount: &Nat, weights_bps: &[u16]) -> Vec<Nat> {
+    let Some((_last_weight, other_weights)) = weights_bps.split_last() else {
+        return vec![];
+    };
+
+    let mut shares: Vec<Nat> = other_weights
+        .iter()
+        .map(|weight_bps| amount.clone() * *weight_bps / 10_000u64)
+        .collect();
+
+    let distributed = shares
+        .iter()
+        .fold(Nat::from(0u64), |total, share| total + share.clone());
+    let remainder = if distributed < *amount {
+        amount.clone() - distributed
+    } else {
+        Nat::from(0u64)
+    };
+    shares.push(remainder);
+
+    shares
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use cashier_backend_types::repository::transaction::v1::{
+        CkBtcRetrieveBtc, FromCallType, Icrc1Transfer, Icrc2Approve, Icrc2TransferFrom,
+        TransactionState,
+    };
+    use cashier_common::constant::{CREATE_LINK_FEE, ICP_CANISTER_PRINCIPAL};
+
+    fn transaction(protocol: IcTransaction) -> Transaction {
+        Transaction {
+            id: "tx".to_string(),
+            created_at: 0,
+            state: TransactionState::Created,
+            dependency: None,
+            group: 0,
+            from_call_type: FromCallType::Wallet,
+            protocol: Protocol::IC(protocol),
+            start_ts: None,
+            block_index: None,
+        }
+    }
 
     #[test]
     fn test_calculate_link_balance_map() {
@@ -67,6 +295,8 @@ mod tests {
             asset: asset.clone(),
             label: label.clone(),
             amount_per_link_use_action: amount_per_link_use_action.clone(),
+            token_ids: None,
+            reserved_token_ids: None,
         };
 
         let fee_map: HashMap<Principal, Nat> = vec![(ICP_CANISTER_PRINCIPAL, Nat::from(2u64))]
@@ -80,11 +310,9 @@ mod tests {
 
         // Assert
         let expected_sending_amount = amount_per_link_use_action * Nat::from(3u64);
-        let address = match &asset {
-            Asset::IC { address } => address,
-        };
+        let address = asset.address();
         assert_eq!(
-            balance_map.get(address).cloned().unwrap(),
+            balance_map.get(&address).cloned().unwrap(),
             expected_sending_amount
         );
     }
@@ -96,11 +324,237 @@ mod tests {
             .into_iter()
             .collect();
 
+        let link_fee = LinkCreationFee {
+            token_id: ICP_CANISTER_PRINCIPAL,
+            amount: CREATE_LINK_FEE,
+        };
+
         // Act
-        let (actual_amount, approved_amount) = calculate_create_link_fee(&fee_map);
+        let (actual_amount, approved_amount) = calculate_create_link_fee(&link_fee, &fee_map);
 
         // Assert
         assert_eq!(actual_amount, Nat::from(CREATE_LINK_FEE));
         assert_eq!(approved_amount, Nat::from(CREATE_LINK_FEE + 5u64));
     }
+
+    #[test]
+    fn test_calculate_create_link_cost_for_send_link() {
+        // Arrange
+        let ckbtc = Principal::from_text("mxzaz-hqaaa-aaaar-qaada-cai").unwrap();
+        let asset_info = vec![AssetInfo {
+            asset: Asset::IC { address: ckbtc },
+            label: "Test Asset1".to_string(),
+            amount_per_link_use_action: Nat::from(100u64),
+            token_ids: None,
+            reserved_token_ids: None,
+        }];
+        let link_fee = LinkCreationFee {
+            token_id: ICP_CANISTER_PRINCIPAL,
+            amount: CREATE_LINK_FEE,
+        };
+        let fee_map: HashMap<Principal, Nat> = vec![
+            (ICP_CANISTER_PRINCIPAL, Nat::from(5u64)),
+            (ckbtc, Nat::from(2u64)),
+        ]
+        .into_iter()
+        .collect();
+
+        // Act
+        let cost_map = calculate_create_link_cost(&asset_info, Some(&link_fee), &fee_map, 3);
+
+        // Assert
+        let ckbtc_cost = cost_map.get(&ckbtc).unwrap();
+        assert_eq!(ckbtc_cost.funding_amount, Nat::from(300u64));
+        assert_eq!(ckbtc_cost.ledger_fee_amount, Nat::from(8u64));
+        assert_eq!(ckbtc_cost.service_fee_amount, Nat::from(0u64));
+        assert_eq!(ckbtc_cost.approve_amount, None);
+        assert_eq!(ckbtc_cost.total_amount(), Nat::from(308u64));
+
+        let icp_cost = cost_map.get(&ICP_CANISTER_PRINCIPAL).unwrap();
+        assert_eq!(icp_cost.funding_amount, Nat::from(0u64));
+        assert_eq!(icp_cost.ledger_fee_amount, Nat::from(10u64));
+        assert_eq!(icp_cost.service_fee_amount, Nat::from(CREATE_LINK_FEE));
+        assert_eq!(
+            icp_cost.approve_amount,
+            Some(Nat::from(CREATE_LINK_FEE + 5))
+        );
+        assert_eq!(icp_cost.total_amount(), Nat::from(CREATE_LINK_FEE + 10));
+    }
+
+    #[test]
+    fn test_calculate_create_link_cost_without_fee() {
+        // Arrange
+        let fee_map: HashMap<Principal, Nat> = HashMap::new();
+
+        // Act
+        let cost_map = calculate_create_link_cost(&[], None, &fee_map, 1);
+
+        // Assert
+        assert!(cost_map.is_empty());
+    }
+
+    #[test]
+    fn test_calculate_random_share_gives_remainder_to_last_claim() {
+        // Arrange
+        let remaining_amount = Nat::from(1234u64);
+
+        // Act
+        let share = calculate_random_share(&remaining_amount, 1, u64::MAX);
+
+        // Assert
+        assert_eq!(share, remaining_amount);
+    }
+
+    #[test]
+    fn test_calculate_random_share_never_overdraws_pot() {
+        // Arrange
+        let pot = Nat::from(1_000u64);
+        let claims = 10u64;
+
+        for seed in [0u64, 1, 7, 999, u64::MAX] {
+            let mut remaining_amount = pot.clone();
+            let mut total = Nat::from(0u64);
+
+            // Act
+            for claim in 0..claims {
+                let random = seed.wrapping_mul(6364136223846793005).wrapping_add(claim);
+                let share = calculate_random_share(&remaining_amount, claims - claim, random);
+
+                // Assert
+                assert!(share >= 1u64);
+                assert!(share <= remaining_amount);
+                remaining_amount -= share.clone();
+                total += share;
+            }
+
+            // Assert
+            assert_eq!(total, pot);
+            assert_eq!(remaining_amount, Nat::from(0u64));
+        }
+    }
+
+    #[test]
+    fn test_calculate_split_amounts_gives_rounding_to_last_share() {
+        // Arrange
+        let amount = Nat::from(1_001u64);
+        let weights_bps = [3_333u16, 3_333, 3_334];
+
+        // Act
+        let shares = calculate_split_amounts(&amount, &weights_bps);
+
+        // Assert
+        assert_eq!(
+            shares,
+            vec![Nat::from(333u64), Nat::from(333u64), Nat::from(335u64)]
+        );
+    }
+
+    #[test]
+    fn test_calculate_split_amounts_without_weights() {
+        // Act
+        let shares = calculate_split_amounts(&Nat::from(100u64), &[]);
+
+        // Assert
+        assert!(shares.is_empty());
+    }
+
+    #[test]
+    fn test_calculate_required_balance_map() {
+        // Arrange
+        let wallet = Wallet::new(Principal::anonymous());
+        let spender = Wallet::new(Principal::management_canister());
+        let asset = Asset::default();
+        let fee_map = HashMap::from([(Principal::anonymous(), Nat::from(10u64))]);
+        let transactions = vec![
+            transaction(IcTransaction::Icrc1Transfer(Icrc1Transfer {
+                from: wallet.clone(),
+                to: spender.clone(),
+                asset: asset.clone(),
+                amount: Nat::from(1_000u64),
+                memo: None,
+                ts: None,
+            })),
+            transaction(IcTransaction::Icrc2Approve(Icrc2Approve {
+                from: wallet.clone(),
+                spender: spender.clone(),
+                asset: asset.clone(),
+                amount: Nat::from(110u64),
+                memo: None,
+                ts: None,
+            })),
+            transaction(IcTransaction::Icrc2TransferFrom(Icrc2TransferFrom {
+                from: wallet.clone(),
+                to: spender.clone(),
+                spender: spender.clone(),
+                asset: asset.clone(),
+                amount: Nat::from(100u64),
+                memo: None,
+                ts: None,
+            })),
+        ];
+
+        // Act
+        let result = calculate_required_balance_map(&transactions, &fee_map);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[&(wallet, asset)], Nat::from(1_130u64));
+    }
+
+    #[test]
+    fn test_calculate_required_balance_map_credits_received_amounts() {
+        // Arrange
+        let payer = Wallet::new(Principal::anonymous());
+        let link = Wallet::new(Principal::management_canister());
+        let recipient = Wallet::new(Principal::from_slice(&[1]));
+        let asset = Asset::default();
+        let fee_map = HashMap::from([(Principal::anonymous(), Nat::from(10u64))]);
+        let transfer = |from: &Wallet, to: &Wallet, amount: u64| {
+            transaction(IcTransaction::Icrc1Transfer(Icrc1Transfer {
+                from: from.clone(),
+                to: to.clone(),
+                asset: asset.clone(),
+                amount: Nat::from(amount),
+                memo: None,
+                ts: None,
+            }))
+        };
+        let transactions = vec![
+            transfer(&payer, &link, 1_020),
+            transfer(&link, &recipient, 600),
+            transfer(&link, &recipient, 400),
+        ];
+
+        // Act
+        let result = calculate_required_balance_map(&transactions, &fee_map);
+
+        // Assert
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[&(payer, asset)], Nat::from(1_030u64));
+    }
+
+    #[test]
+    fn test_calculate_required_balance_map_for_ckbtc_retrieve_btc() {
+        // Arrange
+        let link_wallet = Wallet::new(Principal::anonymous());
+        let asset = Asset::default();
+        let fee_map = HashMap::from([(Principal::anonymous(), Nat::from(10u64))]);
+        let transactions = vec![transaction(IcTransaction::CkBtcRetrieveBtc(
+            CkBtcRetrieveBtc {
+                from: link_wallet.clone(),
+                minter: Wallet::new(Principal::management_canister()),
+                asset: asset.clone(),
+                amount: Nat::from(50_000u64),
+                btc_address: "bcrt1qe8tj5cf8m2y8apvqs8s8sw5yrlmzrfxdnfj8d7".to_string(),
+                memo: None,
+                ts: None,
+            },
+        ))];
+
+        // Act
+        let result = calculate_required_balance_map(&transactions, &fee_map);
+
+        // Assert
+        assert_eq!(result[&(link_wallet, asset)], Nat::from(50_010u64));
+    }
 }
@@ -94,6 +94,7 @@ pub fn generate_mock_intent(id: &str, dependencies: Vec<&str>) -> Intent {
             amount: Nat::from(100u64),
         }),
         label: "mock_intent".to_string(),
+        link_id: None,
     }
 }
 
@@ -118,5 +119,6 @@ pub fn generate_mock_transaction(id: &str, dependencies: Vec<&str>) -> Transacti
             ts: None,
         })),
         start_ts: None,
+        block_index: None,
     }
 }